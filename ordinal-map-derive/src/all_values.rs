@@ -0,0 +1,69 @@
+use crate::variant_ordinals::VariantOrdinals;
+
+/// Generate `ALL_VALUES` constant for `#[ordinal(all_values)]`.
+pub(crate) fn derive_all_values(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    span: proc_macro2::Span,
+    variant_ordinals: &VariantOrdinals,
+) -> syn::Result<proc_macro2::TokenStream> {
+    variant_ordinals.check_fieldless("all_values")?;
+
+    let n = variant_ordinals.variants.len();
+    let variant_idents: Vec<&syn::Ident> =
+        variant_ordinals.variants.iter().map(|v| &v.ident).collect();
+
+    let all_values: syn::Expr = match &variant_ordinals.known_ordinals {
+        Some(known_ordinals) => {
+            let mut by_ordinal = vec![None; n];
+            for (variant, ordinal) in variant_idents.iter().zip(known_ordinals) {
+                by_ordinal[*ordinal] = Some(variant);
+            }
+            let by_ordinal = by_ordinal.into_iter().map(|v| v.unwrap());
+            syn::parse_quote! {
+                [ #( Self::#by_ordinal, )* ]
+            }
+        }
+        None => {
+            // Ordinals are only known in const context,
+            // so invert the table and pick variants with `match`.
+            let items = &variant_ordinals.items;
+            let ordinals_item = variant_ordinals.ordinals_item();
+            let indices: Vec<usize> = (0..n).collect();
+            let values = indices.iter().map(|k| -> syn::Expr {
+                syn::parse_quote! {
+                    match VARIANTS[#k] {
+                        #( #indices => Self::#variant_idents, )*
+                        _ => unreachable!(),
+                    }
+                }
+            });
+            syn::parse_quote! {
+                {
+                    #( #items )*
+                    #ordinals_item
+                    // Variant index in declaration order by ordinal.
+                    const VARIANTS: [usize; #n] = {
+                        let mut variants = [0; #n];
+                        let mut i = 0;
+                        while i < #n {
+                            variants[ORDINALS[i]] = i;
+                            i += 1;
+                        }
+                        variants
+                    };
+                    [ #( #values, )* ]
+                }
+            }
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote::quote_spanned! { span =>
+        #[allow(clippy::all, dead_code)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// All values in ordinal order.
+            pub const ALL_VALUES: [Self; #n] = #all_values;
+        }
+    })
+}
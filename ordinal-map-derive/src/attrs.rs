@@ -0,0 +1,396 @@
+use syn::parse::Parse;
+use syn::spanned::Spanned;
+
+/// Integer range given in `#[ordinal(range = ...)]`.
+#[derive(Clone)]
+pub(crate) struct FieldRange {
+    pub(crate) start: syn::Expr,
+    pub(crate) end: syn::Expr,
+    pub(crate) inclusive: bool,
+}
+
+/// Attributes on struct or enum variant fields.
+#[derive(Default, Clone)]
+pub(crate) struct FieldAttrs {
+    pub(crate) range: Option<FieldRange>,
+}
+
+fn is_ordinal_attr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("ordinal")
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[syn::Attribute]) -> syn::Result<FieldAttrs> {
+        let mut field_attrs = FieldAttrs::default();
+        for attr in attrs.iter().filter(|a| is_ordinal_attr(a)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("range") {
+                    if field_attrs.range.is_some() {
+                        return Err(meta.error("duplicate `range` attribute"));
+                    }
+                    let expr: syn::Expr = meta.value()?.parse()?;
+                    let syn::Expr::Range(range) = &expr else {
+                        return Err(syn::Error::new(
+                            expr.span(),
+                            "expecting range like `0..10` or `1..=31`",
+                        ));
+                    };
+                    let (Some(start), Some(end)) = (&range.start, &range.end) else {
+                        return Err(syn::Error::new(
+                            expr.span(),
+                            "range must have both start and end",
+                        ));
+                    };
+                    field_attrs.range = Some(FieldRange {
+                        start: (**start).clone(),
+                        end: (**end).clone(),
+                        inclusive: matches!(range.limits, syn::RangeLimits::Closed(_)),
+                    });
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `ordinal` field attribute"))
+                }
+            })?;
+        }
+        Ok(field_attrs)
+    }
+}
+
+/// Parse `[a, b]` or `[1, 0]` list of fields.
+fn parse_field_order(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Vec<syn::Member>> {
+    let value = meta.value()?;
+    let content;
+    syn::bracketed!(content in value);
+    let members = content.parse_terminated(syn::Member::parse, syn::Token![,])?;
+    Ok(members.into_iter().collect())
+}
+
+/// How `#[ordinal(discriminant)]` maps discriminants to ordinals.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum DiscriminantMode {
+    /// Ordinal is the discriminant, discriminants must be `0..N`.
+    Exact,
+    /// Ordinal is the index of the discriminant in sorted discriminants.
+    Dense,
+}
+
+/// Case conversion in `#[ordinal(rename_all = "...")]`, same as in serde.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    const ALL: [(&'static str, RenameRule); 8] = [
+        ("lowercase", RenameRule::Lower),
+        ("UPPERCASE", RenameRule::Upper),
+        ("PascalCase", RenameRule::Pascal),
+        ("camelCase", RenameRule::Camel),
+        ("snake_case", RenameRule::Snake),
+        ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+        ("kebab-case", RenameRule::Kebab),
+        ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+    ];
+
+    fn parse(lit: &syn::LitStr) -> syn::Result<RenameRule> {
+        let value = lit.value();
+        match RenameRule::ALL.iter().find(|(name, _)| *name == value) {
+            Some((_, rule)) => Ok(*rule),
+            None => Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "unknown `rename_all` rule, expecting one of: {}",
+                    RenameRule::ALL
+                        .iter()
+                        .map(|(name, _)| format!("\"{name}\""))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )),
+        }
+    }
+
+    /// Apply the rule to a `PascalCase` variant name.
+    pub(crate) fn apply(self, variant: &str) -> String {
+        let snake = || {
+            let mut snake = String::new();
+            for (i, c) in variant.char_indices() {
+                if i > 0 && c.is_uppercase() {
+                    snake.push('_');
+                }
+                snake.push(c.to_ascii_lowercase());
+            }
+            snake
+        };
+        match self {
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Pascal => variant.to_owned(),
+            RenameRule::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            RenameRule::Snake => snake(),
+            RenameRule::ScreamingSnake => snake().to_ascii_uppercase(),
+            RenameRule::Kebab => snake().replace('_', "-"),
+            RenameRule::ScreamingKebab => snake().replace('_', "-").to_ascii_uppercase(),
+        }
+    }
+}
+
+/// Conversion trait in `#[ordinal(via = "...")]`.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ViaKind {
+    /// `From<T> for Self`, every value of `T` is a valid value.
+    From,
+    /// `TryFrom<T> for Self`, some values of `T` may be invalid.
+    TryFrom,
+}
+
+/// `#[ordinal(via = "From<u8>")]`.
+pub(crate) struct Via {
+    pub(crate) kind: ViaKind,
+    pub(crate) ty: syn::Type,
+    pub(crate) span: proc_macro2::Span,
+}
+
+impl Via {
+    fn parse(lit: &syn::LitStr) -> syn::Result<Via> {
+        let error = || {
+            syn::Error::new(
+                lit.span(),
+                "expecting `From<T>` or `TryFrom<T>` where `T: Ordinal`",
+            )
+        };
+        let path: syn::Path = lit.parse()?;
+        let segments = Vec::from_iter(&path.segments);
+        let (last, prefix) = segments.split_last().ok_or_else(error)?;
+        // Only the `core::convert` traits from the prelude, not other traits with the same name.
+        let prefix = Vec::from_iter(prefix.iter().map(|s| (s.ident.to_string(), &s.arguments)));
+        match &prefix[..] {
+            [] if path.leading_colon.is_none() => {}
+            [(krate, syn::PathArguments::None), (module, syn::PathArguments::None)]
+                if (krate == "core" || krate == "std") && module == "convert" => {}
+            _ => return Err(error()),
+        }
+        let kind = if last.ident == "From" {
+            ViaKind::From
+        } else if last.ident == "TryFrom" {
+            ViaKind::TryFrom
+        } else {
+            return Err(error());
+        };
+        let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+            return Err(error());
+        };
+        let [syn::GenericArgument::Type(ty)] = Vec::from_iter(&args.args)[..] else {
+            return Err(error());
+        };
+        Ok(Via {
+            kind,
+            ty: ty.clone(),
+            span: lit.span(),
+        })
+    }
+}
+
+/// Attributes on struct or enum.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    pub(crate) reverse: bool,
+    pub(crate) field_order: Option<Vec<syn::Member>>,
+    pub(crate) discriminant: Option<(DiscriminantMode, proc_macro2::Span)>,
+    pub(crate) names: Option<proc_macro2::Span>,
+    pub(crate) rename_all: Option<(RenameRule, proc_macro2::Span)>,
+    pub(crate) case_insensitive: Option<proc_macro2::Span>,
+    pub(crate) all_values: Option<proc_macro2::Span>,
+    pub(crate) bound: Option<Vec<syn::WherePredicate>>,
+    /// Path to `ordinal_map` crate.
+    pub(crate) krate: Option<syn::Path>,
+    pub(crate) max_size: Option<syn::Expr>,
+    pub(crate) transparent: Option<proc_macro2::Span>,
+    pub(crate) via: Option<Via>,
+    pub(crate) wide: Option<proc_macro2::Span>,
+}
+
+impl ContainerAttrs {
+    /// Attributes which require [`VariantOrdinals`](crate::variant_ordinals::VariantOrdinals).
+    pub(crate) fn fieldless_enum_attrs(&self) -> Vec<(&'static str, proc_macro2::Span)> {
+        let mut attrs = Vec::new();
+        if let Some(span) = self.names {
+            attrs.push(("names", span));
+        }
+        if let Some(span) = self.all_values {
+            attrs.push(("all_values", span));
+        }
+        attrs
+    }
+
+    pub(crate) fn parse(attrs: &[syn::Attribute]) -> syn::Result<ContainerAttrs> {
+        let mut container_attrs = ContainerAttrs::default();
+        for attr in attrs.iter().filter(|a| is_ordinal_attr(a)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("reverse") {
+                    if container_attrs.reverse {
+                        return Err(meta.error("duplicate `reverse` attribute"));
+                    }
+                    container_attrs.reverse = true;
+                    Ok(())
+                } else if meta.path.is_ident("field_order") {
+                    if container_attrs.field_order.is_some() {
+                        return Err(meta.error("duplicate `field_order` attribute"));
+                    }
+                    container_attrs.field_order = Some(parse_field_order(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("discriminant") {
+                    if container_attrs.discriminant.is_some() {
+                        return Err(meta.error("duplicate `discriminant` attribute"));
+                    }
+                    let mut mode = DiscriminantMode::Exact;
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|meta| {
+                            if meta.path.is_ident("dense") {
+                                mode = DiscriminantMode::Dense;
+                                Ok(())
+                            } else {
+                                Err(meta.error("expecting `dense`"))
+                            }
+                        })?;
+                    }
+                    container_attrs.discriminant = Some((mode, meta.path.span()));
+                    Ok(())
+                } else if meta.path.is_ident("names") {
+                    if container_attrs.names.is_some() {
+                        return Err(meta.error("duplicate `names` attribute"));
+                    }
+                    container_attrs.names = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("rename_all") {
+                    if container_attrs.rename_all.is_some() {
+                        return Err(meta.error("duplicate `rename_all` attribute"));
+                    }
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    container_attrs.rename_all = Some((RenameRule::parse(&lit)?, lit.span()));
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    if container_attrs.krate.is_some() {
+                        return Err(meta.error("duplicate `crate` attribute"));
+                    }
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    container_attrs.krate = Some(lit.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("transparent") {
+                    if container_attrs.transparent.is_some() {
+                        return Err(meta.error("duplicate `transparent` attribute"));
+                    }
+                    container_attrs.transparent = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("via") {
+                    if container_attrs.via.is_some() {
+                        return Err(meta.error("duplicate `via` attribute"));
+                    }
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    container_attrs.via = Some(Via::parse(&lit)?);
+                    Ok(())
+                } else if meta.path.is_ident("max_size") {
+                    if container_attrs.max_size.is_some() {
+                        return Err(meta.error("duplicate `max_size` attribute"));
+                    }
+                    container_attrs.max_size = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("bound") {
+                    if container_attrs.bound.is_some() {
+                        return Err(meta.error("duplicate `bound` attribute"));
+                    }
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    let bound = lit.parse_with(
+                        syn::punctuated::Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated,
+                    )?;
+                    container_attrs.bound = Some(bound.into_iter().collect());
+                    Ok(())
+                } else if meta.path.is_ident("all_values") {
+                    if container_attrs.all_values.is_some() {
+                        return Err(meta.error("duplicate `all_values` attribute"));
+                    }
+                    container_attrs.all_values = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("wide") {
+                    if container_attrs.wide.is_some() {
+                        return Err(meta.error("duplicate `wide` attribute"));
+                    }
+                    container_attrs.wide = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("case_insensitive") {
+                    if container_attrs.case_insensitive.is_some() {
+                        return Err(meta.error("duplicate `case_insensitive` attribute"));
+                    }
+                    container_attrs.case_insensitive = Some(meta.path.span());
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `ordinal` attribute"))
+                }
+            })?;
+        }
+        if container_attrs.names.is_none() {
+            if let Some((_, span)) = container_attrs.rename_all {
+                return Err(syn::Error::new(span, "`rename_all` requires `names`"));
+            }
+            if let Some(span) = container_attrs.case_insensitive {
+                return Err(syn::Error::new(span, "`case_insensitive` requires `names`"));
+            }
+        }
+        Ok(container_attrs)
+    }
+}
+
+/// Attributes on enum variants.
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    pub(crate) order: Option<syn::LitInt>,
+    pub(crate) field_order: Option<Vec<syn::Member>>,
+    pub(crate) rename: Option<syn::LitStr>,
+}
+
+impl VariantAttrs {
+    pub(crate) fn parse(attrs: &[syn::Attribute]) -> syn::Result<VariantAttrs> {
+        let mut variant_attrs = VariantAttrs::default();
+        for attr in attrs.iter().filter(|a| is_ordinal_attr(a)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("order") {
+                    if variant_attrs.order.is_some() {
+                        return Err(meta.error("duplicate `order` attribute"));
+                    }
+                    let order: syn::LitInt = meta.value()?.parse()?;
+                    order.base10_parse::<u64>()?;
+                    variant_attrs.order = Some(order);
+                    Ok(())
+                } else if meta.path.is_ident("field_order") {
+                    if variant_attrs.field_order.is_some() {
+                        return Err(meta.error("duplicate `field_order` attribute"));
+                    }
+                    variant_attrs.field_order = Some(parse_field_order(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    if variant_attrs.rename.is_some() {
+                        return Err(meta.error("duplicate `rename` attribute"));
+                    }
+                    variant_attrs.rename = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `ordinal` variant attribute"))
+                }
+            })?;
+        }
+        Ok(variant_attrs)
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use syn::visit::Visit;
+
+use crate::attrs::ContainerAttrs;
+use crate::attrs::FieldAttrs;
+
+/// Check if the type mentions any of the type parameters.
+fn mentions_type_params(ty: &syn::Type, type_params: &HashSet<&syn::Ident>) -> bool {
+    struct Visitor<'a> {
+        type_params: &'a HashSet<&'a syn::Ident>,
+        found: bool,
+    }
+
+    impl<'ast> Visit<'ast> for Visitor<'_> {
+        fn visit_path(&mut self, path: &'ast syn::Path) {
+            if path.leading_colon.is_none() {
+                if let Some(first) = path.segments.first() {
+                    if self.type_params.contains(&first.ident) {
+                        self.found = true;
+                    }
+                }
+            }
+            syn::visit::visit_path(self, path);
+        }
+    }
+
+    let mut visitor = Visitor {
+        type_params,
+        found: false,
+    };
+    visitor.visit_type(ty);
+    visitor.found
+}
+
+/// Generics of the `Ordinal` (or `WideOrdinal`) impl: user generics plus either bounds
+/// from `#[ordinal(bound = "...")]` or `FieldType: Trait` for each field
+/// which mentions a type parameter.
+pub(crate) fn ordinal_impl_generics(
+    generics: &syn::Generics,
+    data: &syn::Data,
+    attrs: &ContainerAttrs,
+    trait_path: &syn::Path,
+) -> syn::Result<syn::Generics> {
+    let mut generics = generics.clone();
+    let predicates: Vec<syn::WherePredicate> = match &attrs.bound {
+        Some(bound) => bound.clone(),
+        // Fields are not used with `via`.
+        None if attrs.via.is_some() => Vec::new(),
+        None => {
+            let type_params: HashSet<&syn::Ident> =
+                generics.type_params().map(|p| &p.ident).collect();
+            let fields: Vec<&syn::Field> = match data {
+                syn::Data::Struct(s) => s.fields.iter().collect(),
+                syn::Data::Enum(e) => e.variants.iter().flat_map(|v| &v.fields).collect(),
+                syn::Data::Union(_) => Vec::new(),
+            };
+            let mut seen = HashSet::new();
+            let mut predicates = Vec::new();
+            for field in fields {
+                if FieldAttrs::parse(&field.attrs)?.range.is_some() {
+                    // Range fields are integers, they don't need `Ordinal`.
+                    continue;
+                }
+                if !mentions_type_params(&field.ty, &type_params) {
+                    continue;
+                }
+                let ty = &field.ty;
+                if seen.insert(quote::quote! { #ty }.to_string()) {
+                    predicates.push(syn::parse_quote! { #ty: #trait_path });
+                }
+            }
+            predicates
+        }
+    };
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+    Ok(generics)
+}
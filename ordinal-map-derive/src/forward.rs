@@ -0,0 +1,172 @@
+use syn::spanned::Spanned;
+
+use crate::attrs::ContainerAttrs;
+use crate::attrs::FieldAttrs;
+use crate::attrs::ViaKind;
+use crate::ordinal::FromOrdinalKind;
+
+enum ForwardKind {
+    /// `#[ordinal(transparent)]`: single field newtype.
+    Transparent(syn::Member),
+    /// `#[ordinal(via = "...")]`: conversion to and from another type.
+    Via(ViaKind),
+}
+
+/// Struct or enum which forwards `Ordinal` to another type.
+pub(crate) struct ForwardGen {
+    kind: ForwardKind,
+    /// Type to forward to.
+    ty: syn::Type,
+}
+
+impl ForwardGen {
+    pub(crate) fn new(data: &syn::Data, attrs: &ContainerAttrs) -> syn::Result<ForwardGen> {
+        let (name, span) = match (attrs.transparent, &attrs.via) {
+            (Some(span), None) => ("transparent", span),
+            (None, Some(via)) => ("via", via.span),
+            (Some(span), Some(_)) => {
+                return Err(syn::Error::new(
+                    span,
+                    "`transparent` cannot be used with `via`",
+                ));
+            }
+            (None, None) => unreachable!(),
+        };
+        let mut conflicts = Vec::new();
+        if attrs.discriminant.is_some() {
+            conflicts.push("discriminant");
+        }
+        if attrs.field_order.is_some() {
+            conflicts.push("field_order");
+        }
+        conflicts.extend(attrs.fieldless_enum_attrs().into_iter().map(|(a, _)| a));
+        if let Some(conflict) = conflicts.first() {
+            return Err(syn::Error::new(
+                span,
+                format!("`{name}` cannot be used with `{conflict}`"),
+            ));
+        }
+
+        match &attrs.via {
+            Some(via) => Ok(ForwardGen {
+                kind: ForwardKind::Via(via.kind),
+                ty: via.ty.clone(),
+            }),
+            None => {
+                let syn::Data::Struct(s) = data else {
+                    return Err(syn::Error::new(
+                        span,
+                        "`transparent` can only be used with structs",
+                    ));
+                };
+                let mut fields = s.fields.iter();
+                let (Some(field), None) = (fields.next(), fields.next()) else {
+                    return Err(syn::Error::new(
+                        s.fields.span(),
+                        "`transparent` requires a struct with exactly one field",
+                    ));
+                };
+                if FieldAttrs::parse(&field.attrs)?.range.is_some() {
+                    return Err(syn::Error::new(
+                        field.span(),
+                        "`transparent` cannot be used with `range`",
+                    ));
+                }
+                let member = match &field.ident {
+                    Some(ident) => syn::Member::Named(ident.clone()),
+                    None => syn::Member::Unnamed(syn::Index::from(0)),
+                };
+                Ok(ForwardGen {
+                    kind: ForwardKind::Transparent(member),
+                    ty: field.ty.clone(),
+                })
+            }
+        }
+    }
+
+    pub(crate) fn ordinal_size(&self) -> syn::Expr {
+        let ty = &self.ty;
+        syn::parse_quote_spanned! { ty.span() =>
+            <#ty as ordinal_map::Ordinal>::ORDINAL_SIZE
+        }
+    }
+
+    pub(crate) fn ordinal(&self) -> syn::Expr {
+        let ty = &self.ty;
+        match &self.kind {
+            ForwardKind::Transparent(member) => syn::parse_quote! {
+                ordinal_map::Ordinal::ordinal(&self.#member)
+            },
+            ForwardKind::Via(_) => syn::parse_quote_spanned! { ty.span() =>
+                ordinal_map::Ordinal::ordinal(
+                    &::core::convert::Into::<#ty>::into(::core::clone::Clone::clone(self))
+                )
+            },
+        }
+    }
+
+    /// Convert a value of the forwarded type to `Option<Self>` or `Self`.
+    fn from_value(&self, value: syn::Expr, kind: FromOrdinalKind) -> syn::Expr {
+        let ty = &self.ty;
+        match (&self.kind, kind) {
+            (ForwardKind::Transparent(member), FromOrdinalKind::Checked) => syn::parse_quote! {
+                ::core::option::Option::Some(Self { #member: #value })
+            },
+            (ForwardKind::Transparent(member), FromOrdinalKind::Unchecked) => syn::parse_quote! {
+                Self { #member: #value }
+            },
+            (ForwardKind::Via(ViaKind::From), FromOrdinalKind::Checked) => {
+                syn::parse_quote_spanned! { ty.span() =>
+                    ::core::option::Option::Some(<Self as ::core::convert::From<#ty>>::from(#value))
+                }
+            }
+            (ForwardKind::Via(ViaKind::From), FromOrdinalKind::Unchecked) => {
+                syn::parse_quote_spanned! { ty.span() =>
+                    <Self as ::core::convert::From<#ty>>::from(#value)
+                }
+            }
+            (ForwardKind::Via(ViaKind::TryFrom), FromOrdinalKind::Checked) => {
+                syn::parse_quote_spanned! { ty.span() =>
+                    <Self as ::core::convert::TryFrom<#ty>>::try_from(#value).ok()
+                }
+            }
+            (ForwardKind::Via(ViaKind::TryFrom), FromOrdinalKind::Unchecked) => {
+                // `try_from` may reject ordinals below `ORDINAL_SIZE`, so this cannot be unchecked.
+                syn::parse_quote_spanned! { ty.span() =>
+                    match <Self as ::core::convert::TryFrom<#ty>>::try_from(#value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(_) => {
+                            ::core::panic!("no value for ordinal below `ORDINAL_SIZE`")
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_ordinal(
+        &self,
+        ordinal_var: &syn::Ident,
+        kind: FromOrdinalKind,
+    ) -> syn::Expr {
+        let ty = &self.ty;
+        match kind {
+            FromOrdinalKind::Checked => {
+                let value = self.from_value(syn::parse_quote! { value }, kind);
+                syn::parse_quote_spanned! { ty.span() =>
+                    match <#ty as ordinal_map::Ordinal>::from_ordinal(#ordinal_var) {
+                        ::core::option::Option::Some(value) => #value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+            }
+            FromOrdinalKind::Unchecked => {
+                let value: syn::Expr = syn::parse_quote_spanned! { ty.span() =>
+                    <#ty as ordinal_map::Ordinal>::from_ordinal_unchecked(#ordinal_var)
+                };
+                // `unsafe` with the call site span does not trigger `unsafe_code` lint.
+                self.from_value(syn::parse_quote! { unsafe { #value } }, kind)
+            }
+        }
+    }
+}
@@ -2,7 +2,14 @@
 //!
 //! This crate does not have a public API, everything is re-exported from `ordinal-map` crate.
 
+mod all_values;
+mod attrs;
+mod bounds;
+mod forward;
+mod names;
 mod ordinal;
+mod variant_ordinals;
+mod wide;
 
 fn wrapper(
     input: proc_macro::TokenStream,
@@ -16,13 +23,27 @@ fn wrapper(
 /// Derive `Ordinal` for structs or enums.
 ///
 /// See `Ordinal` trait for more information.
-#[proc_macro_derive(Ordinal)]
+#[proc_macro_derive(Ordinal, attributes(ordinal))]
 pub fn derive_ordinal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     wrapper(input, ordinal::derive_ordinal)
 }
 
+/// Derive `WideOrdinal` for structs or enums.
+///
+/// See `WideOrdinal` trait for more information.
+#[proc_macro_derive(WideOrdinal, attributes(ordinal))]
+pub fn derive_wide_ordinal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    wrapper(input, wide::derive_wide_ordinal)
+}
+
 #[proc_macro]
 #[doc(hidden)]
 pub fn impl_ordinal_for_tuple(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     wrapper(input, ordinal::impl_ordinal_for_tuple)
 }
+
+#[proc_macro]
+#[doc(hidden)]
+pub fn impl_wide_ordinal_for_tuple(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    wrapper(input, wide::impl_wide_ordinal_for_tuple)
+}
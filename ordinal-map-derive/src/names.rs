@@ -0,0 +1,110 @@
+use syn::spanned::Spanned;
+
+use crate::attrs::ContainerAttrs;
+use crate::attrs::VariantAttrs;
+use crate::variant_ordinals::VariantOrdinals;
+
+/// Generate `NAMES`, `name`, `from_name`, `FromStr` and `Display` for `#[ordinal(names)]`.
+pub(crate) fn derive_names(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    attrs: &ContainerAttrs,
+    span: proc_macro2::Span,
+    variant_ordinals: &VariantOrdinals,
+) -> syn::Result<proc_macro2::TokenStream> {
+    variant_ordinals.check_fieldless("names")?;
+    let variants = &variant_ordinals.variants;
+    let items = &variant_ordinals.items;
+    let ordinals_item = variant_ordinals.ordinals_item();
+
+    let mut names: Vec<String> = Vec::new();
+    for variant in variants {
+        let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+        let name = match (&variant_attrs.rename, attrs.rename_all) {
+            (Some(rename), _) => rename.value(),
+            (None, Some((rule, _))) => rule.apply(&variant.ident.to_string()),
+            (None, None) => variant.ident.to_string(),
+        };
+        let duplicate = names.iter().any(|n| match attrs.case_insensitive {
+            Some(_) => n.eq_ignore_ascii_case(&name),
+            None => *n == name,
+        });
+        if duplicate {
+            return Err(syn::Error::new(
+                variant.span(),
+                format!("duplicate variant name `{name}`"),
+            ));
+        }
+        names.push(name);
+    }
+
+    let n = variants.len();
+    let variant_idents: Vec<&syn::Ident> = variants.iter().map(|v| &v.ident).collect();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let from_name: syn::Expr = match attrs.case_insensitive {
+        None => syn::parse_quote_spanned! { span =>
+            match name {
+                #( #names => ::core::option::Option::Some(Self::#variant_idents), )*
+                _ => ::core::option::Option::None,
+            }
+        },
+        Some(_) => syn::parse_quote_spanned! { span =>
+            {
+                #(
+                    if name.eq_ignore_ascii_case(#names) {
+                        return ::core::option::Option::Some(Self::#variant_idents);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        },
+    };
+
+    Ok(quote::quote_spanned! { span =>
+        #[allow(clippy::all, dead_code)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Variant names in ordinal order.
+            pub const NAMES: [&'static str; #n] = {
+                #( #items )*
+                const DECLARED_NAMES: [&str; #n] = [ #( #names, )* ];
+                #ordinals_item
+                let mut names = [""; #n];
+                let mut i = 0;
+                while i < #n {
+                    names[ORDINALS[i]] = DECLARED_NAMES[i];
+                    i += 1;
+                }
+                names
+            };
+
+            /// Variant name.
+            pub const fn name(&self) -> &'static str {
+                match *self {
+                    #( Self::#variant_idents => #names, )*
+                }
+            }
+
+            /// Find the variant by name.
+            pub fn from_name(name: &str) -> ::core::option::Option<Self> {
+                #from_name
+            }
+        }
+
+        #[allow(clippy::all)]
+        impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = ordinal_map::ParseNameError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::from_name(s).ok_or(ordinal_map::ParseNameError)
+            }
+        }
+
+        #[allow(clippy::all)]
+        impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad(self.name())
+            }
+        }
+    })
+}
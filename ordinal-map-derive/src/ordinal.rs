@@ -1,6 +1,16 @@
 use quote::format_ident;
 use syn::spanned::Spanned;
 
+use crate::all_values::derive_all_values;
+use crate::attrs::ContainerAttrs;
+use crate::attrs::DiscriminantMode;
+use crate::attrs::FieldAttrs;
+use crate::attrs::VariantAttrs;
+use crate::bounds::ordinal_impl_generics;
+use crate::forward::ForwardGen;
+use crate::names::derive_names;
+use crate::variant_ordinals::VariantOrdinals;
+
 pub(crate) fn derive_ordinal(
     input: proc_macro2::TokenStream,
 ) -> syn::Result<proc_macro2::TokenStream> {
@@ -8,24 +18,70 @@ pub(crate) fn derive_ordinal(
     let input = syn::parse2::<syn::DeriveInput>(input)?;
 
     let ident = input.ident;
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
 
     let from_ordinal_ordinal_var = format_ident!("ordinal");
+    let generics = ordinal_impl_generics(
+        &input.generics,
+        &input.data,
+        &attrs,
+        &syn::parse_quote! { ordinal_map::Ordinal },
+    )?;
 
-    let (size, index_expr, from_index_expr) = match input.data {
+    let mut reverse = attrs.reverse;
+    let mut variant_ordinals = None;
+    let (size, index_expr, from_index_expr, from_index_unchecked_expr) = match input.data {
+        _ if attrs.transparent.is_some() || attrs.via.is_some() => {
+            let f = ForwardGen::new(&input.data, &attrs)?;
+            (
+                f.ordinal_size(),
+                f.ordinal(),
+                f.from_ordinal(&from_ordinal_ordinal_var, FromOrdinalKind::Checked),
+                f.from_ordinal(&from_ordinal_ordinal_var, FromOrdinalKind::Unchecked),
+            )
+        }
+        syn::Data::Enum(e) if attrs.discriminant.is_some() => {
+            let e = DiscriminantEnumGen::new(&ident, &input.generics, e, &attrs)?;
+            reverse &= !e.e.variants.is_empty();
+            if let Some((attr, _)) = attrs.fieldless_enum_attrs().first() {
+                variant_ordinals = Some(e.variant_ordinals(attr)?);
+            }
+            let from_index_expr = e.from_ordinal(&from_ordinal_ordinal_var);
+            let from_index_unchecked_expr = syn::parse_quote! {
+                unsafe { #from_index_expr.unwrap_unchecked() }
+            };
+            (
+                e.ordinal_size(),
+                e.ordinal(),
+                from_index_expr,
+                from_index_unchecked_expr,
+            )
+        }
+        _ if attrs.discriminant.is_some() => {
+            return Err(syn::Error::new(
+                attrs.discriminant.unwrap().1,
+                "`discriminant` can only be used with enums",
+            ));
+        }
         syn::Data::Struct(s) => {
-            let s = StructGen { s };
+            let s = StructGen::new(s, &attrs)?;
             (
-                s.ordinal_size()?.expr(),
+                s.ordinal_size(&ident)?,
                 s.ordinal()?.expr(),
-                s.from_ordinal(&from_ordinal_ordinal_var)?,
+                s.from_ordinal(&from_ordinal_ordinal_var, FromOrdinalKind::Checked)?,
+                s.from_ordinal(&from_ordinal_ordinal_var, FromOrdinalKind::Unchecked)?,
             )
         }
         syn::Data::Enum(e) => {
-            let e = EnumGen { e };
+            let e = EnumGen::new(e, &attrs)?;
+            // Nothing to reverse, and `ORDINAL_SIZE - 1` would overflow.
+            reverse &= !e.variants.is_empty();
+            variant_ordinals = Some(e.variant_ordinals());
             (
-                e.ordinal_size()?.expr(),
+                e.ordinal_size(&ident)?,
                 e.ordinal()?.expr(),
-                e.from_ordinal(&from_ordinal_ordinal_var)?,
+                e.from_ordinal(&from_ordinal_ordinal_var, FromOrdinalKind::Checked)?,
+                e.from_ordinal(&from_ordinal_ordinal_var, FromOrdinalKind::Unchecked)?,
             )
         }
         syn::Data::Union(_) => {
@@ -36,27 +92,163 @@ pub(crate) fn derive_ordinal(
         }
     };
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut fieldless_enum_impls = Vec::new();
+    if let Some((attr, attr_span)) = attrs.fieldless_enum_attrs().first() {
+        let Some(mut variant_ordinals) = variant_ordinals else {
+            return Err(syn::Error::new(
+                *attr_span,
+                format!("`{attr}` can only be used with enums"),
+            ));
+        };
+        if reverse {
+            variant_ordinals.reverse();
+        }
+        if attrs.names.is_some() {
+            fieldless_enum_impls.push(derive_names(
+                &ident,
+                &input.generics,
+                &attrs,
+                span,
+                &variant_ordinals,
+            )?);
+        }
+        if attrs.all_values.is_some() {
+            fieldless_enum_impls.push(derive_all_values(
+                &ident,
+                &input.generics,
+                span,
+                &variant_ordinals,
+            )?);
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let check_overflow = check_overflow();
     let check_zero_size = check_zero_size();
+    let check_zero_size_unchecked = check_zero_size_unchecked();
 
-    Ok(syn::parse_quote_spanned! { span =>
-        #[allow(clippy::all)]
-        impl #impl_generics ordinal_map::Ordinal for #ident #ty_generics #where_clause {
-            const ORDINAL_SIZE: usize = #size;
+    let (index_expr, reverse_ordinal, reverse_ordinal_unchecked): (
+        syn::Expr,
+        Option<syn::Stmt>,
+        Option<syn::Stmt>,
+    ) = if reverse {
+        (
+            syn::parse_quote! { Self::ORDINAL_SIZE - 1 - #index_expr },
+            Some(syn::parse_quote! {
+                let #from_ordinal_ordinal_var = match (Self::ORDINAL_SIZE - 1).checked_sub(#from_ordinal_ordinal_var) {
+                    ::core::option::Option::Some(ordinal) => ordinal,
+                    ::core::option::Option::None => return ::core::option::Option::None,
+                };
+            }),
+            Some(syn::parse_quote! {
+                let #from_ordinal_ordinal_var = Self::ORDINAL_SIZE - 1 - #from_ordinal_ordinal_var;
+            }),
+        )
+    } else {
+        (index_expr, None, None)
+    };
 
-            fn ordinal(&self) -> usize {
-                #check_overflow
-                #index_expr
+    let size: syn::Expr = match &attrs.max_size {
+        None => size,
+        Some(max_size) => {
+            let message = format!("`ORDINAL_SIZE` of `{ident}` is greater than `max_size`");
+            let size_var = format_ident!("size");
+            syn::parse_quote_spanned! { max_size.span() =>
+                {
+                    let #size_var: usize = #size;
+                    assert!(#size_var <= #max_size, #message);
+                    #size_var
+                }
             }
+        }
+    };
 
-            #[allow(unreachable_code, unused_mut)]
-            fn from_ordinal(#from_ordinal_ordinal_var: usize) -> std::option::Option<Self> {
-                #check_zero_size
-                #from_index_expr
+    // Overflow is reported where `ORDINAL_SIZE` is used, because it depends on the target.
+    // Evaluate `ORDINAL_SIZE` even if it is not used, so that `max_size`
+    // and gaps between discriminants are reported where the type is defined.
+    let check_size_attr = attrs.max_size.is_some()
+        || matches!(attrs.discriminant, Some((DiscriminantMode::Exact, _)));
+    let check_size: Option<syn::Item> = if check_size_attr && input.generics.params.is_empty() {
+        Some(syn::parse_quote! {
+            const _: usize = <#ident as ordinal_map::Ordinal>::ORDINAL_SIZE;
+        })
+    } else {
+        None
+    };
+
+    // The derive controls the mapping, so ordinals below `ORDINAL_SIZE` always have values,
+    // except for rejected values of `via = "TryFrom<T>"`, for which the method panics.
+    let from_ordinal_unchecked: syn::ImplItemFn = syn::parse_quote! {
+        #[allow(unreachable_code, unused_mut, unused_variables, unused_unsafe)]
+        unsafe fn from_ordinal_unchecked(#from_ordinal_ordinal_var: usize) -> Self {
+            #check_zero_size_unchecked
+            #reverse_ordinal_unchecked
+            #from_index_unchecked_expr
+        }
+    };
+
+    let wide_impl: Option<syn::ItemImpl> = attrs.wide.map(|wide_span| {
+        syn::parse_quote_spanned! { wide_span =>
+            #[allow(clippy::all)]
+            impl #impl_generics ordinal_map::WideOrdinal for #ident #ty_generics #where_clause {
+                const WIDE_ORDINAL_MAX: ::core::option::Option<u128> =
+                    match <Self as ordinal_map::Ordinal>::ORDINAL_SIZE {
+                        0 => ::core::option::Option::None,
+                        size => ::core::option::Option::Some(size as u128 - 1),
+                    };
+
+                fn wide_ordinal(&self) -> u128 {
+                    ordinal_map::Ordinal::ordinal(self) as u128
+                }
+
+                fn from_wide_ordinal(ordinal: u128) -> ::core::option::Option<Self> {
+                    match <usize as ::core::convert::TryFrom<u128>>::try_from(ordinal) {
+                        ::core::result::Result::Ok(ordinal) => {
+                            <Self as ordinal_map::Ordinal>::from_ordinal(ordinal)
+                        }
+                        ::core::result::Result::Err(_) => ::core::option::Option::None,
+                    }
+                }
             }
         }
+    });
+
+    // Generated code refers to the crate as `ordinal_map`,
+    // `#[ordinal(crate = "...")]` makes it an alias for the given path.
+    let use_krate: Option<syn::ItemUse> = attrs
+        .krate
+        .map(|krate| syn::parse_quote! { use #krate as ordinal_map; });
+
+    Ok(quote::quote_spanned! { span =>
+        const _: () = {
+            #use_krate
+
+            #[allow(clippy::all)]
+            impl #impl_generics ordinal_map::Ordinal for #ident #ty_generics #where_clause {
+                const ORDINAL_SIZE: usize = #size;
+
+                fn ordinal(&self) -> usize {
+                    #check_overflow
+                    #index_expr
+                }
+
+                #[allow(unreachable_code, unused_mut, unused_variables)]
+                fn from_ordinal(#from_ordinal_ordinal_var: usize) -> ::core::option::Option<Self> {
+                    #check_zero_size
+                    #reverse_ordinal
+                    #from_index_expr
+                }
+
+                #from_ordinal_unchecked
+            }
+
+            #wide_impl
+
+            #check_size
+
+            #( #fieldless_enum_impls )*
+        };
     })
 }
 
@@ -77,6 +269,64 @@ fn check_zero_size() -> syn::Stmt {
     }
 }
 
+fn check_zero_size_unchecked() -> syn::Stmt {
+    syn::parse_quote! {
+        // Same as in `from_ordinal`, but calling with zero size is undefined behavior.
+        if Self::ORDINAL_SIZE == 0 {
+            unsafe { ::core::hint::unreachable_unchecked() }
+        }
+    }
+}
+
+/// `ORDINAL_SIZE` of struct or enum variant, which panics
+/// during const evaluation with a readable message on overflow.
+fn checked_fields_size(
+    ident: &syn::Ident,
+    variant: Option<&syn::Ident>,
+    fields: &syn::Fields,
+) -> syn::Result<syn::Expr> {
+    // Single identifier, so fields from `macro_rules` fragments see it.
+    let size = format_ident!("size");
+    let mut stmts: Vec<syn::Stmt> = Vec::new();
+    for (i, (field, field_gen)) in fields.iter().zip(field_gens(fields)?).enumerate() {
+        let member = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => i.to_string(),
+        };
+        let member = match variant {
+            Some(variant) => format!("`{member}` of variant `{variant}`"),
+            None => format!("`{member}`"),
+        };
+        let message = format!(
+            "`ORDINAL_SIZE` of `{ident}` overflows `usize`: \
+            product of field sizes up to field {member} is greater than `usize::MAX`"
+        );
+        let field_size = field_gen.ordinal_size().expr();
+        stmts.push(syn::parse_quote_spanned! { field.ty.span() =>
+            let #size = match #size.checked_mul(#field_size) {
+                ::core::option::Option::Some(size) => size,
+                ::core::option::Option::None => panic!(#message),
+            };
+        });
+    }
+    Ok(syn::parse_quote! {
+        {
+            let #size: usize = 1;
+            #( #stmts )*
+            #size
+        }
+    })
+}
+
+/// Generate `from_ordinal` or `from_ordinal_unchecked` body.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum FromOrdinalKind {
+    /// Return `Option<Self>`, check the ordinal is in range.
+    Checked,
+    /// Return `Self`, assume the ordinal is in range.
+    Unchecked,
+}
+
 fn field_vars(fields: &syn::Fields) -> impl Iterator<Item = syn::Ident> + '_ {
     fields.iter().enumerate().map(|(i, f)| match &f.ident {
         Some(ident) => ident.clone(),
@@ -84,8 +334,152 @@ fn field_vars(fields: &syn::Fields) -> impl Iterator<Item = syn::Ident> + '_ {
     })
 }
 
-fn field_types(fields: &syn::Fields) -> impl Iterator<Item = &syn::Type> {
-    fields.iter().map(|f| &f.ty)
+/// Field of a struct, enum variant or tuple.
+#[derive(Clone)]
+struct FieldGen<'a> {
+    ty: &'a syn::Type,
+    attrs: FieldAttrs,
+}
+
+impl<'a> FieldGen<'a> {
+    fn new(ty: &'a syn::Type) -> FieldGen<'a> {
+        FieldGen {
+            ty,
+            attrs: FieldAttrs::default(),
+        }
+    }
+
+    /// Number of possible values of the field.
+    fn ordinal_size(&self) -> SizeExpr {
+        let ty = self.ty;
+        match &self.attrs.range {
+            None => SizeExpr::ordinal_size(ty),
+            Some(range) => {
+                let start = &range.start;
+                let end = &range.end;
+                let end_exclusive: syn::Expr = if range.inclusive {
+                    syn::parse_quote_spanned! { end.span() => (#end) as i128 + 1 }
+                } else {
+                    syn::parse_quote_spanned! { end.span() => (#end) as i128 }
+                };
+                SizeExpr::Syn(syn::parse_quote_spanned! { ty.span() =>
+                    const {
+                        let start = (#start) as i128;
+                        let end = #end_exclusive;
+                        assert!(start <= end, "`#[ordinal(range)]` start is greater than end");
+                        assert!(
+                            start == start as #ty as i128
+                                && (start == end || end - 1 == (end - 1) as #ty as i128),
+                            "`#[ordinal(range)]` does not fit into the field type"
+                        );
+                        (end - start) as usize
+                    }
+                })
+            }
+        }
+    }
+
+    /// Ordinal of the field value given by reference.
+    fn ordinal(&self, value: &syn::Expr) -> SizeExpr {
+        match &self.attrs.range {
+            None => SizeExpr::ordinal_expr(value),
+            Some(range) => {
+                let start = &range.start;
+                let size = self.ordinal_size().expr();
+                SizeExpr::Syn(syn::parse_quote_spanned! { value.span() =>
+                    {
+                        let ordinal = (*#value) as i128 - (#start) as i128;
+                        debug_assert!(
+                            ordinal >= 0 && (ordinal as usize) < #size,
+                            "field value is outside of `#[ordinal(range)]`"
+                        );
+                        ordinal as usize
+                    }
+                })
+            }
+        }
+    }
+
+    /// Field value from the ordinal which is known to be in range.
+    fn from_ordinal(&self, ordinal: &syn::Expr, kind: FromOrdinalKind) -> syn::Expr {
+        let ty = self.ty;
+        match &self.attrs.range {
+            None => match kind {
+                FromOrdinalKind::Checked => syn::parse_quote_spanned! { ty.span() =>
+                    <#ty as ordinal_map::Ordinal>::from_ordinal(#ordinal).unwrap()
+                },
+                FromOrdinalKind::Unchecked => {
+                    let value: syn::Expr = syn::parse_quote_spanned! { ty.span() =>
+                        <#ty as ordinal_map::Ordinal>::from_ordinal_unchecked(#ordinal)
+                    };
+                    // `unsafe` with the call site span does not trigger `unsafe_code` lint.
+                    syn::parse_quote! { unsafe { #value } }
+                }
+            },
+            Some(range) => {
+                let start = &range.start;
+                syn::parse_quote_spanned! { ty.span() =>
+                    ((#start) as i128 + (#ordinal) as i128) as #ty
+                }
+            }
+        }
+    }
+}
+
+/// Resolve `#[ordinal(field_order = [...])]` to field indices,
+/// from the most significant field to the least significant.
+fn field_order(
+    fields: &syn::Fields,
+    field_order: Option<&[syn::Member]>,
+    span: proc_macro2::Span,
+) -> syn::Result<Vec<usize>> {
+    let Some(field_order) = field_order else {
+        return Ok((0..fields.len()).collect());
+    };
+    let members: Vec<syn::Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(i)),
+        })
+        .collect();
+    let mut order = Vec::with_capacity(members.len());
+    for member in field_order {
+        let Some(i) = members.iter().position(|m| m == member) else {
+            return Err(syn::Error::new(
+                member.span(),
+                "unknown field in `field_order`",
+            ));
+        };
+        if order.contains(&i) {
+            return Err(syn::Error::new(
+                member.span(),
+                "duplicate field in `field_order`",
+            ));
+        }
+        order.push(i);
+    }
+    if order.len() != members.len() {
+        return Err(syn::Error::new(span, "`field_order` must list all fields"));
+    }
+    Ok(order)
+}
+
+fn permute<T: Clone>(items: &[T], order: &[usize]) -> Vec<T> {
+    order.iter().map(|&i| items[i].clone()).collect()
+}
+
+fn field_gens(fields: &syn::Fields) -> syn::Result<Vec<FieldGen<'_>>> {
+    fields
+        .iter()
+        .map(|f| {
+            Ok(FieldGen {
+                ty: &f.ty,
+                attrs: FieldAttrs::parse(&f.attrs)?,
+            })
+        })
+        .collect()
 }
 
 enum SizeExpr {
@@ -212,15 +606,16 @@ impl SizeExpr {
 
 struct StructGen {
     s: syn::DataStruct,
+    field_order: Vec<usize>,
 }
 
-fn struct_ordinal_size<'a>(types: impl IntoIterator<Item = &'a syn::Type>) -> SizeExpr {
-    SizeExpr::product(types.into_iter().map(SizeExpr::ordinal_size))
+fn struct_ordinal_size(fields: &[FieldGen]) -> SizeExpr {
+    SizeExpr::product(fields.iter().map(FieldGen::ordinal_size))
 }
 
 fn struct_ordinal<'a>(
     field_expr_refs: impl IntoIterator<Item = &'a syn::Expr>,
-    field_types: impl IntoIterator<Item = &'a syn::Type>,
+    fields: &[FieldGen],
     span: proc_macro2::Span,
 ) -> syn::Result<SizeExpr> {
     fn tuple_2_ordinal(a: SizeExpr, b: SizeExpr, b_count: SizeExpr) -> SizeExpr {
@@ -229,80 +624,103 @@ fn struct_ordinal<'a>(
 
     fn struct_ordinal_impl(
         field_expr_refs: &[&syn::Expr],
-        field_types: &[&syn::Type],
+        fields: &[FieldGen],
         span: proc_macro2::Span,
     ) -> syn::Result<SizeExpr> {
-        match (field_expr_refs, field_types) {
+        match (field_expr_refs, fields) {
             ([], []) => Ok(SizeExpr::Const(0)),
-            ([field_expr], [_]) => Ok(SizeExpr::ordinal_expr(field_expr)),
-            ([first_expr, rem_exprs @ ..], [_first_ty, rem_tys @ ..]) => {
-                let rem_count =
-                    SizeExpr::product(rem_tys.iter().copied().map(|e| SizeExpr::ordinal_size(e)));
-                let first_expr = SizeExpr::ordinal_expr(first_expr);
-                let rem_expr = struct_ordinal_impl(rem_exprs, rem_tys, span)?;
+            ([field_expr], [field]) => Ok(field.ordinal(field_expr)),
+            ([first_expr, rem_exprs @ ..], [first_field, rem_fields @ ..]) => {
+                let rem_count = struct_ordinal_size(rem_fields);
+                let first_expr = first_field.ordinal(first_expr);
+                let rem_expr = struct_ordinal_impl(rem_exprs, rem_fields, span)?;
                 Ok(tuple_2_ordinal(first_expr, rem_expr, rem_count))
             }
             _ => Err(syn::Error::new(
                 span,
-                "Mismatched field_expr_refs and field_types",
+                "Mismatched field_expr_refs and fields",
             )),
         }
     }
 
     let expr = struct_ordinal_impl(
         field_expr_refs.into_iter().collect::<Vec<_>>().as_slice(),
-        field_types.into_iter().collect::<Vec<_>>().as_slice(),
+        fields,
         span,
     )?;
     Ok(expr)
 }
 
-fn struct_from_ordinal<'a>(
+/// `field_vars` and `fields` are in declaration order,
+/// `order` lists field indices from the most significant to the least significant.
+fn struct_from_ordinal(
     ordinal: &syn::Expr,
     field_vars: impl IntoIterator<Item = syn::Ident>,
-    field_types: impl IntoIterator<Item = &'a syn::Type>,
+    fields: &[FieldGen],
+    order: &[usize],
     span: proc_macro2::Span,
+    kind: FromOrdinalKind,
     constructor: impl FnOnce(Vec<syn::Expr>) -> syn::Expr,
 ) -> syn::Result<syn::Expr> {
     let field_vars = Vec::from_iter(field_vars);
-    let field_types = Vec::from_iter(field_types);
 
+    // Same span everywhere, otherwise hygiene breaks when field types come from `macro_rules`.
+    let rem = format_ident!("rem");
     let mut stmts: Vec<syn::Stmt> = Vec::new();
     stmts.push(syn::parse_quote_spanned! { span =>
-        let mut rem = #ordinal;
+        let mut #rem = #ordinal;
     });
-    for (field_var, field_ty) in field_vars.iter().zip(&field_types).rev() {
-        let field_ordinal_size = SizeExpr::ordinal_size(field_ty).const_expr();
+    for &i in order.iter().rev() {
+        let (field_var, field) = (&field_vars[i], &fields[i]);
+        let field_ordinal_size = field.ordinal_size().const_expr();
+        let field_value = field.from_ordinal(
+            &syn::parse_quote_spanned! { field_var.span() =>
+                #rem % #field_ordinal_size
+            },
+            kind,
+        );
         stmts.extend([
             syn::parse_quote_spanned! { field_var.span() =>
-                let #field_var = <#field_ty as ordinal_map::Ordinal>::from_ordinal(
-                    rem % #field_ordinal_size).unwrap();
+                let #field_var = #field_value;
             },
             syn::parse_quote_spanned! { field_var.span() =>
-                rem /= #field_ordinal_size;
+                #rem /= #field_ordinal_size;
             },
         ]);
     }
-    stmts.push(syn::parse_quote_spanned! { span =>
-        if rem != 0 {
-            return None
-        }
-    });
     let build = constructor(
         field_vars
             .iter()
             .map(|f| syn::parse_quote! { #f })
             .collect(),
     );
-    Ok(syn::parse_quote_spanned! { span =>
-        {
-            #( #stmts )*
-            std::option::Option::Some(#build)
-        }
-    })
+    match kind {
+        FromOrdinalKind::Checked => Ok(syn::parse_quote_spanned! { span =>
+            {
+                #( #stmts )*
+                if #rem != 0 {
+                    return None
+                }
+                ::core::option::Option::Some(#build)
+            }
+        }),
+        FromOrdinalKind::Unchecked => Ok(syn::parse_quote_spanned! { span =>
+            {
+                #( #stmts )*
+                let _ignore = #rem;
+                #build
+            }
+        }),
+    }
 }
 
 impl StructGen {
+    fn new(s: syn::DataStruct, attrs: &ContainerAttrs) -> syn::Result<StructGen> {
+        let field_order =
+            field_order(&s.fields, attrs.field_order.as_deref(), s.struct_token.span)?;
+        Ok(StructGen { s, field_order })
+    }
+
     fn field_ref_exprs(&self) -> impl Iterator<Item = syn::Expr> + '_ {
         self.s.fields.iter().enumerate().map(|(i, f)| {
             let i = syn::Index::from(i);
@@ -314,30 +732,37 @@ impl StructGen {
     }
 
     /// Generate `const ORDINAL_SIZE = ` RHS.
-    fn ordinal_size(&self) -> syn::Result<SizeExpr> {
-        Ok(struct_ordinal_size(field_types(&self.s.fields)))
+    fn ordinal_size(&self, ident: &syn::Ident) -> syn::Result<syn::Expr> {
+        checked_fields_size(ident, None, &self.s.fields)
     }
 
     /// Generate `fn ordinal(&self) -> usize` body.
     fn ordinal(&self) -> syn::Result<SizeExpr> {
-        let field_types: Vec<_> = field_types(&self.s.fields).collect();
+        let fields = field_gens(&self.s.fields)?;
         let field_exprs: Vec<_> = self.field_ref_exprs().collect();
         struct_ordinal(
-            &field_exprs,
-            field_types.iter().copied(),
+            &permute(&field_exprs, &self.field_order),
+            &permute(&fields, &self.field_order),
             self.s.struct_token.span,
         )
     }
 
-    /// Generate `fn from_ordinal(ordinal: usize) -> Option<Self>` body.
-    fn from_ordinal(&self, ordinal_var: &syn::Ident) -> syn::Result<syn::Expr> {
-        let field_types: Vec<_> = field_types(&self.s.fields).collect();
+    /// Generate `fn from_ordinal(ordinal: usize) -> Option<Self>` body
+    /// or `unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self` body.
+    fn from_ordinal(
+        &self,
+        ordinal_var: &syn::Ident,
+        kind: FromOrdinalKind,
+    ) -> syn::Result<syn::Expr> {
+        let fields = field_gens(&self.s.fields)?;
         let field_vars: Vec<_> = field_vars(&self.s.fields).collect();
         struct_from_ordinal(
             &syn::parse_quote_spanned! { ordinal_var.span() => #ordinal_var },
             field_vars.iter().cloned(),
-            field_types,
+            &fields,
+            &self.field_order,
             self.s.struct_token.span,
+            kind,
             |exprs| match &self.s.fields {
                 syn::Fields::Unit => syn::parse_quote_spanned! { self.s.struct_token.span =>
                     Self
@@ -361,33 +786,150 @@ impl StructGen {
     }
 }
 
+struct VariantGen {
+    variant: syn::Variant,
+    field_order: Vec<usize>,
+}
+
+fn check_rename(variant_attrs: &VariantAttrs, attrs: &ContainerAttrs) -> syn::Result<()> {
+    match &variant_attrs.rename {
+        Some(rename) if attrs.names.is_none() => Err(syn::Error::new(
+            rename.span(),
+            "`rename` requires `names` on the enum",
+        )),
+        _ => Ok(()),
+    }
+}
+
 struct EnumGen {
     e: syn::DataEnum,
+    /// Variants in ordinal order.
+    variants: Vec<VariantGen>,
 }
 
 impl EnumGen {
+    fn new(e: syn::DataEnum, attrs: &ContainerAttrs) -> syn::Result<EnumGen> {
+        if let Some(field_order) = &attrs.field_order {
+            return Err(syn::Error::new(
+                field_order.first().map_or(e.enum_token.span, |m| m.span()),
+                "`field_order` must be specified on enum variants",
+            ));
+        }
+        let mut variants = Vec::new();
+        let mut orders = Vec::new();
+        for variant in &e.variants {
+            let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+            check_rename(&variant_attrs, attrs)?;
+            let field_order = field_order(
+                &variant.fields,
+                variant_attrs.field_order.as_deref(),
+                variant.span(),
+            )?;
+            if let Some(order) = variant_attrs.order {
+                let value = order.base10_parse::<u64>()?;
+                if orders.iter().any(|(_, v)| *v == value) {
+                    return Err(syn::Error::new(order.span(), "duplicate `order`"));
+                }
+                orders.push((order, value));
+            }
+            variants.push(VariantGen {
+                variant: variant.clone(),
+                field_order,
+            });
+        }
+        if !orders.is_empty() {
+            if orders.len() != variants.len() {
+                return Err(syn::Error::new(
+                    e.enum_token.span,
+                    "`order` must be specified for all variants or none",
+                ));
+            }
+            let mut variants_with_order: Vec<_> = variants
+                .into_iter()
+                .zip(orders.into_iter().map(|(_, v)| v))
+                .collect();
+            variants_with_order.sort_by_key(|(_, order)| *order);
+            variants = variants_with_order.into_iter().map(|(v, _)| v).collect();
+        }
+        Ok(EnumGen { e, variants })
+    }
+
+    /// Ordinals of variants in declaration order, before `reverse`.
+    fn variant_ordinals(&self) -> VariantOrdinals {
+        let known_ordinals: Vec<usize> = self
+            .e
+            .variants
+            .iter()
+            .map(|v| {
+                self.variants
+                    .iter()
+                    .position(|g| g.variant.ident == v.ident)
+                    .unwrap()
+            })
+            .collect();
+        VariantOrdinals {
+            variants: self.e.variants.iter().cloned().collect(),
+            items: Vec::new(),
+            ordinals: known_ordinals
+                .iter()
+                .map(|o| SizeExpr::expr_const(*o as u64))
+                .collect(),
+            known_ordinals: Some(known_ordinals),
+        }
+    }
+
     fn size_of_first_variants(&self, i: usize) -> syn::Result<SizeExpr> {
         let sizes = self
-            .e
             .variants
             .iter()
             .take(i)
-            .map(|v| struct_ordinal_size(v.fields.iter().map(|f| &f.ty)));
+            .map(|v| Ok(struct_ordinal_size(&field_gens(&v.variant.fields)?)))
+            .collect::<syn::Result<Vec<_>>>()?;
         Ok(SizeExpr::sum(sizes))
     }
 
-    fn ordinal_size(&self) -> syn::Result<SizeExpr> {
-        self.size_of_first_variants(self.e.variants.len())
+    fn ordinal_size(&self, ident: &syn::Ident) -> syn::Result<syn::Expr> {
+        // Single identifier, so variants from `macro_rules` fragments see it.
+        let size = format_ident!("size");
+        let mut stmts: Vec<syn::Stmt> = Vec::new();
+        for v in &self.variants {
+            let variant = &v.variant.ident;
+            let variant_size = checked_fields_size(ident, Some(variant), &v.variant.fields)?;
+            let message = format!(
+                "`ORDINAL_SIZE` of `{ident}` overflows `usize`: \
+                sum of variant sizes up to variant `{variant}` is greater than `usize::MAX`"
+            );
+            stmts.push(syn::parse_quote_spanned! { v.variant.span() =>
+                let #size = match #size.checked_add(#variant_size) {
+                    ::core::option::Option::Some(size) => size,
+                    ::core::option::Option::None => panic!(#message),
+                };
+            });
+        }
+        Ok(syn::parse_quote! {
+            {
+                let #size: usize = 0;
+                #( #stmts )*
+                #size
+            }
+        })
     }
 
     fn ordinal(&self) -> syn::Result<SizeExpr> {
-        if self.e.variants.is_empty() {
+        if self.variants.is_empty() {
             // Special case because rust doesn't like `match &empty_enum {}`.
             return Ok(SizeExpr::Const(0));
         }
 
         let mut arms: Vec<syn::Arm> = Vec::new();
-        for (i, variant) in self.e.variants.iter().enumerate() {
+        for (
+            i,
+            VariantGen {
+                variant,
+                field_order,
+            },
+        ) in self.variants.iter().enumerate()
+        {
             let variant_name = &variant.ident;
             let vars = Vec::from_iter(field_vars(&variant.fields));
             let vars = vars.as_slice();
@@ -410,12 +952,10 @@ impl EnumGen {
                     }
                 }
             };
+            let var_exprs: Vec<syn::Expr> = vars.iter().map(|v| syn::parse_quote! { #v }).collect();
             let struct_ordinal = struct_ordinal(
-                vars.iter()
-                    .map(|v| syn::parse_quote! { #v })
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-                field_types(&variant.fields),
+                &permute(&var_exprs, field_order),
+                &permute(&field_gens(&variant.fields)?, field_order),
                 variant.span(),
             )?;
             let value = SizeExpr::sum([size_of_first_variants, struct_ordinal]).expr();
@@ -434,50 +974,333 @@ impl EnumGen {
         }))
     }
 
-    fn from_ordinal(&self, ordinal_var: &syn::Ident) -> syn::Result<syn::Expr> {
-        let mut stmts: Vec<syn::Stmt> = Vec::new();
-        for (i, variant) in self.e.variants.iter().enumerate() {
-            let size_of_first_variants_before = self.size_of_first_variants(i)?.const_expr();
-            let size_of_first_variants_including = self.size_of_first_variants(i + 1)?.const_expr();
-            let rem_ordinal = syn::parse_quote_spanned! { variant.span() =>
-                #ordinal_var - #size_of_first_variants_before
-            };
-            let variant_name = &variant.ident;
-            let struct_from_ordinal = struct_from_ordinal(
-                &rem_ordinal,
-                field_vars(&variant.fields),
-                field_types(&variant.fields),
-                variant.span(),
-                |exprs| match &variant.fields {
-                    syn::Fields::Unit => syn::parse_quote_spanned! { variant.span() =>
-                        Self::#variant_name
+    /// Decode the variant `i` given the ordinal known to be
+    /// not less than the ordinal of the first value of the variant.
+    fn variant_from_ordinal(
+        &self,
+        ordinal_var: &syn::Ident,
+        i: usize,
+        kind: FromOrdinalKind,
+    ) -> syn::Result<syn::Expr> {
+        let VariantGen {
+            variant,
+            field_order,
+        } = &self.variants[i];
+        let size_of_first_variants_before = self.size_of_first_variants(i)?.const_expr();
+        let rem_ordinal = syn::parse_quote_spanned! { variant.span() =>
+            #ordinal_var - #size_of_first_variants_before
+        };
+        struct_from_ordinal(
+            &rem_ordinal,
+            field_vars(&variant.fields),
+            &field_gens(&variant.fields)?,
+            field_order,
+            variant.span(),
+            kind,
+            |exprs| variant_constructor(variant, exprs),
+        )
+    }
+
+    /// Binary search over variant offsets for variants in range `lo..hi`.
+    fn from_ordinal_binary_search(
+        &self,
+        ordinal_var: &syn::Ident,
+        lo: usize,
+        hi: usize,
+        kind: FromOrdinalKind,
+    ) -> syn::Result<syn::Expr> {
+        assert!(lo < hi);
+        if lo + 1 == hi {
+            return self.variant_from_ordinal(ordinal_var, lo, kind);
+        }
+        let mid = lo + (hi - lo) / 2;
+        let size_of_first_variants_mid = self.size_of_first_variants(mid)?.const_expr();
+        let left = self.from_ordinal_binary_search(ordinal_var, lo, mid, kind)?;
+        let right = self.from_ordinal_binary_search(ordinal_var, mid, hi, kind)?;
+        Ok(syn::parse_quote_spanned! { self.e.enum_token.span =>
+            if #ordinal_var < #size_of_first_variants_mid {
+                #left
+            } else {
+                #right
+            }
+        })
+    }
+
+    fn from_ordinal(
+        &self,
+        ordinal_var: &syn::Ident,
+        kind: FromOrdinalKind,
+    ) -> syn::Result<syn::Expr> {
+        let none: syn::Expr = match kind {
+            FromOrdinalKind::Checked => syn::parse_quote! { ::core::option::Option::None },
+            FromOrdinalKind::Unchecked => {
+                syn::parse_quote! { unsafe { ::core::hint::unreachable_unchecked() } }
+            }
+        };
+
+        if self.variants.is_empty() {
+            return Ok(syn::parse_quote_spanned! { self.e.enum_token.span =>
+                {
+                    let _ignore = #ordinal_var;
+                    #none
+                }
+            });
+        }
+
+        if self.variants.iter().all(|v| v.variant.fields.is_empty()) {
+            // Each variant has exactly one value, so the ordinal is the variant index.
+            let arms = self.variants.iter().enumerate().map(|(i, v)| -> syn::Arm {
+                let i = SizeExpr::expr_const(i as u64);
+                let value = variant_constructor(&v.variant, Vec::new());
+                match kind {
+                    FromOrdinalKind::Checked => syn::parse_quote_spanned! { v.variant.span() =>
+                        #i => ::core::option::Option::Some(#value)
+                    },
+                    FromOrdinalKind::Unchecked => syn::parse_quote_spanned! { v.variant.span() =>
+                        #i => #value
                     },
-                    syn::Fields::Unnamed(_) => {
-                        syn::parse_quote_spanned! { variant.span() =>
-                            Self::#variant_name( #( #exprs ),* )
+                }
+            });
+            return Ok(syn::parse_quote_spanned! { self.e.enum_token.span =>
+                match #ordinal_var {
+                    #( #arms, )*
+                    _ => #none,
+                }
+            });
+        }
+
+        let search = self.from_ordinal_binary_search(ordinal_var, 0, self.variants.len(), kind)?;
+        match kind {
+            // The last variant may be empty, so its decoding cannot reject values past it.
+            FromOrdinalKind::Checked => Ok(syn::parse_quote_spanned! { self.e.enum_token.span =>
+                if #ordinal_var < Self::ORDINAL_SIZE {
+                    #search
+                } else {
+                    #none
+                }
+            }),
+            FromOrdinalKind::Unchecked => Ok(search),
+        }
+    }
+}
+
+fn variant_constructor(variant: &syn::Variant, exprs: Vec<syn::Expr>) -> syn::Expr {
+    let variant_name = &variant.ident;
+    match &variant.fields {
+        syn::Fields::Unit => syn::parse_quote_spanned! { variant.span() =>
+            Self::#variant_name
+        },
+        syn::Fields::Unnamed(_) => {
+            syn::parse_quote_spanned! { variant.span() =>
+                Self::#variant_name( #( #exprs ),* )
+            }
+        }
+        syn::Fields::Named(_) => {
+            syn::parse_quote_spanned! { variant.span() =>
+                Self::#variant_name { #( #exprs ),* }
+            }
+        }
+    }
+}
+
+/// Fieldless enum with `#[ordinal(discriminant)]`.
+struct DiscriminantEnumGen<'a> {
+    ident: &'a syn::Ident,
+    e: syn::DataEnum,
+    mode: DiscriminantMode,
+}
+
+impl<'a> DiscriminantEnumGen<'a> {
+    fn new(
+        ident: &'a syn::Ident,
+        generics: &syn::Generics,
+        e: syn::DataEnum,
+        attrs: &ContainerAttrs,
+    ) -> syn::Result<DiscriminantEnumGen<'a>> {
+        let (mode, span) = attrs.discriminant.unwrap();
+        if !generics.params.is_empty() {
+            return Err(syn::Error::new(
+                generics.span(),
+                "`discriminant` cannot be used with generic enums",
+            ));
+        }
+        if attrs.field_order.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "`discriminant` cannot be used with `field_order`",
+            ));
+        }
+        for variant in &e.variants {
+            if !variant.fields.is_empty() {
+                return Err(syn::Error::new(
+                    variant.fields.span(),
+                    "`discriminant` can only be used with fieldless enums",
+                ));
+            }
+            let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+            check_rename(&variant_attrs, attrs)?;
+            if variant_attrs.order.is_some() || variant_attrs.field_order.is_some() {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "`discriminant` cannot be used with variant attributes",
+                ));
+            }
+        }
+        Ok(DiscriminantEnumGen { ident, e, mode })
+    }
+
+    fn variant_count(&self) -> syn::Expr {
+        SizeExpr::expr_const(self.e.variants.len() as u64)
+    }
+
+    /// `const DISCRIMINANTS: [i128; N]` item in declaration order.
+    fn discriminants(&self) -> syn::Item {
+        let ident = self.ident;
+        let n = self.variant_count();
+        let variants = self.e.variants.iter().map(|v| &v.ident);
+        syn::parse_quote! {
+            const DISCRIMINANTS: [i128; #n] = [ #( #ident::#variants as i128, )* ];
+        }
+    }
+
+    /// `const RANKS: [usize; N]`: dense ordinal of each variant in declaration order.
+    fn ranks(&self) -> syn::Item {
+        let n = self.variant_count();
+        syn::parse_quote! {
+            const RANKS: [usize; #n] = {
+                let mut ranks = [0; #n];
+                let mut i = 0;
+                while i < #n {
+                    let mut j = 0;
+                    while j < #n {
+                        if DISCRIMINANTS[j] < DISCRIMINANTS[i] {
+                            ranks[i] += 1;
                         }
+                        j += 1;
                     }
-                    syn::Fields::Named(_) => {
-                        syn::parse_quote_spanned! { variant.span() =>
-                            Self::#variant_name { #( #exprs ),* }
+                    i += 1;
+                }
+                ranks
+            };
+        }
+    }
+
+    /// Ordinals of variants in declaration order, before `reverse`.
+    fn variant_ordinals(&self, attr: &str) -> syn::Result<VariantOrdinals> {
+        if self.mode == DiscriminantMode::Exact {
+            return Err(syn::Error::new(
+                self.e.enum_token.span,
+                format!("`{attr}` cannot be used with `discriminant`, use `discriminant(dense)`"),
+            ));
+        }
+        Ok(VariantOrdinals {
+            variants: self.e.variants.iter().cloned().collect(),
+            items: vec![self.discriminants(), self.ranks()],
+            ordinals: (0..self.e.variants.len())
+                .map(|i| {
+                    let i = syn::Index::from(i);
+                    syn::parse_quote! { RANKS[#i] }
+                })
+                .collect(),
+            known_ordinals: None,
+        })
+    }
+
+    fn ordinal_size(&self) -> syn::Expr {
+        match self.mode {
+            DiscriminantMode::Dense => self.variant_count(),
+            DiscriminantMode::Exact => {
+                let discriminants = self.discriminants();
+                syn::parse_quote! {
+                    {
+                        #discriminants
+                        let mut size = 0usize;
+                        let mut i = 0;
+                        while i < DISCRIMINANTS.len() {
+                            let d = DISCRIMINANTS[i];
+                            assert!(d >= 0, "negative discriminant");
+                            assert!(d < usize::MAX as i128, "discriminant is too large");
+                            if d as usize + 1 > size {
+                                size = d as usize + 1;
+                            }
+                            i += 1;
                         }
+                        // Discriminants are distinct, so this means they are exactly `0..N`.
+                        assert!(
+                            size == DISCRIMINANTS.len(),
+                            "discriminants must be exactly `0..N`, use `discriminant(dense)`"
+                        );
+                        size
                     }
-                },
-            )?;
-            stmts.push(syn::parse_quote_spanned! {
-                variant.span() =>
-                if #ordinal_var < #size_of_first_variants_including {
-                    return #struct_from_ordinal;
                 }
-            })
+            }
         }
-        Ok(syn::parse_quote_spanned! { self.e.enum_token.span =>
-            {
-                #( #stmts )*
-                let _ignore = #ordinal_var;
-                std::option::Option::None
+    }
+
+    fn ordinal(&self) -> syn::Expr {
+        if self.e.variants.is_empty() {
+            return SizeExpr::expr_const(0);
+        }
+        let variants = self.e.variants.iter().map(|v| &v.ident);
+        match self.mode {
+            DiscriminantMode::Exact => syn::parse_quote! {
+                match self {
+                    #( Self::#variants => Self::#variants as usize, )*
+                }
+            },
+            DiscriminantMode::Dense => {
+                let discriminants = self.discriminants();
+                let ranks = self.ranks();
+                let indices = (0..self.e.variants.len()).map(syn::Index::from);
+                syn::parse_quote! {
+                    {
+                        #discriminants
+                        #ranks
+                        match self {
+                            #( Self::#variants => RANKS[#indices], )*
+                        }
+                    }
+                }
             }
-        })
+        }
+    }
+
+    fn from_ordinal(&self, ordinal_var: &syn::Ident) -> syn::Expr {
+        let variants: Vec<_> = self.e.variants.iter().map(|v| &v.ident).collect();
+        match self.mode {
+            DiscriminantMode::Exact => syn::parse_quote! {
+                match #ordinal_var {
+                    #( o if o == Self::#variants as usize => ::core::option::Option::Some(Self::#variants), )*
+                    _ => ::core::option::Option::None,
+                }
+            },
+            DiscriminantMode::Dense => {
+                let n = self.variant_count();
+                let discriminants = self.discriminants();
+                let ranks = self.ranks();
+                let indices = (0..self.e.variants.len()).map(syn::Index::from);
+                syn::parse_quote! {
+                    {
+                        #discriminants
+                        #ranks
+                        // Variant index in declaration order by dense ordinal.
+                        const VARIANTS: [usize; #n] = {
+                            let mut variants = [0; #n];
+                            let mut i = 0;
+                            while i < #n {
+                                variants[RANKS[i]] = i;
+                                i += 1;
+                            }
+                            variants
+                        };
+                        match VARIANTS.get(#ordinal_var) {
+                            #( ::core::option::Option::Some(#indices) => ::core::option::Option::Some(Self::#variants), )*
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -490,28 +1313,32 @@ fn impl_ordinal_for_tuple_n(n: u32) -> syn::Result<syn::ItemImpl> {
         .map(|i| format_ident!("{}", char::try_from('a' as u32 + i).unwrap()))
         .collect();
     let field_types: Vec<syn::Type> = params.iter().map(|p| syn::parse_quote! { #p }).collect();
+    let fields: Vec<FieldGen> = field_types.iter().map(FieldGen::new).collect();
     let field_ref_exprs: Vec<syn::Expr> = (0..n)
         .map(|i| {
             let i = syn::Index::from(i as usize);
             syn::parse_quote! { &self.#i }
         })
         .collect();
-    let ordinal_size = struct_ordinal_size(&field_types).expr();
-    let ordinal = struct_ordinal(
-        &field_ref_exprs,
-        &field_types,
-        proc_macro2::Span::call_site(),
-    )?
-    .expr();
-    let from_ordinal = struct_from_ordinal(
-        &syn::parse_quote_spanned! { ordinal_var.span() => #ordinal_var },
-        vars,
-        &field_types,
-        proc_macro2::Span::call_site(),
-        |exprs| syn::parse_quote! { ( #( #exprs, )* ) },
-    )?;
+    let ordinal_size = struct_ordinal_size(&fields).expr();
+    let ordinal = struct_ordinal(&field_ref_exprs, &fields, proc_macro2::Span::call_site())?.expr();
+    let order: Vec<usize> = (0..fields.len()).collect();
+    let from_ordinal = |kind| {
+        struct_from_ordinal(
+            &syn::parse_quote_spanned! { ordinal_var.span() => #ordinal_var },
+            vars.iter().cloned(),
+            &fields,
+            &order,
+            proc_macro2::Span::call_site(),
+            kind,
+            |exprs| syn::parse_quote! { ( #( #exprs, )* ) },
+        )
+    };
+    let from_ordinal_unchecked = from_ordinal(FromOrdinalKind::Unchecked)?;
+    let from_ordinal = from_ordinal(FromOrdinalKind::Checked)?;
     let check_overflow = check_overflow();
     let check_zero_size = check_zero_size();
+    let check_zero_size_unchecked = check_zero_size_unchecked();
     Ok(syn::parse_quote! {
         impl< #( #params: ordinal_map::Ordinal, )* > ordinal_map::Ordinal for ( #( #params, )* ) {
             const ORDINAL_SIZE: usize = #ordinal_size;
@@ -521,10 +1348,16 @@ fn impl_ordinal_for_tuple_n(n: u32) -> syn::Result<syn::ItemImpl> {
                 #ordinal
             }
 
-            fn from_ordinal(#ordinal_var: usize) -> std::option::Option<Self> {
+            fn from_ordinal(#ordinal_var: usize) -> ::core::option::Option<Self> {
                 #check_zero_size
                 #from_ordinal
             }
+
+            #[allow(unused_unsafe, clippy::unused_unit)]
+            unsafe fn from_ordinal_unchecked(#ordinal_var: usize) -> Self {
+                #check_zero_size_unchecked
+                #from_ordinal_unchecked
+            }
         }
     })
 }
@@ -0,0 +1,50 @@
+use syn::spanned::Spanned;
+
+/// Variants of a fieldless enum with their ordinals,
+/// input for `#[ordinal(names)]` and `#[ordinal(all_values)]`.
+pub(crate) struct VariantOrdinals {
+    /// Variants in declaration order.
+    pub(crate) variants: Vec<syn::Variant>,
+    /// Items referenced by `ordinals`.
+    pub(crate) items: Vec<syn::Item>,
+    /// Constant ordinal of each variant in declaration order.
+    pub(crate) ordinals: Vec<syn::Expr>,
+    /// Same as `ordinals` when they are known at macro expansion time.
+    pub(crate) known_ordinals: Option<Vec<usize>>,
+}
+
+impl VariantOrdinals {
+    /// Apply `#[ordinal(reverse)]`.
+    pub(crate) fn reverse(&mut self) {
+        let n = self.variants.len();
+        for ordinal in &mut self.ordinals {
+            *ordinal = syn::parse_quote! { #n - 1 - #ordinal };
+        }
+        if let Some(known_ordinals) = &mut self.known_ordinals {
+            for ordinal in known_ordinals {
+                *ordinal = n - 1 - *ordinal;
+            }
+        }
+    }
+
+    pub(crate) fn check_fieldless(&self, attr: &str) -> syn::Result<()> {
+        for variant in &self.variants {
+            if !variant.fields.is_empty() {
+                return Err(syn::Error::new(
+                    variant.fields.span(),
+                    format!("`{attr}` can only be used with fieldless enums"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// `const ORDINALS: [usize; N]` item: ordinal by declaration index.
+    pub(crate) fn ordinals_item(&self) -> syn::Item {
+        let n = self.variants.len();
+        let ordinals = &self.ordinals;
+        syn::parse_quote! {
+            const ORDINALS: [usize; #n] = [ #( #ordinals, )* ];
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use quote::format_ident;
+use syn::spanned::Spanned;
+
+use crate::attrs::ContainerAttrs;
+use crate::bounds::ordinal_impl_generics;
+
+/// Only these container attributes are supported by `#[derive(WideOrdinal)]`.
+const SUPPORTED_ATTRS: [&str; 2] = ["bound", "crate"];
+
+fn check_attrs(input: &syn::DeriveInput) -> syn::Result<()> {
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("ordinal")) {
+        attr.parse_nested_meta(|meta| {
+            if SUPPORTED_ATTRS.iter().any(|a| meta.path.is_ident(a)) {
+                meta.value()?.parse::<syn::LitStr>()?;
+                Ok(())
+            } else {
+                Err(meta.error("attribute is not supported by `WideOrdinal` derive"))
+            }
+        })?;
+    }
+    let fields: Vec<&syn::Field> = match &input.data {
+        syn::Data::Struct(s) => s.fields.iter().collect(),
+        syn::Data::Enum(e) => {
+            for variant in &e.variants {
+                if let Some(attr) = variant.attrs.iter().find(|a| a.path().is_ident("ordinal")) {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "variant attributes are not supported by `WideOrdinal` derive",
+                    ));
+                }
+            }
+            e.variants.iter().flat_map(|v| &v.fields).collect()
+        }
+        syn::Data::Union(_) => Vec::new(),
+    };
+    for field in fields {
+        if let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("ordinal")) {
+            return Err(syn::Error::new(
+                attr.span(),
+                "field attributes are not supported by `WideOrdinal` derive",
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn field_name(i: usize, field: &syn::Field) -> String {
+    match &field.ident {
+        Some(ident) => ident.to_string(),
+        None => i.to_string(),
+    }
+}
+
+fn field_vars(fields: &syn::Fields) -> Vec<syn::Ident> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(ident) => format_ident!("{}", ident),
+            None => format_ident!("f{}", i),
+        })
+        .collect()
+}
+
+/// Struct, enum variant or tuple fields.
+struct FieldsGen<'a> {
+    /// Type name for error messages.
+    name: &'a str,
+    variant: Option<&'a syn::Ident>,
+    fields: &'a syn::Fields,
+}
+
+impl FieldsGen<'_> {
+    fn location(&self) -> String {
+        match self.variant {
+            Some(variant) => format!(" of variant `{variant}`"),
+            None => String::new(),
+        }
+    }
+
+    /// Number of values of the field type as `u128`, const expression.
+    /// `2^128` values wrap to `0`, which is fine because then other fields
+    /// have a single value, and for uninhabited types the value is never used.
+    fn field_size(field: &syn::Field) -> syn::Expr {
+        let ty = &field.ty;
+        syn::parse_quote! {
+            const {
+                match <#ty as ordinal_map::WideOrdinal>::WIDE_ORDINAL_MAX {
+                    ::core::option::Option::None => 0u128,
+                    ::core::option::Option::Some(max) => max.wrapping_add(1),
+                }
+            }
+        }
+    }
+
+    /// Largest ordinal of the struct or variant, `Option<u128>` const expression.
+    fn max(&self) -> syn::Expr {
+        let max_var = format_ident!("max");
+        let steps = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| -> syn::Stmt {
+                let ty = &field.ty;
+                let message = format!(
+                    "`WideOrdinal` of `{}` overflows `u128`: \
+                product of field sizes up to field `{}`{} is greater than `2^128`",
+                    self.name,
+                    field_name(i, field),
+                    self.location(),
+                );
+                // `(max + 1) * (field_max + 1) - 1` without overflow on `2^128` values.
+                syn::parse_quote! {
+                    let #max_var = match #max_var {
+                        ::core::option::Option::Some(max) => match <#ty as ordinal_map::WideOrdinal>::WIDE_ORDINAL_MAX {
+                            ::core::option::Option::Some(field_max) => {
+                                let (max_0, o_0) = max.overflowing_mul(field_max);
+                                let (max_1, o_1) = max_0.overflowing_add(max);
+                                let (max_2, o_2) = max_1.overflowing_add(field_max);
+                                if o_0 || o_1 || o_2 {
+                                    ::core::panic!(#message);
+                                }
+                                ::core::option::Option::Some(max_2)
+                            }
+                            ::core::option::Option::None => ::core::option::Option::None,
+                        },
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                }
+            });
+        syn::parse_quote! {
+            const {
+                let #max_var: ::core::option::Option<u128> = ::core::option::Option::Some(0);
+                #( #steps )*
+                #max_var
+            }
+        }
+    }
+
+    /// Mixed radix number with the first field being the most significant digit.
+    fn ordinal(&self, refs: &[syn::Expr]) -> syn::Expr {
+        let ordinal_var = format_ident!("ordinal");
+        let steps = self.fields.iter().zip(refs).map(|(field, r)| -> syn::Stmt {
+            let field_size = Self::field_size(field);
+            syn::parse_quote! {
+                #ordinal_var = #ordinal_var * #field_size
+                    + ordinal_map::WideOrdinal::wide_ordinal(#r);
+            }
+        });
+        syn::parse_quote! {
+            {
+                let mut #ordinal_var: u128 = 0;
+                #( #steps )*
+                #ordinal_var
+            }
+        }
+    }
+
+    /// Decode fields from `rem` and construct the value, return `Option<Self>`.
+    fn from_ordinal(
+        &self,
+        rem: &syn::Ident,
+        constructor: impl FnOnce(&[syn::Ident]) -> syn::Expr,
+    ) -> syn::Expr {
+        let vars = field_vars(self.fields);
+        let decode = self
+            .fields
+            .iter()
+            .zip(&vars)
+            .rev()
+            .map(|(field, var)| -> syn::Stmt {
+                let ty = &field.ty;
+                let field_size = Self::field_size(field);
+                syn::parse_quote! {
+                    let #var = {
+                        let size = #field_size;
+                        let digit = match #rem.checked_rem(size) {
+                            ::core::option::Option::Some(digit) => digit,
+                            ::core::option::Option::None => #rem,
+                        };
+                        #rem = match #rem.checked_div(size) {
+                            ::core::option::Option::Some(rem) => rem,
+                            ::core::option::Option::None => 0,
+                        };
+                        <#ty as ordinal_map::WideOrdinal>::from_wide_ordinal(digit)?
+                    };
+                }
+            });
+        let value = constructor(&vars);
+        syn::parse_quote! {
+            {
+                #( #decode )*
+                ::core::option::Option::Some(#value)
+            }
+        }
+    }
+}
+
+/// Construct a struct or enum variant from field variables.
+fn construct(path: syn::Path, fields: &syn::Fields, vars: &[syn::Ident]) -> syn::Expr {
+    match fields {
+        syn::Fields::Unit => syn::parse_quote! { #path },
+        syn::Fields::Unnamed(_) => syn::parse_quote! { #path( #( #vars ),* ) },
+        syn::Fields::Named(_) => syn::parse_quote! { #path { #( #vars ),* } },
+    }
+}
+
+/// References to the fields of `self`.
+fn self_field_refs(fields: &syn::Fields) -> Vec<syn::Expr> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| -> syn::Expr {
+            let member = match &f.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(syn::Index::from(i)),
+            };
+            syn::parse_quote! { &self.#member }
+        })
+        .collect()
+}
+
+/// Items of `WideOrdinal` impl.
+fn impl_items(
+    max: &syn::Expr,
+    ordinal: &syn::Expr,
+    from_ordinal: &syn::Expr,
+) -> proc_macro2::TokenStream {
+    let ordinal_var = format_ident!("ordinal");
+    let rem_var = format_ident!("rem");
+    quote::quote! {
+        const WIDE_ORDINAL_MAX: ::core::option::Option<u128> = #max;
+
+        #[allow(unused_mut)]
+        fn wide_ordinal(&self) -> u128 {
+            // Make sure multiplication doesn't overflow.
+            let _check_overflow = Self::WIDE_ORDINAL_MAX;
+            #ordinal
+        }
+
+        #[allow(unreachable_code, unused_mut, unused_variables, unused_assignments)]
+        fn from_wide_ordinal(#ordinal_var: u128) -> ::core::option::Option<Self> {
+            match Self::WIDE_ORDINAL_MAX {
+                ::core::option::Option::Some(max) if #ordinal_var <= max => {}
+                _ => return ::core::option::Option::None,
+            }
+            let mut #rem_var = #ordinal_var;
+            #from_ordinal
+        }
+    }
+}
+
+pub(crate) fn derive_wide_ordinal(
+    input: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let span = input.span();
+    let input = syn::parse2::<syn::DeriveInput>(input)?;
+    check_attrs(&input)?;
+
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let generics = ordinal_impl_generics(
+        &input.generics,
+        &input.data,
+        &attrs,
+        &syn::parse_quote! { ordinal_map::WideOrdinal },
+    )?;
+
+    let rem_var = format_ident!("rem");
+
+    let (max, ordinal, from_ordinal): (syn::Expr, syn::Expr, syn::Expr) = match &input.data {
+        syn::Data::Struct(s) => {
+            let fields = FieldsGen {
+                name: &name,
+                variant: None,
+                fields: &s.fields,
+            };
+            (
+                fields.max(),
+                fields.ordinal(&self_field_refs(&s.fields)),
+                fields.from_ordinal(&rem_var, |vars| {
+                    construct(syn::parse_quote! { Self }, &s.fields, vars)
+                }),
+            )
+        }
+        syn::Data::Enum(e) => {
+            let variants: Vec<FieldsGen> = e
+                .variants
+                .iter()
+                .map(|v| FieldsGen {
+                    name: &name,
+                    variant: Some(&v.ident),
+                    fields: &v.fields,
+                })
+                .collect();
+            let maxes: Vec<syn::Expr> = variants.iter().map(|v| v.max()).collect();
+            // Number of values of the variant, `0` if uninhabited,
+            // wraps to `0` for `2^128` values, then other variants are uninhabited.
+            let sizes: Vec<syn::Expr> = maxes
+                .iter()
+                .map(|max| -> syn::Expr {
+                    syn::parse_quote! {
+                        const {
+                            match #max {
+                                ::core::option::Option::None => 0u128,
+                                ::core::option::Option::Some(max) => max.wrapping_add(1),
+                            }
+                        }
+                    }
+                })
+                .collect();
+
+            let max_var = format_ident!("max");
+            let max_steps = variants
+                .iter()
+                .zip(&maxes)
+                .map(|(v, variant_max)| -> syn::Stmt {
+                    let message = format!(
+                        "`WideOrdinal` of `{}` overflows `u128`: \
+                    sum of variant sizes up to variant `{}` is greater than `2^128`",
+                        ident,
+                        v.variant.unwrap(),
+                    );
+                    syn::parse_quote! {
+                        let #max_var = match #variant_max {
+                            ::core::option::Option::Some(variant_max) => match #max_var {
+                                ::core::option::Option::Some(max) => {
+                                    let (max_0, o_0) = max.overflowing_add(variant_max);
+                                    let (max_1, o_1) = max_0.overflowing_add(1);
+                                    if o_0 || o_1 {
+                                        ::core::panic!(#message);
+                                    }
+                                    ::core::option::Option::Some(max_1)
+                                }
+                                ::core::option::Option::None => ::core::option::Option::Some(variant_max),
+                            },
+                            ::core::option::Option::None => #max_var,
+                        };
+                    }
+                });
+            let max = syn::parse_quote! {
+                const {
+                    let #max_var: ::core::option::Option<u128> = ::core::option::Option::None;
+                    #( #max_steps )*
+                    #max_var
+                }
+            };
+
+            let arms = variants.iter().enumerate().map(|(i, v)| -> syn::Arm {
+                let variant = v.variant.unwrap();
+                let vars = field_vars(v.fields);
+                let pattern: syn::Pat = match v.fields {
+                    syn::Fields::Unit => syn::parse_quote! { Self::#variant },
+                    syn::Fields::Unnamed(_) => {
+                        syn::parse_quote! { Self::#variant( #( ref #vars ),* ) }
+                    }
+                    syn::Fields::Named(_) => {
+                        syn::parse_quote! { Self::#variant { #( ref #vars ),* } }
+                    }
+                };
+                let refs: Vec<syn::Expr> = vars.iter().map(|v| syn::parse_quote! { #v }).collect();
+                let ordinal = v.ordinal(&refs);
+                let offset = &sizes[..i];
+                syn::parse_quote! {
+                    #pattern => 0u128 #( + #offset )* + #ordinal,
+                }
+            });
+            let ordinal = syn::parse_quote! {
+                match *self {
+                    #( #arms )*
+                }
+            };
+
+            let decode = variants
+                .iter()
+                .zip(&maxes)
+                .map(|(v, variant_max)| -> syn::Stmt {
+                    let variant = v.variant.unwrap();
+                    let value = v.from_ordinal(&rem_var, |vars| {
+                        construct(syn::parse_quote! { Self::#variant }, v.fields, vars)
+                    });
+                    syn::parse_quote! {
+                        if let ::core::option::Option::Some(variant_max) = #variant_max {
+                            if #rem_var <= variant_max {
+                                return #value;
+                            }
+                            #rem_var -= variant_max;
+                            #rem_var -= 1;
+                        }
+                    }
+                });
+            let from_ordinal = syn::parse_quote! {
+                {
+                    #( #decode )*
+                    ::core::option::Option::None
+                }
+            };
+            (max, ordinal, from_ordinal)
+        }
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new(
+                span,
+                "WideOrdinal cannot be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let items = impl_items(&max, &ordinal, &from_ordinal);
+
+    // Generated code refers to the crate as `ordinal_map`,
+    // `#[ordinal(crate = "...")]` makes it an alias for the given path.
+    let use_krate: Option<syn::ItemUse> = attrs
+        .krate
+        .map(|krate| syn::parse_quote! { use #krate as ordinal_map; });
+
+    Ok(quote::quote_spanned! { span =>
+        const _: () = {
+            #use_krate
+
+            #[allow(clippy::all)]
+            impl #impl_generics ordinal_map::WideOrdinal for #ident #ty_generics #where_clause {
+                #items
+            }
+        };
+    })
+}
+
+fn impl_wide_ordinal_for_tuple_n(n: u32) -> syn::ItemImpl {
+    let params: Vec<syn::Ident> = (0..n)
+        .map(|i| format_ident!("{}", char::try_from('A' as u32 + i).unwrap()))
+        .collect();
+    let fields: syn::Fields = syn::Fields::Unnamed(syn::parse_quote! { ( #( #params, )* ) });
+    let name = format!(
+        "({})",
+        params.iter().map(|p| format!("{p}, ")).collect::<String>()
+    );
+    let fields_gen = FieldsGen {
+        name: &name,
+        variant: None,
+        fields: &fields,
+    };
+    let rem_var = format_ident!("rem");
+    let items = impl_items(
+        &fields_gen.max(),
+        &fields_gen.ordinal(&self_field_refs(&fields)),
+        &fields_gen.from_ordinal(&rem_var, |vars| syn::parse_quote! { ( #( #vars, )* ) }),
+    );
+    syn::parse_quote! {
+        #[allow(clippy::all)]
+        impl< #( #params: ordinal_map::WideOrdinal, )* > ordinal_map::WideOrdinal for ( #( #params, )* ) {
+            #items
+        }
+    }
+}
+
+pub(crate) fn impl_wide_ordinal_for_tuple(
+    _input: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let tuples = (0..=16).map(impl_wide_ordinal_for_tuple_n);
+    Ok(quote::quote! {
+        #( #tuples )*
+    })
+}
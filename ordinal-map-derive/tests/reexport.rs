@@ -0,0 +1,35 @@
+//! `ordinal_map` is not a dependency of this crate,
+//! the derive only works with `#[ordinal(crate = "...")]`.
+
+mod nested {
+    pub(crate) use facade as ordinal;
+}
+
+use facade::Ordinal;
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(crate = "facade")]
+struct ReexportStruct<T>(T, bool);
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(crate = "nested::ordinal", names, all_values)]
+enum ReexportEnum {
+    A,
+    B,
+}
+
+#[test]
+fn test_reexport_struct() {
+    assert_eq!(4, ReexportStruct::<bool>::ORDINAL_SIZE);
+    assert_eq!(3, ReexportStruct(true, true).ordinal());
+    assert_eq!(
+        Some(ReexportStruct(true, false)),
+        ReexportStruct::from_ordinal(2)
+    );
+}
+
+#[test]
+fn test_reexport_enum() {
+    assert_eq!([ReexportEnum::A, ReexportEnum::B], ReexportEnum::ALL_VALUES);
+    assert_eq!(Ok(ReexportEnum::B), "B".parse());
+}
@@ -0,0 +1,353 @@
+//! Compare derived `from_ordinal` for enums with the linear chain of checks
+//! which `#[derive(Ordinal)]` generated before.
+
+use std::hint::black_box;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use ordinal_map::Ordinal;
+
+macro_rules! fieldless_enum {
+    ($name:ident, $($v:ident),* $(,)?) => {
+        #[derive(Ordinal, Debug)]
+        enum $name {
+            $($v,)*
+        }
+
+        impl $name {
+            fn from_ordinal_linear(ordinal: usize) -> Option<Self> {
+                let mut size = 0usize;
+                $(
+                    size += 1;
+                    if ordinal < size {
+                        return Some($name::$v);
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+macro_rules! payload_enum {
+    ($name:ident, $($v:ident($t:ty)),* $(,)?) => {
+        #[derive(Ordinal, Debug)]
+        enum $name {
+            $($v($t),)*
+        }
+
+        impl $name {
+            fn from_ordinal_linear(ordinal: usize) -> Option<Self> {
+                let mut size = 0usize;
+                $(
+                    let before = size;
+                    size += <$t as Ordinal>::ORDINAL_SIZE;
+                    if ordinal < size {
+                        return <$t as Ordinal>::from_ordinal(ordinal - before).map($name::$v);
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+fieldless_enum!(
+    Fieldless200,
+    V000,
+    V001,
+    V002,
+    V003,
+    V004,
+    V005,
+    V006,
+    V007,
+    V008,
+    V009,
+    V010,
+    V011,
+    V012,
+    V013,
+    V014,
+    V015,
+    V016,
+    V017,
+    V018,
+    V019,
+    V020,
+    V021,
+    V022,
+    V023,
+    V024,
+    V025,
+    V026,
+    V027,
+    V028,
+    V029,
+    V030,
+    V031,
+    V032,
+    V033,
+    V034,
+    V035,
+    V036,
+    V037,
+    V038,
+    V039,
+    V040,
+    V041,
+    V042,
+    V043,
+    V044,
+    V045,
+    V046,
+    V047,
+    V048,
+    V049,
+    V050,
+    V051,
+    V052,
+    V053,
+    V054,
+    V055,
+    V056,
+    V057,
+    V058,
+    V059,
+    V060,
+    V061,
+    V062,
+    V063,
+    V064,
+    V065,
+    V066,
+    V067,
+    V068,
+    V069,
+    V070,
+    V071,
+    V072,
+    V073,
+    V074,
+    V075,
+    V076,
+    V077,
+    V078,
+    V079,
+    V080,
+    V081,
+    V082,
+    V083,
+    V084,
+    V085,
+    V086,
+    V087,
+    V088,
+    V089,
+    V090,
+    V091,
+    V092,
+    V093,
+    V094,
+    V095,
+    V096,
+    V097,
+    V098,
+    V099,
+    V100,
+    V101,
+    V102,
+    V103,
+    V104,
+    V105,
+    V106,
+    V107,
+    V108,
+    V109,
+    V110,
+    V111,
+    V112,
+    V113,
+    V114,
+    V115,
+    V116,
+    V117,
+    V118,
+    V119,
+    V120,
+    V121,
+    V122,
+    V123,
+    V124,
+    V125,
+    V126,
+    V127,
+    V128,
+    V129,
+    V130,
+    V131,
+    V132,
+    V133,
+    V134,
+    V135,
+    V136,
+    V137,
+    V138,
+    V139,
+    V140,
+    V141,
+    V142,
+    V143,
+    V144,
+    V145,
+    V146,
+    V147,
+    V148,
+    V149,
+    V150,
+    V151,
+    V152,
+    V153,
+    V154,
+    V155,
+    V156,
+    V157,
+    V158,
+    V159,
+    V160,
+    V161,
+    V162,
+    V163,
+    V164,
+    V165,
+    V166,
+    V167,
+    V168,
+    V169,
+    V170,
+    V171,
+    V172,
+    V173,
+    V174,
+    V175,
+    V176,
+    V177,
+    V178,
+    V179,
+    V180,
+    V181,
+    V182,
+    V183,
+    V184,
+    V185,
+    V186,
+    V187,
+    V188,
+    V189,
+    V190,
+    V191,
+    V192,
+    V193,
+    V194,
+    V195,
+    V196,
+    V197,
+    V198,
+    V199,
+);
+
+payload_enum!(
+    Payload64,
+    P00(bool),
+    P01(Option<bool>),
+    P02(()),
+    P03((bool, bool)),
+    P04(bool),
+    P05(Option<bool>),
+    P06(()),
+    P07((bool, bool)),
+    P08(bool),
+    P09(Option<bool>),
+    P10(()),
+    P11((bool, bool)),
+    P12(bool),
+    P13(Option<bool>),
+    P14(()),
+    P15((bool, bool)),
+    P16(bool),
+    P17(Option<bool>),
+    P18(()),
+    P19((bool, bool)),
+    P20(bool),
+    P21(Option<bool>),
+    P22(()),
+    P23((bool, bool)),
+    P24(bool),
+    P25(Option<bool>),
+    P26(()),
+    P27((bool, bool)),
+    P28(bool),
+    P29(Option<bool>),
+    P30(()),
+    P31((bool, bool)),
+    P32(bool),
+    P33(Option<bool>),
+    P34(()),
+    P35((bool, bool)),
+    P36(bool),
+    P37(Option<bool>),
+    P38(()),
+    P39((bool, bool)),
+    P40(bool),
+    P41(Option<bool>),
+    P42(()),
+    P43((bool, bool)),
+    P44(bool),
+    P45(Option<bool>),
+    P46(()),
+    P47((bool, bool)),
+    P48(bool),
+    P49(Option<bool>),
+    P50(()),
+    P51((bool, bool)),
+    P52(bool),
+    P53(Option<bool>),
+    P54(()),
+    P55((bool, bool)),
+    P56(bool),
+    P57(Option<bool>),
+    P58(()),
+    P59((bool, bool)),
+    P60(bool),
+    P61(Option<bool>),
+    P62(()),
+    P63((bool, bool)),
+);
+
+fn bench_decode<T: Ordinal>(c: &mut Criterion, name: &str, linear: fn(usize) -> Option<T>) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("derive", |b| {
+        b.iter(|| {
+            for i in 0..T::ORDINAL_SIZE {
+                black_box(T::from_ordinal(black_box(i)));
+            }
+        })
+    });
+    group.bench_function("linear", |b| {
+        b.iter(|| {
+            for i in 0..T::ORDINAL_SIZE {
+                black_box(linear(black_box(i)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn from_ordinal(c: &mut Criterion) {
+    bench_decode(c, "fieldless_200", Fieldless200::from_ordinal_linear);
+    bench_decode(c, "payload_64", Payload64::from_ordinal_linear);
+}
+
+criterion_group!(benches, from_ordinal);
+criterion_main!(benches);
@@ -0,0 +1,243 @@
+//! Integer types restricted to an inclusive range `LO..=HI`.
+//!
+//! These types implement [`Ordinal`] with `ORDINAL_SIZE = HI - LO + 1`,
+//! so they can be used as keys of dense maps and sets
+//! without paying for the whole range of the underlying integer type.
+//!
+//! # Example
+//!
+//! ```
+//! use ordinal_map::bounded::BoundedU8;
+//! use ordinal_map::map::total::OrdinalTotalArrayMap;
+//! use ordinal_map::Ordinal;
+//!
+//! type DayOfMonth = BoundedU8<1, 31>;
+//!
+//! let mut events: OrdinalTotalArrayMap<DayOfMonth, u32, { DayOfMonth::ORDINAL_SIZE }> =
+//!     OrdinalTotalArrayMap::default();
+//! events[&DayOfMonth::new(17).unwrap()] += 1;
+//!
+//! assert_eq!(31, DayOfMonth::ORDINAL_SIZE);
+//! assert!(DayOfMonth::new(0).is_none());
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::Ordinal;
+
+/// Error returned when converting an integer outside of the range to a bounded integer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct OutOfBoundsError;
+
+impl Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "value is out of bounds")
+    }
+}
+
+impl Error for OutOfBoundsError {}
+
+macro_rules! bounded {
+    ($name:ident, $t:ident) => {
+        #[doc = concat!("[`", stringify!($t), "`] in the inclusive range `LO..=HI`.")]
+        ///
+        /// See [module documentation](crate::bounded) for an example.
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub struct $name<const LO: $t, const HI: $t>($t);
+
+        impl<const LO: $t, const HI: $t> $name<LO, HI> {
+            const ASSERT: () = assert!(LO <= HI, "LO > HI");
+
+            /// The smallest value.
+            pub const MIN: Self = {
+                const { Self::ASSERT };
+                $name(LO)
+            };
+
+            /// The largest value.
+            pub const MAX: Self = {
+                const { Self::ASSERT };
+                $name(HI)
+            };
+
+            /// Create a new value, returning `None` if the value is out of range.
+            #[inline]
+            pub const fn new(value: $t) -> Option<Self> {
+                const { Self::ASSERT };
+                if LO <= value && value <= HI {
+                    Some($name(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Get the underlying value.
+            #[inline]
+            pub const fn get(self) -> $t {
+                self.0
+            }
+
+            /// Checked addition. Returns `None` if the result is out of range.
+            #[inline]
+            pub const fn checked_add(self, rhs: $t) -> Option<Self> {
+                match self.0.checked_add(rhs) {
+                    Some(value) => Self::new(value),
+                    None => None,
+                }
+            }
+
+            /// Checked subtraction. Returns `None` if the result is out of range.
+            #[inline]
+            pub const fn checked_sub(self, rhs: $t) -> Option<Self> {
+                match self.0.checked_sub(rhs) {
+                    Some(value) => Self::new(value),
+                    None => None,
+                }
+            }
+
+            /// Checked multiplication. Returns `None` if the result is out of range.
+            #[inline]
+            pub const fn checked_mul(self, rhs: $t) -> Option<Self> {
+                match self.0.checked_mul(rhs) {
+                    Some(value) => Self::new(value),
+                    None => None,
+                }
+            }
+        }
+
+        impl<const LO: $t, const HI: $t> Ordinal for $name<LO, HI> {
+            const ORDINAL_SIZE: usize = {
+                const { Self::ASSERT };
+                let size = HI as i128 - LO as i128 + 1;
+                assert!(size <= usize::MAX as i128, "ORDINAL_SIZE overflows usize");
+                size as usize
+            };
+
+            #[inline]
+            fn ordinal(&self) -> usize {
+                const { Self::ORDINAL_SIZE };
+                self.0.abs_diff(LO) as usize
+            }
+
+            #[inline]
+            fn from_ordinal(ordinal: usize) -> Option<Self> {
+                if ordinal < Self::ORDINAL_SIZE {
+                    Some($name((LO as i128 + ordinal as i128) as $t))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+                $name((LO as i128 + ordinal as i128) as $t)
+            }
+        }
+
+        impl<const LO: $t, const HI: $t> TryFrom<$t> for $name<LO, HI> {
+            type Error = OutOfBoundsError;
+
+            #[inline]
+            fn try_from(value: $t) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(OutOfBoundsError)
+            }
+        }
+
+        impl<const LO: $t, const HI: $t> From<$name<LO, HI>> for $t {
+            #[inline]
+            fn from(value: $name<LO, HI>) -> $t {
+                value.0
+            }
+        }
+
+        impl<const LO: $t, const HI: $t> Display for $name<LO, HI> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+bounded!(BoundedU8, u8);
+bounded!(BoundedU16, u16);
+bounded!(BoundedU32, u32);
+bounded!(BoundedU64, u64);
+bounded!(BoundedUsize, usize);
+bounded!(BoundedI8, i8);
+bounded!(BoundedI16, i16);
+bounded!(BoundedI32, i32);
+bounded!(BoundedI64, i64);
+bounded!(BoundedIsize, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::bounded::BoundedI16;
+    use crate::bounded::BoundedU32;
+    use crate::bounded::BoundedU8;
+    use crate::bounded::BoundedUsize;
+    use crate::bounded::OutOfBoundsError;
+    use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_ordinal_some;
+    use crate::Ordinal;
+
+    #[test]
+    fn test_bounded_u8() {
+        test_ordinal((1..=31).map(|i| BoundedU8::<1, 31>::new(i).unwrap()));
+    }
+
+    #[test]
+    fn test_bounded_u8_full() {
+        test_ordinal((0..=u8::MAX).map(|i| BoundedU8::<0, { u8::MAX }>::new(i).unwrap()));
+    }
+
+    #[test]
+    fn test_bounded_i16() {
+        test_ordinal((-3..=2).map(|i| BoundedI16::<-3, 2>::new(i).unwrap()));
+    }
+
+    #[test]
+    fn test_bounded_u32() {
+        assert_eq!(16, BoundedU32::<1000, 1015>::ORDINAL_SIZE);
+        test_ordinal_some::<BoundedU32<1000, 1015>>();
+    }
+
+    #[test]
+    fn test_bounded_usize_max() {
+        test_ordinal_some::<BoundedUsize<1, { usize::MAX }>>();
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(None, BoundedU8::<1, 31>::new(0));
+        assert_eq!(Some(1), BoundedU8::<1, 31>::new(1).map(|b| b.get()));
+        assert_eq!(Some(31), BoundedU8::<1, 31>::new(31).map(|b| b.get()));
+        assert_eq!(None, BoundedU8::<1, 31>::new(32));
+        assert_eq!(1, BoundedU8::<1, 31>::MIN.get());
+        assert_eq!(31, BoundedU8::<1, 31>::MAX.get());
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(Err(OutOfBoundsError), BoundedI16::<-3, 2>::try_from(3));
+        assert_eq!(Ok(-3), BoundedI16::<-3, 2>::try_from(-3).map(i16::from));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let v = BoundedU8::<1, 31>::new(30).unwrap();
+        assert_eq!(Some(31), v.checked_add(1).map(|b| b.get()));
+        assert_eq!(None, v.checked_add(2));
+        assert_eq!(None, v.checked_add(u8::MAX));
+        assert_eq!(Some(1), v.checked_sub(29).map(|b| b.get()));
+        assert_eq!(None, v.checked_sub(30));
+        assert_eq!(None, v.checked_mul(2));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("-3", BoundedI16::<-3, 2>::MIN.to_string());
+    }
+}
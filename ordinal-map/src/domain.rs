@@ -0,0 +1,232 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::DynOrdinal;
+use crate::Ordinal;
+
+/// Set of keys `K` with ordinals `0..size`, where `size` is known only at runtime.
+///
+/// Domain is used to create [`DynOrdinalMap`](crate::map::DynOrdinalMap)
+/// and [`DynOrdinalSet`](crate::set::DynOrdinalSet).
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::Domain;
+///
+/// # let config_shards = 3;
+/// let shards: Domain<usize> = Domain::new(config_shards);
+/// assert_eq!(vec![0, 1, 2], shards.values().collect::<Vec<_>>());
+/// ```
+pub struct Domain<K> {
+    size: usize,
+    _phantom: PhantomData<fn() -> K>,
+}
+
+impl<K: DynOrdinal> Domain<K> {
+    /// Create a domain of keys with ordinals `0..size`.
+    ///
+    /// # Panics
+    ///
+    /// If `K` has no value with ordinal `size - 1`.
+    #[inline]
+    pub fn new(size: usize) -> Self {
+        if let Some(last) = size.checked_sub(1) {
+            assert!(
+                K::from_dyn_ordinal(last).is_some(),
+                "domain size is too large for the key type"
+            );
+        }
+        Domain {
+            size,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Number of keys in the domain.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Check if the key belongs to the domain.
+    #[inline]
+    pub fn contains(&self, key: &K) -> bool {
+        key.dyn_ordinal() < self.size
+    }
+
+    /// Ordinal of the key, or `None` if the key does not belong to the domain.
+    #[inline]
+    pub fn ordinal(&self, key: &K) -> Option<usize> {
+        let ordinal = key.dyn_ordinal();
+        (ordinal < self.size).then_some(ordinal)
+    }
+
+    /// Key by ordinal, or `None` if the ordinal is not less than [`size`](Domain::size).
+    #[inline]
+    pub fn from_ordinal(&self, ordinal: usize) -> Option<K> {
+        if ordinal < self.size {
+            // SAFETY: `ordinal` is within the domain.
+            Some(unsafe { K::from_dyn_ordinal_unchecked(ordinal) })
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over all keys of the domain in ordinal order.
+    #[inline]
+    pub fn values(&self) -> DomainValues<K> {
+        DomainValues {
+            range: 0..self.size,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: Ordinal> Domain<K> {
+    /// Domain of all values of the [`Ordinal`] type.
+    #[inline]
+    pub fn all() -> Self {
+        Domain {
+            size: K::ORDINAL_SIZE,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K> Clone for Domain<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for Domain<K> {}
+
+impl<K> PartialEq for Domain<K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+    }
+}
+
+impl<K> Eq for Domain<K> {}
+
+impl<K> Debug for Domain<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Domain").field("size", &self.size).finish()
+    }
+}
+
+/// Iterator over the keys of [`Domain`].
+///
+/// This iterator is created by [`Domain::values`].
+pub struct DomainValues<K> {
+    range: Range<usize>,
+    _phantom: PhantomData<fn() -> K>,
+}
+
+impl<K: DynOrdinal> Iterator for DomainValues<K> {
+    type Item = K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.range.next()?;
+        // SAFETY: `range` is within the domain.
+        Some(unsafe { K::from_dyn_ordinal_unchecked(next) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<K: DynOrdinal> ExactSizeIterator for DomainValues<K> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl<K: DynOrdinal> DoubleEndedIterator for DomainValues<K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next = self.range.next_back()?;
+        // SAFETY: `range` is within the domain.
+        Some(unsafe { K::from_dyn_ordinal_unchecked(next) })
+    }
+}
+
+impl<K> Clone for DomainValues<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        DomainValues {
+            range: self.range.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: DynOrdinal + Debug> Debug for DomainValues<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Domain;
+    use crate::DynOrdinal;
+
+    #[test]
+    fn test_domain() {
+        let domain: Domain<usize> = Domain::new(3);
+        assert_eq!(3, domain.size());
+        assert!(domain.contains(&2));
+        assert!(!domain.contains(&3));
+        assert_eq!(Some(2), domain.ordinal(&2));
+        assert_eq!(None, domain.from_ordinal(3));
+        assert_eq!(vec![2, 1, 0], domain.values().rev().collect::<Vec<_>>());
+        assert_eq!(Domain::new(3), domain);
+    }
+
+    #[test]
+    fn test_domain_ordinal() {
+        let domain: Domain<u8> = Domain::new(10);
+        assert_eq!(10, domain.values().len());
+        assert_eq!(256, Domain::<u8>::all().size());
+        assert_eq!(0, Domain::<u8>::new(0).values().count());
+    }
+
+    #[test]
+    #[should_panic(expected = "domain size is too large for the key type")]
+    fn test_domain_too_large() {
+        Domain::<u8>::new(257);
+    }
+
+    /// Breaks the contiguity requirement, which must not cause undefined behavior.
+    #[derive(Debug)]
+    struct Even(usize);
+
+    impl DynOrdinal for Even {
+        fn dyn_ordinal(&self) -> usize {
+            self.0
+        }
+
+        fn from_dyn_ordinal(ordinal: usize) -> Option<Self> {
+            ordinal.is_multiple_of(2).then_some(Even(ordinal))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no value for ordinal within the domain")]
+    fn test_domain_not_contiguous() {
+        let domain = Domain::<Even>::new(3);
+        assert_eq!(Some(0), domain.from_ordinal(0).map(|e| e.0));
+        domain.from_ordinal(1);
+    }
+}
@@ -0,0 +1,483 @@
+//! Assign dense ordinals to arbitrary hashable values.
+//!
+//! [`OrdinalInterner`] hands out [`InternId`] for values on first sight.
+//! When all values are interned, the interner can be [frozen](OrdinalInterner::freeze)
+//! into a [`FrozenInterner`] with a fixed [`Domain`] of ids, which can be used as keys of
+//! [`DynOrdinalMap`](crate::map::DynOrdinalMap) and [`DynOrdinalSet`](crate::set::DynOrdinalSet).
+//!
+//! # Example
+//!
+//! ```
+//! use ordinal_map::interner::OrdinalInterner;
+//! use ordinal_map::set::DynOrdinalSet;
+//!
+//! let mut interner: OrdinalInterner<String> = OrdinalInterner::new();
+//! let cpu = interner.intern_ref("cpu");
+//! let mem = interner.intern_ref("mem");
+//! assert_eq!(cpu, interner.intern_ref("cpu"));
+//!
+//! let metrics = interner.freeze();
+//! let mut seen = DynOrdinalSet::new(metrics.domain());
+//! seen.insert(mem);
+//! assert_eq!("mem", metrics[mem]);
+//! assert_eq!(Some(mem), metrics.get("mem"));
+//! assert!(!seen.contains(&cpu));
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::iter::Enumerate;
+use std::marker::PhantomData;
+use std::ops::Index;
+use std::slice;
+
+use crate::Domain;
+use crate::DynOrdinal;
+
+/// Dense id of a value interned by [`OrdinalInterner`].
+///
+/// Ids are assigned sequentially from zero in the order values are first interned.
+/// Ids are typed by the value type, but not by the interner,
+/// so ids from different interners of the same type must not be mixed.
+pub struct InternId<T> {
+    index: usize,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> InternId<T> {
+    #[inline]
+    fn new(index: usize) -> Self {
+        InternId {
+            index,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Index of the id, which is also its ordinal.
+    #[inline]
+    pub fn index(self) -> usize {
+        self.index
+    }
+}
+
+impl<T> DynOrdinal for InternId<T> {
+    #[inline]
+    fn dyn_ordinal(&self) -> usize {
+        self.index
+    }
+
+    #[inline]
+    fn from_dyn_ordinal(ordinal: usize) -> Option<Self> {
+        Some(InternId::new(ordinal))
+    }
+
+    #[inline]
+    unsafe fn from_dyn_ordinal_unchecked(ordinal: usize) -> Self {
+        InternId::new(ordinal)
+    }
+}
+
+impl<T> Clone for InternId<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for InternId<T> {}
+
+impl<T> PartialEq for InternId<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for InternId<T> {}
+
+impl<T> PartialOrd for InternId<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for InternId<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl<T> Hash for InternId<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state)
+    }
+}
+
+impl<T> Debug for InternId<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "InternId({})", self.index)
+    }
+}
+
+/// Marks the end of a hash chain.
+const NO_NEXT: usize = usize::MAX;
+
+/// Interned values and the hash index over them.
+///
+/// Values are stored once; the index maps a value hash to the first value with that hash,
+/// and `next` links values with equal hashes, so lookup only needs `T: Borrow<Q>`.
+struct Table<T> {
+    values: Vec<T>,
+    heads: HashMap<u64, usize>,
+    next: Vec<usize>,
+    hasher: RandomState,
+}
+
+impl<T: Hash + Eq> Table<T> {
+    fn find<Q: Hash + Eq + ?Sized>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+    {
+        let mut index = *self.heads.get(&self.hasher.hash_one(value))?;
+        while index != NO_NEXT {
+            if self.values[index].borrow() == value {
+                return Some(index);
+            }
+            index = self.next[index];
+        }
+        None
+    }
+
+    fn push(&mut self, value: T) -> usize {
+        let index = self.values.len();
+        let hash = self.hasher.hash_one(&value);
+        self.next
+            .push(self.heads.insert(hash, index).unwrap_or(NO_NEXT));
+        self.values.push(value);
+        index
+    }
+}
+
+/// Assign dense [`InternId`] to values on first sight.
+///
+/// See [module documentation](crate::interner) for an example.
+pub struct OrdinalInterner<T> {
+    table: Table<T>,
+}
+
+impl<T: Hash + Eq> OrdinalInterner<T> {
+    /// Create a new empty interner.
+    pub fn new() -> Self {
+        OrdinalInterner {
+            table: Table {
+                values: Vec::new(),
+                heads: HashMap::new(),
+                next: Vec::new(),
+                hasher: RandomState::new(),
+            },
+        }
+    }
+
+    /// Return the id of the value, assigning the next id if the value is new.
+    pub fn intern(&mut self, value: T) -> InternId<T> {
+        match self.table.find(&value) {
+            Some(index) => InternId::new(index),
+            None => InternId::new(self.table.push(value)),
+        }
+    }
+
+    /// Like [`intern`](OrdinalInterner::intern), but the value is cloned only if it is new.
+    pub fn intern_ref<Q>(&mut self, value: &Q) -> InternId<T>
+    where
+        Q: Hash + Eq + ToOwned<Owned = T> + ?Sized,
+        T: Borrow<Q>,
+    {
+        match self.table.find(value) {
+            Some(index) => InternId::new(index),
+            None => InternId::new(self.table.push(value.to_owned())),
+        }
+    }
+
+    /// Return the id of the value if it is interned.
+    pub fn get<Q: Hash + Eq + ?Sized>(&self, value: &Q) -> Option<InternId<T>>
+    where
+        T: Borrow<Q>,
+    {
+        self.table.find(value).map(InternId::new)
+    }
+
+    /// Return the value by id, or `None` if the id is not assigned by this interner.
+    #[inline]
+    pub fn resolve(&self, id: InternId<T>) -> Option<&T> {
+        self.table.values.get(id.index)
+    }
+
+    /// Number of interned values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.values.len()
+    }
+
+    /// Check if no values are interned.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.table.values.is_empty()
+    }
+
+    /// Iterate over ids and values in id order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            iter: self.table.values.iter().enumerate(),
+        }
+    }
+
+    /// Stop interning and fix the domain of ids.
+    #[inline]
+    pub fn freeze(self) -> FrozenInterner<T> {
+        FrozenInterner { table: self.table }
+    }
+}
+
+impl<T: Hash + Eq> Default for OrdinalInterner<T> {
+    #[inline]
+    fn default() -> Self {
+        OrdinalInterner::new()
+    }
+}
+
+impl<T: Hash + Eq> Extend<T> for OrdinalInterner<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.intern(value);
+        }
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for OrdinalInterner<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut interner = OrdinalInterner::new();
+        interner.extend(iter);
+        interner
+    }
+}
+
+impl<T: Hash + Eq> Index<InternId<T>> for OrdinalInterner<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, id: InternId<T>) -> &T {
+        self.resolve(id)
+            .expect("id is not assigned by this interner")
+    }
+}
+
+impl<T: Debug> Debug for OrdinalInterner<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.table.values).finish()
+    }
+}
+
+/// [`OrdinalInterner`] which no longer accepts new values,
+/// so ids form a fixed [`Domain`].
+pub struct FrozenInterner<T> {
+    table: Table<T>,
+}
+
+impl<T: Hash + Eq> FrozenInterner<T> {
+    /// Domain of all ids assigned by the interner.
+    #[inline]
+    pub fn domain(&self) -> Domain<InternId<T>> {
+        Domain::new(self.table.values.len())
+    }
+
+    /// Return the id of the value if it is interned.
+    pub fn get<Q: Hash + Eq + ?Sized>(&self, value: &Q) -> Option<InternId<T>>
+    where
+        T: Borrow<Q>,
+    {
+        self.table.find(value).map(InternId::new)
+    }
+
+    /// Return the value by id, or `None` if the id is not assigned by this interner.
+    #[inline]
+    pub fn resolve(&self, id: InternId<T>) -> Option<&T> {
+        self.table.values.get(id.index)
+    }
+
+    /// Interned values in id order.
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.table.values
+    }
+
+    /// Number of interned values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.values.len()
+    }
+
+    /// Check if no values are interned.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.table.values.is_empty()
+    }
+
+    /// Iterate over ids and values in id order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            iter: self.table.values.iter().enumerate(),
+        }
+    }
+}
+
+impl<T: Hash + Eq> Index<InternId<T>> for FrozenInterner<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, id: InternId<T>) -> &T {
+        self.resolve(id)
+            .expect("id is not assigned by this interner")
+    }
+}
+
+impl<T: Debug> Debug for FrozenInterner<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.table.values).finish()
+    }
+}
+
+/// Iterator over ids and values of [`OrdinalInterner`] or [`FrozenInterner`].
+pub struct Iter<'a, T> {
+    iter: Enumerate<slice::Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (InternId<T>, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, value) = self.iter.next()?;
+        Some((InternId::new(index), value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (index, value) = self.iter.next_back()?;
+        Some((InternId::new(index), value))
+    }
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, T: Debug> Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+    use std::hash::Hasher;
+
+    use crate::interner::InternId;
+    use crate::interner::OrdinalInterner;
+    use crate::map::DynOrdinalMap;
+
+    #[quickcheck]
+    fn qc_intern(values: Vec<u8>) {
+        let mut interner = OrdinalInterner::new();
+        let mut control: HashMap<u8, InternId<u8>> = HashMap::new();
+        for value in &values {
+            let next = control.len();
+            let id = interner.intern(*value);
+            let expected = *control.entry(*value).or_insert(id);
+            assert_eq!(expected, id);
+            if control.len() > next {
+                assert_eq!(next, id.index());
+            }
+            assert_eq!(Some(value), interner.resolve(id));
+        }
+        assert_eq!(control.len(), interner.len());
+
+        let frozen = interner.freeze();
+        for (value, id) in &control {
+            assert_eq!(Some(*id), frozen.get(value));
+            assert_eq!(value, &frozen[*id]);
+        }
+        assert_eq!(control.len(), frozen.domain().size());
+    }
+
+    /// Value with a terrible hash to exercise hash chains.
+    #[derive(Eq, PartialEq, Debug)]
+    struct Collide(u32);
+
+    impl Hash for Collide {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            (self.0 % 2).hash(state)
+        }
+    }
+
+    #[test]
+    fn test_collisions() {
+        let mut interner = OrdinalInterner::new();
+        let ids: Vec<_> = (0..10).map(|i| interner.intern(Collide(i))).collect();
+        for (i, id) in ids.iter().enumerate() {
+            assert_eq!(i, id.index());
+            assert_eq!(Some(*id), interner.get(&Collide(i as u32)));
+        }
+        assert_eq!(None, interner.get(&Collide(10)));
+    }
+
+    #[test]
+    fn test_frozen_map() {
+        let mut interner: OrdinalInterner<String> = OrdinalInterner::new();
+        let words = ["a", "b", "a", "c", "b", "a"];
+        let ids: Vec<_> = words.iter().map(|w| interner.intern_ref(*w)).collect();
+        let interner = interner.freeze();
+
+        let mut counts = DynOrdinalMap::new(interner.domain());
+        for id in ids {
+            *counts.entry(id).or_insert(0) += 1;
+        }
+        let counts: Vec<(&str, i32)> = counts
+            .iter()
+            .map(|(id, count)| (interner[id].as_str(), *count))
+            .collect();
+        assert_eq!(vec![("a", 3), ("b", 2), ("c", 1)], counts);
+    }
+}
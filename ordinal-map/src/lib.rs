@@ -40,14 +40,26 @@ extern crate quickcheck_macros;
 pub(crate) mod array_as_mut;
 pub(crate) mod array_from_iter;
 mod atomic;
+pub mod bounded;
+mod domain;
+pub mod interner;
 pub mod map;
+mod names;
 mod ordinal;
 pub mod set;
 pub(crate) mod tests;
 mod values;
+mod wide_values;
 
 pub use atomic::AtomicOrdinal;
+pub use domain::Domain;
+pub use domain::DomainValues;
+pub use names::ParseNameError;
+pub use ordinal::dyn_ordinal::DynOrdinal;
 pub use ordinal::traits::Ordinal;
+pub use ordinal::wide::WideOrdinal;
 pub use ordinal_map_derive::Ordinal;
+pub use ordinal_map_derive::WideOrdinal;
 pub use values::OrdinalValues;
+pub use wide_values::WideOrdinalValues;
 pub(crate) mod array_builder;
@@ -5,6 +5,9 @@ use crate::map::iter::IntoIterArray;
 use crate::map::iter::Iter;
 use crate::map::iter::IterMut;
 use crate::map::iter::ValuesMut;
+use crate::map::slots::DrainSlots;
+use crate::map::slots::SlotsMut;
+use crate::map::slots::SlotsRef;
 use crate::map::total::array_map::OrdinalTotalArrayMap;
 use crate::map::Drain;
 use crate::map::Entry;
@@ -102,13 +105,13 @@ impl<K: Ordinal, V, const S: usize> OrdinalArrayMap<K, V, S> {
     /// Iterate over the map.
     #[inline]
     pub fn iter<'a>(&'a self) -> Iter<'a, K, V> {
-        Iter::new(self.map.iter())
+        Iter::new(SlotsRef::flat(self.map.values()))
     }
 
     /// Iterate over the map mutably.
     #[inline]
     pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, K, V> {
-        IterMut::new(self.map.iter_mut())
+        IterMut::new(SlotsMut::flat(self.map.values_mut()))
     }
 
     /// Iterate over the keys of the map.
@@ -132,7 +135,7 @@ impl<K: Ordinal, V, const S: usize> OrdinalArrayMap<K, V, S> {
     /// Remove all elements from the map.
     #[inline]
     pub fn drain(&mut self) -> Drain<'_, K, V> {
-        Drain::new(self.map.iter_mut())
+        Drain::new(DrainSlots::flat(self.map.values_mut()))
     }
 
     /// Remove all elements from the map.
@@ -193,7 +196,7 @@ impl<K: Ordinal, V, const S: usize> IntoIterator for OrdinalArrayMap<K, V, S> {
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        IntoIterArray::new(self.map.into_iter())
+        IntoIterArray::new(self.map.into_values().into_iter())
     }
 }
 
@@ -0,0 +1,258 @@
+use std::fmt;
+use std::fmt::Debug;
+
+use crate::map::iter::ValuesMut;
+use crate::map::slots::DrainSlots;
+use crate::map::slots::IntoSlots;
+use crate::map::slots::SlotsMut;
+use crate::map::slots::SlotsRef;
+use crate::map::Drain;
+use crate::map::Entry;
+use crate::map::IntoIter;
+use crate::map::Iter;
+use crate::map::IterMut;
+use crate::map::Keys;
+use crate::map::Values;
+use crate::Domain;
+use crate::DynOrdinal;
+
+/// Map keys of a [`Domain`] whose size is known only at runtime to values.
+///
+/// This is the same as [`OrdinalMap`](crate::map::OrdinalMap),
+/// but the number of slots is taken from the domain:
+/// the first insertion allocates a boxed slice `[Option<V>; domain.size()]`.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::map::DynOrdinalMap;
+/// use ordinal_map::Domain;
+///
+/// # let config_shards = 8;
+/// let shards: Domain<usize> = Domain::new(config_shards);
+/// let mut load = DynOrdinalMap::new(shards);
+/// load.insert(3, 0.5);
+/// assert_eq!(Some(&0.5), load.get(&3));
+/// assert_eq!(None, load.get(&100));
+/// ```
+pub struct DynOrdinalMap<K, V> {
+    domain: Domain<K>,
+    // Empty when the map is just created.
+    map: Box<[Option<V>]>,
+}
+
+impl<K: DynOrdinal, V> DynOrdinalMap<K, V> {
+    /// Create a new empty map.
+    /// This operation does not allocate memory, but first insertion allocates the whole map.
+    #[inline]
+    pub fn new(domain: Domain<K>) -> Self {
+        DynOrdinalMap {
+            domain,
+            map: Box::default(),
+        }
+    }
+
+    /// The domain of the map keys.
+    #[inline]
+    pub fn domain(&self) -> Domain<K> {
+        self.domain
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V> {
+        self.map.get(key.dyn_ordinal())?.as_ref()
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V> {
+        self.map.get_mut(key.dyn_ordinal())?.as_mut()
+    }
+
+    /// Returns `true` if the map contains the key.
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of elements in the map. This is an `O(domain.size())` operation.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Return true if the map container no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Allocate the map and return the slot for the key.
+    fn slot(&mut self, key: &K) -> &mut Option<V> {
+        let ordinal = self
+            .domain
+            .ordinal(key)
+            .expect("key is out of the map domain");
+        if self.map.is_empty() {
+            let mut map = Vec::with_capacity(self.domain.size());
+            for _ in 0..self.domain.size() {
+                map.push(None);
+            }
+            self.map = map.into_boxed_slice();
+        }
+        &mut self.map[ordinal]
+    }
+
+    /// Insert a value into the map, returning the previous value if it existed.
+    ///
+    /// # Panics
+    ///
+    /// If the key is not in the domain of the map.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.slot(&key).replace(value)
+    }
+
+    /// Get an entry in the map for the given key.
+    ///
+    /// # Panics
+    ///
+    /// If the key is not in the domain of the map.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let entry = self.slot(&key);
+        Entry::new(key, entry)
+    }
+
+    /// Remove a value from the map, returning it if it existed.
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.map.get_mut(key.dyn_ordinal())?.take()
+    }
+
+    /// Iterate over the map.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(SlotsRef::flat(self.map.iter()))
+    }
+
+    /// Iterate over the map mutably.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(SlotsMut::flat(self.map.iter_mut()))
+    }
+
+    /// Iterate over the keys of the map.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self.iter())
+    }
+
+    /// Iterate over the values of the map.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values::new(self.iter())
+    }
+
+    /// Iterate over the mutable references to the values of the map.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut::new(self.iter_mut())
+    }
+
+    /// Clears the map, returning all key-value pairs as an iterator.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        Drain::new(DrainSlots::flat(self.map.iter_mut()))
+    }
+
+    /// Remove all elements from the map.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.drain();
+    }
+}
+
+impl<K: DynOrdinal, V> Extend<(K, V)> for DynOrdinalMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K, V: Clone> Clone for DynOrdinalMap<K, V> {
+    fn clone(&self) -> Self {
+        DynOrdinalMap {
+            domain: self.domain,
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K: DynOrdinal + Debug, V: Debug> Debug for DynOrdinalMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: DynOrdinal, V> IntoIterator for DynOrdinalMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(IntoSlots::flat(self.map.into_vec().into_iter()))
+    }
+}
+
+impl<'a, K: DynOrdinal, V> IntoIterator for &'a DynOrdinalMap<K, V> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::map::DynOrdinalMap;
+    use crate::Domain;
+
+    #[quickcheck]
+    fn qc(size: u8, values: Vec<(u8, u32)>, check: Vec<u8>) {
+        let domain: Domain<usize> = Domain::new(size as usize);
+        let mut map = DynOrdinalMap::new(domain);
+        let mut control: BTreeMap<usize, u32> = BTreeMap::new();
+
+        for (key, value) in &values {
+            let key = *key as usize;
+            if !domain.contains(&key) {
+                continue;
+            }
+            let control_inserted = control.insert(key, *value);
+            let inserted = map.insert(key, *value);
+            assert_eq!(control_inserted, inserted);
+            assert_eq!(control.len(), map.len());
+        }
+
+        for key in &check {
+            let key = *key as usize;
+            assert_eq!(control.get(&key), map.get(&key));
+            assert_eq!(control.contains_key(&key), map.contains_key(&key));
+        }
+
+        assert!(map.iter().eq(control.iter().map(|(k, v)| (*k, v))));
+        assert!(map.into_iter().eq(control));
+    }
+
+    #[test]
+    #[should_panic(expected = "key is out of the map domain")]
+    fn test_insert_out_of_domain() {
+        DynOrdinalMap::new(Domain::<usize>::new(3)).insert(3, ());
+    }
+}
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::Ordinal;
+use crate::DynOrdinal;
 
 pub(crate) struct OrdinalEnumerate<K, I> {
     pub(crate) next: usize,
@@ -18,13 +18,14 @@ impl<K, I> OrdinalEnumerate<K, I> {
     }
 }
 
-impl<K: Ordinal, I: Iterator> Iterator for OrdinalEnumerate<K, I> {
+impl<K: DynOrdinal, I: Iterator> Iterator for OrdinalEnumerate<K, I> {
     type Item = (K, I::Item);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         let v = self.iter.next()?;
-        let k = K::from_ordinal(self.next).unwrap();
+        // SAFETY: iterator is created from a slice with an element per key of the domain.
+        let k = unsafe { K::from_dyn_ordinal_unchecked(self.next) };
         self.next += 1;
         Some((k, v))
     }
@@ -35,20 +36,21 @@ impl<K: Ordinal, I: Iterator> Iterator for OrdinalEnumerate<K, I> {
     }
 }
 
-impl<K: Ordinal, I: ExactSizeIterator> ExactSizeIterator for OrdinalEnumerate<K, I> {
+impl<K: DynOrdinal, I: ExactSizeIterator> ExactSizeIterator for OrdinalEnumerate<K, I> {
     #[inline]
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<K: Ordinal, I: ExactSizeIterator + DoubleEndedIterator> DoubleEndedIterator
+impl<K: DynOrdinal, I: ExactSizeIterator + DoubleEndedIterator> DoubleEndedIterator
     for OrdinalEnumerate<K, I>
 {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         let v = self.iter.next_back()?;
-        let k = K::from_ordinal(self.next + self.iter.len()).unwrap();
+        // SAFETY: iterator is created from a slice with an element per key of the domain.
+        let k = unsafe { K::from_dyn_ordinal_unchecked(self.next + self.iter.len()) };
         Some((k, v))
     }
 }
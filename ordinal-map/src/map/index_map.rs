@@ -0,0 +1,487 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+use std::mem;
+use std::slice;
+
+use crate::Ordinal;
+
+/// Marker of an absent key in the position table.
+const NO_POSITION: usize = usize::MAX;
+
+/// Map with [`Ordinal`] keys which preserves insertion order,
+/// similar to `IndexMap` of the `indexmap` crate.
+///
+/// Entries are stored in a vector in insertion order,
+/// and a table of `K::ORDINAL_SIZE` positions gives constant time lookup by key.
+/// Entries can also be accessed by their index in the order,
+/// and the order can be changed with
+/// [`sort_by`](OrdinalIndexMap::sort_by) and [`move_index`](OrdinalIndexMap::move_index).
+///
+/// The position table is allocated on the first insertion.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::map::OrdinalIndexMap;
+/// #[derive(ordinal_map::Ordinal, Debug, Eq, PartialEq)]
+/// enum Category {
+///     Fruit,
+///     Vegetable,
+///     Grain,
+/// }
+///
+/// let mut map = OrdinalIndexMap::new();
+/// map.insert(Category::Grain, 3);
+/// map.insert(Category::Fruit, 5);
+/// map.insert(Category::Vegetable, 1);
+/// assert_eq!(Some((Category::Fruit, &5)), map.get_index(1));
+///
+/// map.sort_by(|_, a, _, b| b.cmp(a));
+/// assert_eq!(
+///     vec![Category::Fruit, Category::Grain, Category::Vegetable],
+///     map.iter().map(|(k, _)| k).collect::<Vec<_>>()
+/// );
+/// ```
+pub struct OrdinalIndexMap<K, V> {
+    /// Ordinals of the keys and values in the map order.
+    entries: Vec<(usize, V)>,
+    /// Index in `entries` for each ordinal, or `NO_POSITION`. Empty until the first insertion.
+    positions: Box<[usize]>,
+    _phantom: PhantomData<K>,
+}
+
+impl<K: Ordinal, V> OrdinalIndexMap<K, V> {
+    /// Create a new empty map. Does not allocate.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalIndexMap {
+            entries: Vec::new(),
+            positions: Box::new([]),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn key(ordinal: usize) -> K {
+        // SAFETY: `entries` contains only ordinals returned by `ordinal` of inserted keys.
+        unsafe { K::from_ordinal_unchecked(ordinal) }
+    }
+
+    /// Update the position table for entries in the given index range.
+    fn update_positions(&mut self, first: usize, end: usize) {
+        for (index, (ordinal, _)) in self.entries[first..end].iter().enumerate() {
+            self.positions[*ordinal] = first + index;
+        }
+    }
+
+    /// Returns the number of elements in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the index of the key in the map order.
+    #[inline]
+    pub fn get_index_of(&self, key: &K) -> Option<usize> {
+        match self.positions.get(key.ordinal()) {
+            Some(&NO_POSITION) | None => None,
+            Some(index) => Some(*index),
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V> {
+        let index = self.get_index_of(key)?;
+        Some(&self.entries[index].1)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V> {
+        let index = self.get_index_of(key)?;
+        Some(&mut self.entries[index].1)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Returns the entry at the index in the map order.
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<(K, &V)> {
+        let (ordinal, value) = self.entries.get(index)?;
+        Some((Self::key(*ordinal), value))
+    }
+
+    /// Returns the entry at the index in the map order with a mutable reference to the value.
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(K, &mut V)> {
+        let (ordinal, value) = self.entries.get_mut(index)?;
+        Some((Self::key(*ordinal), value))
+    }
+
+    /// Insert a value into the map, returning the previous value if it existed.
+    ///
+    /// A new key is appended to the end of the map order,
+    /// an existing key keeps its position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(index) = self.get_index_of(&key) {
+            return Some(mem::replace(&mut self.entries[index].1, value));
+        }
+        if self.positions.is_empty() {
+            self.positions = vec![NO_POSITION; K::ORDINAL_SIZE].into_boxed_slice();
+        }
+        let ordinal = key.ordinal();
+        self.positions[ordinal] = self.entries.len();
+        self.entries.push((ordinal, value));
+        None
+    }
+
+    /// Remove a key from the map, returning the value if it existed.
+    ///
+    /// The last entry takes the position of the removed entry.
+    /// This is a constant time operation.
+    pub fn swap_remove(&mut self, key: &K) -> Option<V> {
+        let index = self.get_index_of(key)?;
+        self.positions[key.ordinal()] = NO_POSITION;
+        let (_, value) = self.entries.swap_remove(index);
+        if let Some((moved, _)) = self.entries.get(index) {
+            self.positions[*moved] = index;
+        }
+        Some(value)
+    }
+
+    /// Remove a key from the map, returning the value if it existed.
+    ///
+    /// The following entries are shifted to keep the order.
+    /// This is an `O(n)` operation.
+    pub fn shift_remove(&mut self, key: &K) -> Option<V> {
+        let index = self.get_index_of(key)?;
+        self.positions[key.ordinal()] = NO_POSITION;
+        let (_, value) = self.entries.remove(index);
+        self.update_positions(index, self.entries.len());
+        Some(value)
+    }
+
+    /// Remove and return the last entry of the map.
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let (ordinal, value) = self.entries.pop()?;
+        self.positions[ordinal] = NO_POSITION;
+        Some((Self::key(ordinal), value))
+    }
+
+    /// Move the entry at index `from` to index `to`, shifting the entries in between.
+    ///
+    /// # Panics
+    ///
+    /// If either index is out of bounds.
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        match from.cmp(&to) {
+            Ordering::Less => self.entries[from..=to].rotate_left(1),
+            Ordering::Greater => self.entries[to..=from].rotate_right(1),
+            Ordering::Equal => {
+                assert!(from < self.entries.len(), "index out of bounds");
+                return;
+            }
+        }
+        self.update_positions(from.min(to), from.max(to) + 1);
+    }
+
+    /// Sort the entries with the comparator function.
+    ///
+    /// The sort is stable.
+    pub fn sort_by(&mut self, mut compare: impl FnMut(&K, &V, &K, &V) -> Ordering) {
+        /// Update the positions even if `compare` panics,
+        /// leaving the entries in an unspecified order.
+        struct UpdatePositions<'a, K: Ordinal, V>(&'a mut OrdinalIndexMap<K, V>);
+
+        impl<'a, K: Ordinal, V> Drop for UpdatePositions<'a, K, V> {
+            fn drop(&mut self) {
+                self.0.update_positions(0, self.0.entries.len());
+            }
+        }
+
+        let map = UpdatePositions(self);
+        map.0
+            .entries
+            .sort_by(|(a, va), (b, vb)| compare(&Self::key(*a), va, &Self::key(*b), vb));
+    }
+
+    /// Remove all elements from the map.
+    pub fn clear(&mut self) {
+        for (ordinal, _) in self.entries.drain(..) {
+            self.positions[ordinal] = NO_POSITION;
+        }
+    }
+
+    /// Iterate over the entries of the map in the map order.
+    #[inline]
+    pub fn iter(&self) -> IterIndex<'_, K, V> {
+        IterIndex {
+            iter: self.entries.iter(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Iterate over the entries of the map in the map order with mutable references to values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMutIndex<'_, K, V> {
+        IterMutIndex {
+            iter: self.entries.iter_mut(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: Ordinal, V> Default for OrdinalIndexMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ordinal, V> FromIterator<(K, V)> for OrdinalIndexMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OrdinalIndexMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ordinal, V> Extend<(K, V)> for OrdinalIndexMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V: Clone> Clone for OrdinalIndexMap<K, V> {
+    fn clone(&self) -> Self {
+        OrdinalIndexMap {
+            entries: self.entries.clone(),
+            positions: self.positions.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: Ordinal + Debug, V: Debug> Debug for OrdinalIndexMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: Ordinal, V> IntoIterator for &'a OrdinalIndexMap<K, V> {
+    type Item = (K, &'a V);
+    type IntoIter = IterIndex<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Ordinal, V> IntoIterator for &'a mut OrdinalIndexMap<K, V> {
+    type Item = (K, &'a mut V);
+    type IntoIter = IterMutIndex<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the entries of [`OrdinalIndexMap`] in the map order.
+pub struct IterIndex<'a, K, V> {
+    iter: slice::Iter<'a, (usize, V)>,
+    _phantom: PhantomData<K>,
+}
+
+impl<'a, K: Ordinal, V> Iterator for IterIndex<'a, K, V> {
+    type Item = (K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (ordinal, value) = self.iter.next()?;
+        Some((OrdinalIndexMap::<K, V>::key(*ordinal), value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: Ordinal, V> ExactSizeIterator for IterIndex<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, K: Ordinal, V> DoubleEndedIterator for IterIndex<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (ordinal, value) = self.iter.next_back()?;
+        Some((OrdinalIndexMap::<K, V>::key(*ordinal), value))
+    }
+}
+
+impl<'a, K, V> Clone for IterIndex<'a, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        IterIndex {
+            iter: self.iter.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the entries of [`OrdinalIndexMap`] in the map order
+/// with mutable references to values.
+pub struct IterMutIndex<'a, K, V> {
+    iter: slice::IterMut<'a, (usize, V)>,
+    _phantom: PhantomData<K>,
+}
+
+impl<'a, K: Ordinal, V> Iterator for IterMutIndex<'a, K, V> {
+    type Item = (K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (ordinal, value) = self.iter.next()?;
+        Some((OrdinalIndexMap::<K, V>::key(*ordinal), value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: Ordinal, V> ExactSizeIterator for IterMutIndex<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, K: Ordinal, V> DoubleEndedIterator for IterMutIndex<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (ordinal, value) = self.iter.next_back()?;
+        Some((OrdinalIndexMap::<K, V>::key(*ordinal), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+    use std::panic::AssertUnwindSafe;
+
+    use crate::map::OrdinalIndexMap;
+
+    /// Check the map against a control vector of entries in the map order.
+    fn check(map: &OrdinalIndexMap<u8, u32>, control: &[(u8, u32)]) {
+        assert_eq!(control.len(), map.len());
+        assert!(map.iter().eq(control.iter().map(|(k, v)| (*k, v))));
+        for (index, (k, v)) in control.iter().enumerate() {
+            assert_eq!(Some(index), map.get_index_of(k));
+            assert_eq!(Some(v), map.get(k));
+            assert_eq!(Some((*k, v)), map.get_index(index));
+        }
+        assert_eq!(None, map.get_index(control.len()));
+    }
+
+    #[quickcheck]
+    fn qc(ops: Vec<(u8, u32, u8)>) {
+        let mut map: OrdinalIndexMap<u8, u32> = OrdinalIndexMap::new();
+        let mut control: Vec<(u8, u32)> = Vec::new();
+        for (key, value, op) in ops {
+            let position = control.iter().position(|(k, _)| *k == key);
+            match op % 5 {
+                0 | 1 => {
+                    let prev = match position {
+                        Some(position) => Some(std::mem::replace(&mut control[position].1, value)),
+                        None => {
+                            control.push((key, value));
+                            None
+                        }
+                    };
+                    assert_eq!(prev, map.insert(key, value));
+                }
+                2 => {
+                    let removed = position.map(|p| control.swap_remove(p).1);
+                    assert_eq!(removed, map.swap_remove(&key));
+                }
+                3 => {
+                    let removed = position.map(|p| control.remove(p).1);
+                    assert_eq!(removed, map.shift_remove(&key));
+                }
+                _ if !control.is_empty() => {
+                    let from = value as usize % control.len();
+                    let to = key as usize % control.len();
+                    let entry = control.remove(from);
+                    control.insert(to, entry);
+                    map.move_index(from, to);
+                }
+                _ => {}
+            }
+            check(&map, &control);
+        }
+
+        control.sort_by_key(|(_, v)| *v);
+        map.sort_by(|_, a, _, b| a.cmp(b));
+        check(&map, &control);
+
+        assert_eq!(control.pop(), map.pop());
+        check(&map, &control);
+        map.clear();
+        check(&map, &[]);
+    }
+
+    #[test]
+    fn test_sort_by_panic() {
+        let mut map: OrdinalIndexMap<u8, u32> =
+            (0..100).map(|k| (k, k as u32 * 37 % 100)).collect();
+        let mut comparisons = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            map.sort_by(|_, a, _, b| {
+                comparisons += 1;
+                assert!(comparisons < 300);
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(100, map.len());
+        for (index, (k, _)) in map.iter().enumerate() {
+            assert_eq!(Some(index), map.get_index_of(&k));
+        }
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut map: OrdinalIndexMap<u8, u32> = [(3, 30), (1, 10), (2, 20)].into_iter().collect();
+        for (k, v) in &mut map {
+            *v += k as u32;
+        }
+        assert_eq!(
+            vec![(2, &22), (1, &11), (3, &33)],
+            map.iter().rev().collect::<Vec<_>>()
+        );
+        assert_eq!("{3: 33, 1: 11, 2: 22}", format!("{:?}", map));
+    }
+}
@@ -1,50 +1,67 @@
+use std::array;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::marker::PhantomData;
-use std::mem;
 
-use crate::map::total;
-use crate::Ordinal;
+use crate::map::enumerate::OrdinalEnumerate;
+use crate::map::slots::DrainSlots;
+use crate::map::slots::IntoSlots;
+use crate::map::slots::PageIter;
+use crate::map::slots::Slots;
+use crate::map::slots::SlotsMut;
+use crate::map::slots::SlotsRef;
+use crate::DynOrdinal;
+
+/// Key of a slot with a value.
+#[inline]
+fn key<K: DynOrdinal>(ordinal: usize) -> K {
+    // SAFETY: only slots of inserted keys have values.
+    unsafe { K::from_dyn_ordinal_unchecked(ordinal) }
+}
 
 /// Iterator over the entries of
-/// [`OrdinalMap`](crate::map::OrdinalMap) and [`OrdinalArrayMap`](crate::map::OrdinalArrayMap).
+/// [`OrdinalMap`](crate::map::OrdinalMap) and other maps of [this module](crate::map).
 pub struct Iter<'a, K, V> {
-    iter: total::Iter<'a, K, Option<V>>,
+    iter: SlotsRef<'a, V>,
+    _phantom: PhantomData<fn() -> K>,
 }
 
 impl<'a, K, V> Iter<'a, K, V> {
     #[inline]
-    pub(crate) fn new(iter: total::Iter<'a, K, Option<V>>) -> Self {
-        Iter { iter }
+    pub(crate) fn new(iter: SlotsRef<'a, V>) -> Self {
+        Iter {
+            iter,
+            _phantom: PhantomData,
+        }
     }
 }
 
-impl<'a, K: Ordinal, V> Iterator for Iter<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Iterator for Iter<'a, K, V> {
     type Item = (K, &'a V);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             let next = self.iter.next()?;
-            if let (k, Some(v)) = next {
-                return Some((k, v));
+            if let (ordinal, Some(v)) = next {
+                return Some((key(ordinal), v));
             }
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.iter.len()))
+        (0, self.iter.size_hint().1)
     }
 }
 
-impl<'a, K: Ordinal, V> DoubleEndedIterator for Iter<'a, K, V> {
+impl<'a, K: DynOrdinal, V> DoubleEndedIterator for Iter<'a, K, V> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         loop {
             let next = self.iter.next_back()?;
-            if let (k, Some(v)) = next {
-                return Some((k, v));
+            if let (ordinal, Some(v)) = next {
+                return Some((key(ordinal), v));
             }
         }
     }
@@ -53,86 +70,88 @@ impl<'a, K: Ordinal, V> DoubleEndedIterator for Iter<'a, K, V> {
 impl<'a, K, V> Clone for Iter<'a, K, V> {
     #[inline]
     fn clone(&self) -> Self {
-        Iter {
-            iter: self.iter.clone(),
-        }
+        Iter::new(self.iter.clone())
     }
 }
 
-impl<'a, K: Ordinal + Debug, V: Debug> Debug for Iter<'a, K, V> {
+impl<'a, K: DynOrdinal + Debug, V: Debug> Debug for Iter<'a, K, V> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
 }
 
 /// Iterator over mutable references to the entries of
-/// [`OrdinalMap`](crate::map::OrdinalMap) and [`OrdinalArrayMap`](crate::map::OrdinalArrayMap).
+/// [`OrdinalMap`](crate::map::OrdinalMap) and other maps of [this module](crate::map).
 pub struct IterMut<'a, K, V> {
-    iter: total::IterMut<'a, K, Option<V>>,
+    iter: SlotsMut<'a, V>,
+    _phantom: PhantomData<fn() -> K>,
 }
 
-impl<'a, K: Ordinal, V> IterMut<'a, K, V> {
+impl<'a, K, V> IterMut<'a, K, V> {
     #[inline]
-    pub(crate) fn new(iter: total::IterMut<'a, K, Option<V>>) -> Self {
-        IterMut { iter }
+    pub(crate) fn new(iter: SlotsMut<'a, V>) -> Self {
+        IterMut {
+            iter,
+            _phantom: PhantomData,
+        }
     }
 
     fn iter(&self) -> Iter<'_, K, V> {
-        Iter::new(self.iter.iter())
+        Iter::new(self.iter.view())
     }
 }
 
-impl<'a, K: Ordinal, V> Iterator for IterMut<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Iterator for IterMut<'a, K, V> {
     type Item = (K, &'a mut V);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             let next = self.iter.next()?;
-            if let (k, Some(v)) = next {
-                return Some((k, v));
+            if let (ordinal, Some(v)) = next {
+                return Some((key(ordinal), v));
             }
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.iter.len()))
+        (0, self.iter.size_hint().1)
     }
 }
 
-impl<'a, K: Ordinal, V> DoubleEndedIterator for IterMut<'a, K, V> {
+impl<'a, K: DynOrdinal, V> DoubleEndedIterator for IterMut<'a, K, V> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         loop {
             let next = self.iter.next_back()?;
-            if let (k, Some(v)) = next {
-                return Some((k, v));
+            if let (ordinal, Some(v)) = next {
+                return Some((key(ordinal), v));
             }
         }
     }
 }
 
-impl<'a, K: Ordinal + Debug, V: Debug> Debug for IterMut<'a, K, V> {
+impl<'a, K: DynOrdinal + Debug, V: Debug> Debug for IterMut<'a, K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
 }
 
 /// Iterator over the keys of [`OrdinalMap`](crate::map::OrdinalMap)
-/// and [`OrdinalArrayMap`](crate::map::OrdinalArrayMap).
+/// and other maps of [this module](crate::map).
 pub struct Keys<'a, K, V> {
     iter: Iter<'a, K, V>,
 }
 
-impl<'a, K: Ordinal, V> Keys<'a, K, V> {
+impl<'a, K, V> Keys<'a, K, V> {
     #[inline]
     pub(crate) fn new(iter: Iter<'a, K, V>) -> Self {
         Keys { iter }
     }
 }
 
-impl<'a, K: Ordinal, V> Iterator for Keys<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Iterator for Keys<'a, K, V> {
     type Item = K;
 
     #[inline]
@@ -146,14 +165,14 @@ impl<'a, K: Ordinal, V> Iterator for Keys<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal, V> DoubleEndedIterator for Keys<'a, K, V> {
+impl<'a, K: DynOrdinal, V> DoubleEndedIterator for Keys<'a, K, V> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back().map(|(k, _)| k)
     }
 }
 
-impl<'a, K: Ordinal, V> Clone for Keys<'a, K, V> {
+impl<'a, K, V> Clone for Keys<'a, K, V> {
     #[inline]
     fn clone(&self) -> Self {
         Keys {
@@ -162,26 +181,26 @@ impl<'a, K: Ordinal, V> Clone for Keys<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal + Debug> Debug for Keys<'a, K, ()> {
+impl<'a, K: DynOrdinal + Debug> Debug for Keys<'a, K, ()> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
 }
 
 /// Iterator over the values of [`OrdinalMap`](crate::map::OrdinalMap)
-/// and [`OrdinalArrayMap`](crate::map::OrdinalArrayMap).
+/// and other maps of [this module](crate::map).
 pub struct Values<'a, K, V> {
     iter: Iter<'a, K, V>,
 }
 
-impl<'a, K: Ordinal, V> Values<'a, K, V> {
+impl<'a, K, V> Values<'a, K, V> {
     #[inline]
     pub(crate) fn new(iter: Iter<'a, K, V>) -> Self {
         Values { iter }
     }
 }
 
-impl<'a, K: Ordinal, V> Iterator for Values<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Iterator for Values<'a, K, V> {
     type Item = &'a V;
 
     #[inline]
@@ -195,14 +214,14 @@ impl<'a, K: Ordinal, V> Iterator for Values<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal, V> DoubleEndedIterator for Values<'a, K, V> {
+impl<'a, K: DynOrdinal, V> DoubleEndedIterator for Values<'a, K, V> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back().map(|(_, v)| v)
     }
 }
 
-impl<'a, K: Ordinal, V> Clone for Values<'a, K, V> {
+impl<'a, K, V> Clone for Values<'a, K, V> {
     #[inline]
     fn clone(&self) -> Self {
         Values {
@@ -211,19 +230,19 @@ impl<'a, K: Ordinal, V> Clone for Values<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal + Debug, V: Debug> Debug for Values<'a, K, V> {
+impl<'a, K: DynOrdinal + Debug, V: Debug> Debug for Values<'a, K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
 }
 
 /// Iterator over mutable references to the values of
-/// [`OrdinalMap`](crate::map::OrdinalMap) and [`OrdinalArrayMap`](crate::map::OrdinalArrayMap).
+/// [`OrdinalMap`](crate::map::OrdinalMap) and other maps of [this module](crate::map).
 pub struct ValuesMut<'a, K, V> {
     iter: IterMut<'a, K, V>,
 }
 
-impl<'a, K: Ordinal, V> ValuesMut<'a, K, V> {
+impl<'a, K, V> ValuesMut<'a, K, V> {
     #[inline]
     pub(crate) fn new(iter: IterMut<'a, K, V>) -> Self {
         ValuesMut { iter }
@@ -236,7 +255,7 @@ impl<'a, K: Ordinal, V> ValuesMut<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal, V> Iterator for ValuesMut<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Iterator for ValuesMut<'a, K, V> {
     type Item = &'a mut V;
 
     #[inline]
@@ -250,112 +269,120 @@ impl<'a, K: Ordinal, V> Iterator for ValuesMut<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+impl<'a, K: DynOrdinal, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back().map(|(_, v)| v)
     }
 }
 
-impl<'a, K: Ordinal + Debug, V: Debug> Debug for ValuesMut<'a, K, V> {
+impl<'a, K: DynOrdinal + Debug, V: Debug> Debug for ValuesMut<'a, K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
 }
 
 /// Iterator that removes all key-value pairs from [`OrdinalMap`](crate::map::OrdinalMap)
-/// or [`OrdinalArrayMap`](crate::map::OrdinalArrayMap).
-pub struct Drain<'a, K: Ordinal, V> {
-    iter: total::IterMut<'a, K, Option<V>>,
+/// or other maps of [this module](crate::map).
+pub struct Drain<'a, K: DynOrdinal, V> {
+    iter: DrainSlots<'a, V>,
+    _phantom: PhantomData<fn() -> K>,
 }
 
-impl<'a, K: Ordinal, V> Drain<'a, K, V> {
-    pub(crate) fn new(iter: total::IterMut<'a, K, Option<V>>) -> Self {
-        Drain { iter }
+impl<'a, K: DynOrdinal, V> Drain<'a, K, V> {
+    pub(crate) fn new(iter: DrainSlots<'a, V>) -> Self {
+        Drain {
+            iter,
+            _phantom: PhantomData,
+        }
     }
 }
 
-impl<'a, K: Ordinal, V> Drop for Drain<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Drop for Drain<'a, K, V> {
     fn drop(&mut self) {
         for _ in self {}
     }
 }
 
-impl<'a, K: Ordinal, V> Iterator for Drain<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Iterator for Drain<'a, K, V> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let (k, v) = self.iter.next()?;
-            if let Some(v) = mem::take(v) {
-                return Some((k, v));
+            let (ordinal, v) = self.iter.next()?;
+            if let Some(v) = v {
+                return Some((key(ordinal), v));
             }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.iter.len()))
+        (0, self.iter.size_hint().1)
     }
 }
 
-impl<'a, K: Ordinal, V> DoubleEndedIterator for Drain<'a, K, V> {
+impl<'a, K: DynOrdinal, V> DoubleEndedIterator for Drain<'a, K, V> {
     fn next_back(&mut self) -> Option<Self::Item> {
         loop {
-            let (k, v) = self.iter.next_back()?;
-            if let Some(v) = mem::take(v) {
-                return Some((k, v));
+            let (ordinal, v) = self.iter.next_back()?;
+            if let Some(v) = v {
+                return Some((key(ordinal), v));
             }
         }
     }
 }
 
-/// Iterator created from [`OrdinalInitArrayMap`](total::OrdinalTotalArrayMap).
+/// Iterator created from [`OrdinalArrayMap`](crate::map::OrdinalArrayMap).
 pub struct IntoIterArray<K, V, const S: usize> {
-    iter: total::IntoIterArray<K, Option<V>, S>,
-    _phantom: PhantomData<K>,
+    iter: OrdinalEnumerate<usize, array::IntoIter<Option<V>, S>>,
+    _phantom: PhantomData<fn() -> K>,
 }
 
-impl<K: Ordinal, V, const S: usize> IntoIterArray<K, V, S> {
+impl<K: DynOrdinal, V, const S: usize> IntoIterArray<K, V, S> {
     #[inline]
-    pub(crate) fn new(iter: total::IntoIterArray<K, Option<V>, S>) -> Self {
+    pub(crate) fn new(iter: array::IntoIter<Option<V>, S>) -> Self {
         IntoIterArray {
-            iter,
+            iter: OrdinalEnumerate::new(iter, 0),
             _phantom: PhantomData,
         }
     }
 
     fn iter(&self) -> Iter<'_, K, V> {
-        Iter::new(self.iter.iter())
+        let slots = self.iter.iter.as_slice().iter();
+        Iter::new(Slots::new(
+            PageIter::flat(slots, self.iter.next),
+            Default::default(),
+        ))
     }
 }
 
-impl<K: Ordinal, V, const S: usize> Iterator for IntoIterArray<K, V, S> {
+impl<K: DynOrdinal, V, const S: usize> Iterator for IntoIterArray<K, V, S> {
     type Item = (K, V);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let (k, v) = self.iter.next()?;
+            let (ordinal, v) = self.iter.next()?;
             if let Some(v) = v {
-                return Some((k, v));
+                return Some((key(ordinal), v));
             }
         }
     }
 }
 
-impl<K: Ordinal, V, const S: usize> DoubleEndedIterator for IntoIterArray<K, V, S> {
+impl<K: DynOrdinal, V, const S: usize> DoubleEndedIterator for IntoIterArray<K, V, S> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         loop {
-            let (k, v) = self.iter.next_back()?;
+            let (ordinal, v) = self.iter.next_back()?;
             if let Some(v) = v {
-                return Some((k, v));
+                return Some((key(ordinal), v));
             }
         }
     }
 }
 
-impl<K: Ordinal + Debug, V: Debug, const S: usize> Debug for IntoIterArray<K, V, S> {
+impl<K: DynOrdinal + Debug, V: Debug, const S: usize> Debug for IntoIterArray<K, V, S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
@@ -370,51 +397,56 @@ impl<K, V: Clone, const S: usize> Clone for IntoIterArray<K, V, S> {
     }
 }
 
-/// Iterator created from [`OrdinalMap`](crate::map::OrdinalMap).
+/// Iterator created from [`OrdinalMap`](crate::map::OrdinalMap)
+/// or other maps of [this module](crate::map).
 pub struct IntoIter<K, V> {
-    iter: total::IntoIter<K, Option<V>>,
+    iter: IntoSlots<V>,
+    _phantom: PhantomData<fn() -> K>,
 }
 
 impl<K, V> IntoIter<K, V> {
-    pub(crate) fn new(iter: total::IntoIter<K, Option<V>>) -> Self {
-        IntoIter { iter }
+    pub(crate) fn new(iter: IntoSlots<V>) -> Self {
+        IntoIter {
+            iter,
+            _phantom: PhantomData,
+        }
     }
 
     fn iter(&self) -> Iter<'_, K, V> {
-        Iter::new(self.iter.iter())
+        Iter::new(self.iter.view())
     }
 }
 
-impl<K: Ordinal, V> Iterator for IntoIter<K, V> {
+impl<K: DynOrdinal, V> Iterator for IntoIter<K, V> {
     type Item = (K, V);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let (k, v) = self.iter.next()?;
+            let (ordinal, v) = self.iter.next()?;
             if let Some(v) = v {
-                return Some((k, v));
+                return Some((key(ordinal), v));
             }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.iter.len()))
+        (0, self.iter.size_hint().1)
     }
 }
 
-impl<K: Ordinal, V> DoubleEndedIterator for IntoIter<K, V> {
+impl<K: DynOrdinal, V> DoubleEndedIterator for IntoIter<K, V> {
     fn next_back(&mut self) -> Option<Self::Item> {
         loop {
-            let (k, v) = self.iter.next_back()?;
+            let (ordinal, v) = self.iter.next_back()?;
             if let Some(v) = v {
-                return Some((k, v));
+                return Some((key(ordinal), v));
             }
         }
     }
 }
 
-impl<K: Ordinal + Debug, V: Debug> Debug for IntoIter<K, V> {
+impl<K: DynOrdinal + Debug, V: Debug> Debug for IntoIter<K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
@@ -422,8 +454,6 @@ impl<K: Ordinal + Debug, V: Debug> Debug for IntoIter<K, V> {
 
 impl<K, V: Clone> Clone for IntoIter<K, V> {
     fn clone(&self) -> Self {
-        IntoIter {
-            iter: self.iter.clone(),
-        }
+        IntoIter::new(self.iter.clone())
     }
 }
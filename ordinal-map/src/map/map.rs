@@ -1,15 +1,23 @@
+use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Debug;
-use std::marker::PhantomData;
+use std::iter;
 
 use crate::map::iter::ValuesMut;
-use crate::map::total;
+use crate::map::paged::PAGED_THRESHOLD;
+use crate::map::patch::MapChange;
+use crate::map::patch::OrdinalMapPatch;
+use crate::map::slots::DrainSlots;
+use crate::map::slots::IntoSlots;
+use crate::map::slots::SlotsMut;
+use crate::map::slots::SlotsRef;
 use crate::map::Drain;
 use crate::map::Entry;
 use crate::map::IntoIter;
 use crate::map::Iter;
 use crate::map::IterMut;
 use crate::map::Keys;
+use crate::map::OrdinalPagedMap;
 use crate::map::Values;
 use crate::Ordinal;
 
@@ -21,45 +29,63 @@ use crate::Ordinal;
 /// on the first insertion. For non-allocating map, consider using
 /// [`OrdinalArrayMap`](crate::map::OrdinalArrayMap).
 ///
+/// When `K::ORDINAL_SIZE` is greater than `65536` (for example, `u32` or `char`),
+/// the map is stored as [`OrdinalPagedMap`] instead, which allocates pages of slots on demand.
+/// The representation is selected at compile time from `K::ORDINAL_SIZE`.
+///
 /// This implementation is sparse (not every key has an associated value).
-/// For a total map, see [`OrdinalTotalMap`](total::OrdinalTotalMap)
-/// and [`OrdinalTotalArrayMap`](total::OrdinalTotalArrayMap).
+/// For a total map, see [`OrdinalTotalMap`](crate::map::total::OrdinalTotalMap)
+/// and [`OrdinalTotalArrayMap`](crate::map::total::OrdinalTotalArrayMap).
 pub struct OrdinalMap<K, V> {
-    // Empty when the map is just created.
+    // Empty when the map is just created or when the map is paged.
     map: Box<[Option<V>]>,
-    _phantom: PhantomData<K>,
+    // Empty unless the map is paged.
+    paged: OrdinalPagedMap<K, V>,
 }
 
 impl<K: Ordinal, V> OrdinalMap<K, V> {
+    /// Branches on this constant are resolved at compile time.
+    const IS_PAGED: bool = K::ORDINAL_SIZE > PAGED_THRESHOLD;
+
     /// Create a new empty map.
-    /// This operation does not allocate memory, but first insertion allocates the whole map.
+    /// This operation does not allocate memory, but first insertion allocates the whole map
+    /// (or a page of it for large key types).
     #[inline]
     pub fn new() -> Self {
         OrdinalMap {
             map: Box::default(),
-            _phantom: PhantomData,
+            paged: OrdinalPagedMap::new(),
         }
     }
 
     /// Returns a reference to the value corresponding to the key.
     #[inline]
     pub fn get<'a>(&'a self, key: &K) -> Option<&'a V> {
-        self.map.get(key.ordinal())?.as_ref()
+        if Self::IS_PAGED {
+            self.paged.get(key)
+        } else {
+            self.map.get(key.ordinal())?.as_ref()
+        }
     }
 
     /// Returns a mutable reference to the value corresponding to the key.
     #[inline]
     pub fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V> {
-        self.map.get_mut(key.ordinal())?.as_mut()
+        if Self::IS_PAGED {
+            self.paged.get_mut(key)
+        } else {
+            self.map.get_mut(key.ordinal())?.as_mut()
+        }
     }
 
     /// Returns `true` if the map contains the key.
     #[inline]
     pub fn contains_key(&self, key: &K) -> bool {
-        self.map.get(key.ordinal()).is_some()
+        self.get(key).is_some()
     }
 
-    /// Returns the number of elements in the map. This is an `O(K::ORDINAL_SIZE)` operation.
+    /// Returns the number of elements in the map. This is an `O(K::ORDINAL_SIZE)` operation
+    /// (`O(allocated pages * page size)` for large key types).
     #[inline]
     pub fn len(&self) -> usize {
         self.iter().count()
@@ -84,12 +110,18 @@ impl<K: Ordinal, V> OrdinalMap<K, V> {
     /// Insert a value into the map, returning the previous value if it existed.
     #[inline]
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if Self::IS_PAGED {
+            return self.paged.insert(key, value);
+        }
         self.init_full_map();
         self.map[key.ordinal()].replace(value)
     }
 
     /// Get an entry in the map for the given key.
     pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        if Self::IS_PAGED {
+            return self.paged.entry(key);
+        }
         self.init_full_map();
         let entry = &mut self.map[key.ordinal()];
         Entry::new(key, entry)
@@ -98,19 +130,60 @@ impl<K: Ordinal, V> OrdinalMap<K, V> {
     /// Remove a value from the map, returning it if it existed.
     #[inline]
     pub fn remove(&mut self, key: &K) -> Option<V> {
-        self.map.get_mut(key.ordinal())?.take()
+        if Self::IS_PAGED {
+            self.paged.remove(key)
+        } else {
+            self.map.get_mut(key.ordinal())?.take()
+        }
+    }
+
+    /// All the allocated slots of the map with their ordinals.
+    #[inline]
+    pub(crate) fn slots(&self) -> SlotsRef<'_, V> {
+        if Self::IS_PAGED {
+            self.paged.slots()
+        } else {
+            SlotsRef::flat(self.map.iter())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn slots_mut(&mut self) -> SlotsMut<'_, V> {
+        if Self::IS_PAGED {
+            self.paged.slots_mut()
+        } else {
+            SlotsMut::flat(self.map.iter_mut())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn drain_slots(&mut self) -> DrainSlots<'_, V> {
+        if Self::IS_PAGED {
+            self.paged.drain_slots()
+        } else {
+            DrainSlots::flat(self.map.iter_mut())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn into_slots(self) -> IntoSlots<V> {
+        if Self::IS_PAGED {
+            self.paged.into_slots()
+        } else {
+            IntoSlots::flat(self.map.into_vec().into_iter())
+        }
     }
 
     /// Iterate over the map.
     #[inline]
     pub fn iter(&self) -> Iter<'_, K, V> {
-        Iter::new(total::Iter::new(self.map.iter(), 0))
+        Iter::new(self.slots())
     }
 
     /// Iterate over the map mutably.
     #[inline]
     pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
-        IterMut::new(total::IterMut::new(self.map.iter_mut()))
+        IterMut::new(self.slots_mut())
     }
 
     /// Iterate over the keys of the map.
@@ -134,7 +207,7 @@ impl<K: Ordinal, V> OrdinalMap<K, V> {
     /// Clears the map, returning all key-value pairs as an iterator.
     #[inline]
     pub fn drain(&mut self) -> Drain<'_, K, V> {
-        Drain::new(total::IterMut::new(self.map.iter_mut()))
+        Drain::new(self.drain_slots())
     }
 
     /// Remove all elements from the map.
@@ -142,6 +215,91 @@ impl<K: Ordinal, V> OrdinalMap<K, V> {
     pub fn clear(&mut self) {
         self.drain();
     }
+
+    /// Iterate over the keys which are added, removed or changed in `other`
+    /// compared to this map, in ordinal order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ordinal_map::map::MapChange;
+    /// use ordinal_map::map::OrdinalMap;
+    ///
+    /// let old = OrdinalMap::from_iter([(1u8, "a"), (2, "b"), (3, "c")]);
+    /// let new = OrdinalMap::from_iter([(2u8, "b"), (3, "x"), (4, "d")]);
+    /// assert_eq!(
+    ///     vec![
+    ///         (1, MapChange::Removed(&"a")),
+    ///         (3, MapChange::Changed(&"c", &"x")),
+    ///         (4, MapChange::Added(&"d")),
+    ///     ],
+    ///     old.diff(&new).collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a OrdinalMap<K, V>,
+    ) -> impl Iterator<Item = (K, MapChange<'a, V>)>
+    where
+        V: PartialEq,
+    {
+        let mut old = self.iter().peekable();
+        let mut new = other.iter().peekable();
+        iter::from_fn(move || loop {
+            let order = match (old.peek(), new.peek()) {
+                (Some((a, _)), Some((b, _))) => a.ordinal().cmp(&b.ordinal()),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => return None,
+            };
+            match order {
+                Ordering::Less => {
+                    let (k, v) = old.next()?;
+                    return Some((k, MapChange::Removed(v)));
+                }
+                Ordering::Greater => {
+                    let (k, v) = new.next()?;
+                    return Some((k, MapChange::Added(v)));
+                }
+                Ordering::Equal => {
+                    let (k, a) = old.next()?;
+                    let (_, b) = new.next()?;
+                    if a != b {
+                        return Some((k, MapChange::Changed(a, b)));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Create a patch which turns this map into `other` when applied
+    /// with [`apply_patch`](OrdinalMap::apply_patch).
+    /// Removed keys are stored as `None`.
+    pub fn patch(&self, other: &OrdinalMap<K, V>) -> OrdinalMapPatch<K, Option<V>>
+    where
+        V: PartialEq + Clone,
+    {
+        self.diff(other)
+            .map(|(k, change)| match change {
+                MapChange::Added(v) | MapChange::Changed(_, v) => (k, Some(v.clone())),
+                MapChange::Removed(_) => (k, None),
+            })
+            .collect()
+    }
+
+    /// Insert the entries of the patch with values and remove the entries without values.
+    pub fn apply_patch(&mut self, patch: OrdinalMapPatch<K, Option<V>>) {
+        for (key, value) in patch {
+            match value {
+                Some(value) => {
+                    self.insert(key, value);
+                }
+                None => {
+                    self.remove(&key);
+                }
+            }
+        }
+    }
 }
 
 impl<K: Ordinal, V> Default for OrdinalMap<K, V> {
@@ -165,7 +323,7 @@ impl<K, V: Clone> Clone for OrdinalMap<K, V> {
     fn clone(&self) -> Self {
         OrdinalMap {
             map: self.map.clone(),
-            _phantom: PhantomData,
+            paged: self.paged.clone(),
         }
     }
 }
@@ -182,7 +340,7 @@ impl<K: Ordinal, V> IntoIterator for OrdinalMap<K, V> {
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter::new(total::IntoIter::new(self.map.into_vec().into_iter()))
+        IntoIter::new(self.into_slots())
     }
 }
 
@@ -198,8 +356,10 @@ impl<'a, K: Ordinal, V> IntoIterator for &'a OrdinalMap<K, V> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use std::collections::HashMap;
 
+    use crate::map::MapChange;
     use crate::map::OrdinalMap;
 
     #[quickcheck]
@@ -218,4 +378,54 @@ mod tests {
             assert_eq!(control.get(key), map.get(key));
         }
     }
+
+    #[quickcheck]
+    fn qc_paged(values: Vec<(u32, u32)>, check: Vec<u32>) {
+        let mut map: OrdinalMap<u32, u32> = OrdinalMap::new();
+        let mut control: HashMap<u32, u32> = HashMap::new();
+
+        for (key, value) in &values {
+            assert_eq!(control.insert(*key, *value), map.insert(*key, *value));
+        }
+
+        for key in &check {
+            assert_eq!(control.get(key), map.get(key));
+            assert_eq!(control.contains_key(key), map.contains_key(key));
+        }
+
+        let mut entries = Vec::from_iter(control);
+        entries.sort();
+        assert!(map.into_iter().eq(entries));
+    }
+
+    #[test]
+    fn test_contains_key() {
+        let mut map: OrdinalMap<u8, ()> = OrdinalMap::new();
+        map.insert(1, ());
+        assert!(map.contains_key(&1));
+        assert!(!map.contains_key(&2));
+    }
+
+    #[quickcheck]
+    fn qc_diff_patch(old: BTreeMap<u8, u8>, new: BTreeMap<u8, u8>) {
+        let old_map = OrdinalMap::from_iter(old.clone());
+        let new_map = OrdinalMap::from_iter(new.clone());
+
+        let mut expected = Vec::new();
+        for key in 0..=u8::MAX {
+            match (old.get(&key), new.get(&key)) {
+                (Some(a), None) => expected.push((key, MapChange::Removed(a))),
+                (None, Some(b)) => expected.push((key, MapChange::Added(b))),
+                (Some(a), Some(b)) if a != b => expected.push((key, MapChange::Changed(a, b))),
+                _ => {}
+            }
+        }
+        assert_eq!(expected, old_map.diff(&new_map).collect::<Vec<_>>());
+
+        let patch = old_map.patch(&new_map);
+        assert_eq!(expected.len(), patch.len());
+        let mut patched = old_map.clone();
+        patched.apply_patch(patch);
+        assert!(patched.iter().eq(new_map.iter()));
+    }
 }
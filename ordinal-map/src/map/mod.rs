@@ -2,16 +2,28 @@
 //! the [`Ordinal`](crate::Ordinal) trait.
 
 pub(crate) mod array_map;
+pub(crate) mod dyn_map;
 pub(crate) mod entry;
 pub(crate) mod enumerate;
+pub(crate) mod index_map;
 pub(crate) mod iter;
 pub(crate) mod map;
+pub(crate) mod paged;
+pub(crate) mod patch;
+pub(crate) mod slots;
+pub(crate) mod small;
+pub(crate) mod sparse;
+pub(crate) mod stamped;
 pub mod total;
 
 pub use array_map::OrdinalArrayMap;
+pub use dyn_map::DynOrdinalMap;
 pub use entry::Entry;
 pub use entry::OccupiedEntry;
 pub use entry::VacantEntry;
+pub use index_map::IterIndex;
+pub use index_map::IterMutIndex;
+pub use index_map::OrdinalIndexMap;
 pub use iter::Drain;
 pub use iter::IntoIter;
 pub use iter::IntoIterArray;
@@ -20,3 +32,11 @@ pub use iter::IterMut;
 pub use iter::Keys;
 pub use iter::Values;
 pub use map::OrdinalMap;
+pub use paged::OrdinalPagedMap;
+pub use patch::MapChange;
+pub use patch::OrdinalMapPatch;
+pub use small::OrdinalSmallMap;
+pub use sparse::OrdinalSparseMap;
+pub use stamped::IterMutStamped;
+pub use stamped::IterStamped;
+pub use stamped::OrdinalStampedMap;
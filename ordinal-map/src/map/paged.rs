@@ -0,0 +1,359 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::map::iter::ValuesMut;
+use crate::map::slots::DrainSlots;
+use crate::map::slots::IntoSlots;
+use crate::map::slots::Page;
+use crate::map::slots::SlotsMut;
+use crate::map::slots::SlotsRef;
+use crate::map::Drain;
+use crate::map::Entry;
+use crate::map::IntoIter;
+use crate::map::Iter;
+use crate::map::IterMut;
+use crate::map::Keys;
+use crate::map::Values;
+use crate::Ordinal;
+
+/// Number of bits of the ordinal used to address a slot within a page.
+///
+/// Directory and pages are of similar size, about `sqrt(size)`.
+pub(crate) const fn page_bits(size: usize) -> u32 {
+    if size <= 1 {
+        return 0;
+    }
+    let bits = usize::BITS - (size - 1).leading_zeros();
+    bits.div_ceil(2)
+}
+
+/// [`OrdinalMap`](crate::map::OrdinalMap) is stored as [`OrdinalPagedMap`]
+/// when `K::ORDINAL_SIZE` is greater than this.
+pub(crate) const PAGED_THRESHOLD: usize = 1 << 16;
+
+/// Map for [`Ordinal`] keys with a large number of values, like `u32` or `char`.
+///
+/// Like [`OrdinalMap`](crate::map::OrdinalMap), map operations are constant time,
+/// but instead of a single `[Option<V>; K::ORDINAL_SIZE]` allocation,
+/// slots are split into pages of about `sqrt(K::ORDINAL_SIZE)` slots
+/// allocated on first insertion of a key into the page.
+/// The first insertion also allocates the directory of pages of about the same size.
+///
+/// [`OrdinalMap`](crate::map::OrdinalMap) uses this representation
+/// when `K::ORDINAL_SIZE` is greater than `65536`.
+/// Prefer this map over [`OrdinalMap`](crate::map::OrdinalMap) for smaller key types
+/// when only a few keys are expected to be present.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::map::OrdinalPagedMap;
+///
+/// let mut widths: OrdinalPagedMap<char, u8> = OrdinalPagedMap::new();
+/// widths.insert('a', 1);
+/// widths.insert('😀', 2);
+/// assert_eq!(Some(&2), widths.get(&'😀'));
+/// assert_eq!(vec![('a', &1), ('😀', &2)], widths.iter().collect::<Vec<_>>());
+/// ```
+pub struct OrdinalPagedMap<K, V> {
+    // Empty when the map is just created.
+    pages: Box<[Page<V>]>,
+    _phantom: PhantomData<K>,
+}
+
+impl<K: Ordinal, V> OrdinalPagedMap<K, V> {
+    const PAGE_BITS: u32 = page_bits(K::ORDINAL_SIZE);
+    const PAGE_SIZE: usize = 1 << Self::PAGE_BITS;
+    const PAGES: usize = K::ORDINAL_SIZE.div_ceil(Self::PAGE_SIZE);
+
+    /// Create a new empty map. This operation does not allocate memory.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalPagedMap {
+            pages: Box::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn slot(&self, key: &K) -> Option<&Option<V>> {
+        let ordinal = key.ordinal();
+        let page = self.pages.get(ordinal >> Self::PAGE_BITS)?.as_ref()?;
+        page.get(ordinal & (Self::PAGE_SIZE - 1))
+    }
+
+    #[inline]
+    fn slot_mut(&mut self, key: &K) -> Option<&mut Option<V>> {
+        let ordinal = key.ordinal();
+        let page = self.pages.get_mut(ordinal >> Self::PAGE_BITS)?.as_mut()?;
+        page.get_mut(ordinal & (Self::PAGE_SIZE - 1))
+    }
+
+    /// Return the slot, allocating the directory and the page if needed.
+    fn slot_alloc(&mut self, key: &K) -> &mut Option<V> {
+        let ordinal = key.ordinal();
+        if self.pages.is_empty() {
+            let mut pages = Vec::with_capacity(Self::PAGES);
+            for _ in 0..Self::PAGES {
+                pages.push(None);
+            }
+            self.pages = pages.into_boxed_slice();
+        }
+        let index = ordinal >> Self::PAGE_BITS;
+        let page = self.pages[index].get_or_insert_with(|| {
+            // Last page is shorter, so every slot has a key.
+            let len = Self::PAGE_SIZE.min(K::ORDINAL_SIZE - (index << Self::PAGE_BITS));
+            let mut page = Vec::with_capacity(len);
+            for _ in 0..len {
+                page.push(None);
+            }
+            page.into_boxed_slice()
+        });
+        &mut page[ordinal & (Self::PAGE_SIZE - 1)]
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V> {
+        self.slot(key)?.as_ref()
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V> {
+        self.slot_mut(key)?.as_mut()
+    }
+
+    /// Returns `true` if the map contains the key.
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of elements in the map.
+    /// This is an `O(allocated pages * page size)` operation.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Return true if the map container no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Insert a value into the map, returning the previous value if it existed.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.slot_alloc(&key).replace(value)
+    }
+
+    /// Get an entry in the map for the given key.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let entry = self.slot_alloc(&key);
+        Entry::new(key, entry)
+    }
+
+    /// Remove a value from the map, returning it if it existed.
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.slot_mut(key)?.take()
+    }
+
+    /// Slots of allocated pages with their ordinals.
+    #[inline]
+    pub(crate) fn slots(&self) -> SlotsRef<'_, V> {
+        SlotsRef::paged(self.pages.iter(), Self::PAGE_BITS)
+    }
+
+    #[inline]
+    pub(crate) fn slots_mut(&mut self) -> SlotsMut<'_, V> {
+        SlotsMut::paged(self.pages.iter_mut(), Self::PAGE_BITS)
+    }
+
+    #[inline]
+    pub(crate) fn drain_slots(&mut self) -> DrainSlots<'_, V> {
+        DrainSlots::paged(self.pages.iter_mut(), Self::PAGE_BITS)
+    }
+
+    #[inline]
+    pub(crate) fn into_slots(self) -> IntoSlots<V> {
+        IntoSlots::paged(self.pages.into_vec().into_iter(), Self::PAGE_BITS)
+    }
+
+    /// Iterate over the map.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.slots())
+    }
+
+    /// Iterate over the map mutably.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(self.slots_mut())
+    }
+
+    /// Iterate over the keys of the map.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self.iter())
+    }
+
+    /// Iterate over the values of the map.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values::new(self.iter())
+    }
+
+    /// Iterate over the mutable references to the values of the map.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut::new(self.iter_mut())
+    }
+
+    /// Clears the map, returning all key-value pairs as an iterator.
+    /// Allocated pages are kept.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        Drain::new(self.drain_slots())
+    }
+
+    /// Remove all elements from the map. Allocated pages are kept.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.drain();
+    }
+}
+
+impl<K: Ordinal, V> Default for OrdinalPagedMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ordinal, V> FromIterator<(K, V)> for OrdinalPagedMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OrdinalPagedMap::new();
+        for (k, v) in iter {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
+impl<K, V: Clone> Clone for OrdinalPagedMap<K, V> {
+    fn clone(&self) -> Self {
+        OrdinalPagedMap {
+            pages: self.pages.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: Ordinal + Debug, V: Debug> Debug for OrdinalPagedMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ordinal, V> IntoIterator for OrdinalPagedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.into_slots())
+    }
+}
+
+impl<'a, K: Ordinal, V> IntoIterator for &'a OrdinalPagedMap<K, V> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::map::paged::page_bits;
+    use crate::map::OrdinalPagedMap;
+    use crate::Ordinal;
+
+    #[test]
+    fn test_page_bits() {
+        assert_eq!(0, page_bits(0));
+        assert_eq!(0, page_bits(1));
+        assert_eq!(1, page_bits(2));
+        assert_eq!(4, page_bits(256));
+        assert_eq!(5, page_bits(257));
+        assert_eq!(11, page_bits(char::ORDINAL_SIZE));
+        assert_eq!(16, page_bits(u32::ORDINAL_SIZE));
+    }
+
+    #[quickcheck]
+    fn qc(values: Vec<(char, u32)>, check: Vec<char>, remove: Vec<char>) {
+        let mut map: OrdinalPagedMap<char, u32> = OrdinalPagedMap::new();
+        let mut control: BTreeMap<char, u32> = BTreeMap::new();
+
+        for (key, value) in &values {
+            assert_eq!(control.insert(*key, *value), map.insert(*key, *value));
+        }
+        for key in &remove {
+            assert_eq!(control.remove(key), map.remove(key));
+        }
+        for key in &check {
+            assert_eq!(control.get(key), map.get(key));
+            assert_eq!(control.contains_key(key), map.contains_key(key));
+        }
+
+        assert_eq!(control.len(), map.len());
+        assert!(map.iter().eq(control.iter().map(|(k, v)| (*k, v))));
+        assert!(map
+            .iter()
+            .rev()
+            .eq(control.iter().rev().map(|(k, v)| (*k, v))));
+        assert!(map.keys().eq(control.keys().copied()));
+        for (_, v) in map.iter_mut() {
+            *v = v.wrapping_add(1);
+        }
+        assert!(map
+            .values()
+            .copied()
+            .eq(control.values().map(|v| v.wrapping_add(1))));
+        assert!(map
+            .clone()
+            .into_iter()
+            .eq(control.iter().map(|(k, v)| (*k, v.wrapping_add(1)))));
+        assert_eq!(control.len(), map.drain().count());
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_last_page() {
+        // Last page is shorter than others.
+        let mut map: OrdinalPagedMap<char, ()> = OrdinalPagedMap::new();
+        map.insert(char::MAX, ());
+        map.insert('\0', ());
+        assert_eq!(vec!['\0', char::MAX], map.keys().collect::<Vec<_>>());
+        assert_eq!(Some(char::MAX), map.keys().next_back());
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut map: OrdinalPagedMap<u32, u8> = OrdinalPagedMap::new();
+        map.insert(u32::MAX, 1);
+        map.insert(7, 2);
+        assert_eq!("{7: 2, 4294967295: 1}", format!("{:?}", map));
+        let mut iter = map.iter_mut();
+        iter.next().unwrap();
+        assert_eq!("[(4294967295, 1)]", format!("{:?}", iter));
+    }
+}
@@ -0,0 +1,132 @@
+use std::slice;
+
+/// Change of an entry between two [`OrdinalMap`](crate::map::OrdinalMap)s.
+///
+/// Produced by [`OrdinalMap::diff`](crate::map::OrdinalMap::diff).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapChange<'a, V> {
+    /// Entry is only in the new map.
+    Added(&'a V),
+    /// Entry is only in the old map.
+    Removed(&'a V),
+    /// Entry is in both maps with different values: old and new value.
+    Changed(&'a V, &'a V),
+}
+
+/// List of entries to overwrite in a map.
+///
+/// Created by [`OrdinalTotalMap::patch`](crate::map::total::OrdinalTotalMap::patch)
+/// as `OrdinalMapPatch<K, V>`, and by [`OrdinalMap::patch`](crate::map::OrdinalMap::patch)
+/// as `OrdinalMapPatch<K, Option<V>>`, where `None` means the key is removed.
+/// Created patches are sorted by key, patches can also be collected from an iterator.
+///
+/// With the `serde` feature the patch can be serialized,
+/// for example to be sent to another process and applied there.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::map::total::OrdinalTotalMap;
+/// #[derive(ordinal_map::Ordinal, Debug, Eq, PartialEq)]
+/// enum Setting {
+///     Threads,
+///     Timeout,
+///     Retries,
+/// }
+///
+/// let old: OrdinalTotalMap<Setting, u32> = OrdinalTotalMap::new(|_| 1);
+/// let mut new = old.clone();
+/// new[&Setting::Timeout] = 30;
+///
+/// let patch = old.patch(&new);
+/// assert_eq!(&[(Setting::Timeout, 30)], patch.entries());
+///
+/// let mut replica = old.clone();
+/// replica.apply_patch(patch);
+/// assert_eq!(30, replica[&Setting::Timeout]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrdinalMapPatch<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K, V> OrdinalMapPatch<K, V> {
+    /// Entries to overwrite, applied in order.
+    #[inline]
+    pub fn entries(&self) -> &[(K, V)] {
+        &self.entries
+    }
+
+    /// Number of entries in the patch.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the patch does not change anything.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries of the patch.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, (K, V)> {
+        self.entries.iter()
+    }
+
+    /// Obtain the entries of the patch.
+    #[inline]
+    pub fn into_entries(self) -> Vec<(K, V)> {
+        self.entries
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OrdinalMapPatch<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        OrdinalMapPatch {
+            entries: Vec::from_iter(iter),
+        }
+    }
+}
+
+impl<K, V> IntoIterator for OrdinalMapPatch<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    #[test]
+    fn test_serde() {
+        use crate::map::total::OrdinalTotalMap;
+        use crate::map::OrdinalMapPatch;
+        use crate::set::OrdinalSet;
+        use crate::set::OrdinalSetPatch;
+
+        let old: OrdinalTotalMap<bool, u32> = OrdinalTotalMap::new(|_| 0);
+        let mut new = old.clone();
+        new[&true] = 7;
+        let patch = old.patch(&new);
+        let json = serde_json::to_string(&patch).unwrap();
+        assert_eq!(r#"{"entries":[[true,7]]}"#, json);
+        let patch: OrdinalMapPatch<bool, u32> = serde_json::from_str(&json).unwrap();
+        let mut replica = old.clone();
+        replica.apply_patch(patch);
+        assert_eq!(7, replica[&true]);
+
+        let patch = OrdinalSet::from_iter([1u8]).patch(&OrdinalSet::from_iter([2u8]));
+        let json = serde_json::to_string(&patch).unwrap();
+        assert_eq!(r#"{"added":[2],"removed":[1]}"#, json);
+        assert_eq!(
+            patch,
+            serde_json::from_str::<OrdinalSetPatch<u8>>(&json).unwrap()
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use std::slice;
+use std::vec;
+
+use crate::map::enumerate::OrdinalEnumerate;
+
+/// Page of slots, `None` if not allocated yet.
+pub(crate) type Page<V> = Option<Box<[Option<V>]>>;
+
+/// Iterator over the underlying slice.
+pub(crate) trait AsSlice {
+    type Elem;
+
+    fn as_slice(&self) -> &[Self::Elem];
+}
+
+impl<'a, T> AsSlice for slice::Iter<'a, T> {
+    type Elem = T;
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        slice::Iter::as_slice(self)
+    }
+}
+
+impl<'a, T> AsSlice for slice::IterMut<'a, T> {
+    type Elem = T;
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        slice::IterMut::as_slice(self)
+    }
+}
+
+impl<T> AsSlice for vec::IntoIter<T> {
+    type Elem = T;
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        vec::IntoIter::as_slice(self)
+    }
+}
+
+/// Slots of a page.
+pub(crate) trait PageSlots {
+    type Slots: ExactSizeIterator + DoubleEndedIterator;
+
+    /// Slots of the page, `None` if the page is not allocated.
+    fn slots(self) -> Option<Self::Slots>;
+}
+
+impl<'a, V> PageSlots for &'a Page<V> {
+    type Slots = slice::Iter<'a, Option<V>>;
+
+    #[inline]
+    fn slots(self) -> Option<Self::Slots> {
+        self.as_deref().map(|page| page.iter())
+    }
+}
+
+impl<'a, V> PageSlots for &'a mut Page<V> {
+    type Slots = slice::IterMut<'a, Option<V>>;
+
+    #[inline]
+    fn slots(self) -> Option<Self::Slots> {
+        self.as_deref_mut().map(|page| page.iter_mut())
+    }
+}
+
+impl<V> PageSlots for Page<V> {
+    type Slots = vec::IntoIter<Option<V>>;
+
+    #[inline]
+    fn slots(self) -> Option<Self::Slots> {
+        self.map(|page| page.into_vec().into_iter())
+    }
+}
+
+type PageEnumerate<D> = OrdinalEnumerate<usize, <<D as Iterator>::Item as PageSlots>::Slots>;
+
+/// Slots of allocated pages with their ordinals,
+/// like [`Flatten`](std::iter::Flatten) over pages.
+///
+/// Flat storage is iterated as a single page.
+pub(crate) struct PageIter<D: Iterator>
+where
+    D::Item: PageSlots,
+{
+    front: Option<PageEnumerate<D>>,
+    pages: OrdinalEnumerate<usize, D>,
+    back: Option<PageEnumerate<D>>,
+    page_bits: u32,
+}
+
+impl<D: Iterator> PageIter<D>
+where
+    D::Item: PageSlots,
+{
+    /// Slots of pages of `1 << page_bits` slots.
+    #[inline]
+    pub(crate) fn new(pages: D, page_bits: u32) -> Self {
+        PageIter {
+            front: None,
+            pages: OrdinalEnumerate::new(pages, 0),
+            back: None,
+            page_bits,
+        }
+    }
+
+    /// Slots of flat storage, the first slot has ordinal `next`.
+    #[inline]
+    pub(crate) fn flat(slots: <D::Item as PageSlots>::Slots, next: usize) -> Self
+    where
+        D: Default,
+    {
+        PageIter {
+            front: Some(OrdinalEnumerate::new(slots, next)),
+            pages: OrdinalEnumerate::new(D::default(), 0),
+            back: None,
+            page_bits: 0,
+        }
+    }
+
+    #[inline]
+    fn page(&self, (index, page): (usize, D::Item)) -> Option<PageEnumerate<D>> {
+        Some(OrdinalEnumerate::new(
+            page.slots()?,
+            index << self.page_bits,
+        ))
+    }
+
+    /// Immutable view of the remaining slots.
+    pub(crate) fn view<V>(&self) -> PageIter<slice::Iter<'_, Page<V>>>
+    where
+        D: AsSlice<Elem = Page<V>>,
+        <D::Item as PageSlots>::Slots: AsSlice<Elem = Option<V>>,
+    {
+        fn slots<S: AsSlice>(
+            s: &OrdinalEnumerate<usize, S>,
+        ) -> OrdinalEnumerate<usize, slice::Iter<'_, S::Elem>> {
+            OrdinalEnumerate::new(s.iter.as_slice().iter(), s.next)
+        }
+        PageIter {
+            front: self.front.as_ref().map(slots),
+            pages: slots(&self.pages),
+            back: self.back.as_ref().map(slots),
+            page_bits: self.page_bits,
+        }
+    }
+}
+
+impl<D: Iterator + Default> Default for PageIter<D>
+where
+    D::Item: PageSlots,
+{
+    #[inline]
+    fn default() -> Self {
+        PageIter::new(D::default(), 0)
+    }
+}
+
+impl<D: ExactSizeIterator + DoubleEndedIterator> Iterator for PageIter<D>
+where
+    D::Item: PageSlots,
+{
+    type Item = (usize, <<D::Item as PageSlots>::Slots as Iterator>::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(front) = &mut self.front {
+                if let Some(next) = front.next() {
+                    return Some(next);
+                }
+                self.front = None;
+            }
+            match self.pages.next() {
+                Some(page) => self.front = self.page(page),
+                None => return self.back.as_mut()?.next(),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let front = self.front.as_ref().map_or(0, |f| f.len());
+        let back = self.back.as_ref().map_or(0, |b| b.len());
+        let pages = self.pages.len().checked_shl(self.page_bits);
+        (0, pages.and_then(|p| p.checked_add(front + back)))
+    }
+}
+
+impl<D: ExactSizeIterator + DoubleEndedIterator> DoubleEndedIterator for PageIter<D>
+where
+    D::Item: PageSlots,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(back) = &mut self.back {
+                if let Some(next) = back.next_back() {
+                    return Some(next);
+                }
+                self.back = None;
+            }
+            match self.pages.next_back() {
+                Some(page) => self.back = self.page(page),
+                None => return self.front.as_mut()?.next_back(),
+            }
+        }
+    }
+}
+
+impl<D: Iterator + Clone> Clone for PageIter<D>
+where
+    D::Item: PageSlots,
+    <D::Item as PageSlots>::Slots: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        PageIter {
+            front: self.front.clone(),
+            pages: self.pages.clone(),
+            back: self.back.clone(),
+            page_bits: self.page_bits,
+        }
+    }
+}
+
+/// Entry sorted by ordinal with its slot.
+pub(crate) trait SortedEntry {
+    type Slot;
+
+    fn into_slot(self) -> (usize, Self::Slot);
+}
+
+impl<'a, V> SortedEntry for &'a (usize, Option<V>) {
+    type Slot = &'a Option<V>;
+
+    #[inline]
+    fn into_slot(self) -> (usize, Self::Slot) {
+        (self.0, &self.1)
+    }
+}
+
+impl<'a, V> SortedEntry for &'a mut (usize, Option<V>) {
+    type Slot = &'a mut Option<V>;
+
+    #[inline]
+    fn into_slot(self) -> (usize, Self::Slot) {
+        (self.0, &mut self.1)
+    }
+}
+
+impl<V> SortedEntry for (usize, Option<V>) {
+    type Slot = Option<V>;
+
+    #[inline]
+    fn into_slot(self) -> (usize, Self::Slot) {
+        self
+    }
+}
+
+/// Slots of entries sorted by ordinal with their ordinals.
+#[derive(Clone, Default)]
+pub(crate) struct EntryIter<I> {
+    iter: I,
+}
+
+impl<I> EntryIter<I> {
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        EntryIter { iter }
+    }
+
+    /// Immutable view of the remaining slots.
+    #[inline]
+    pub(crate) fn view<V>(&self) -> EntryIter<slice::Iter<'_, (usize, Option<V>)>>
+    where
+        I: AsSlice<Elem = (usize, Option<V>)>,
+    {
+        EntryIter::new(self.iter.as_slice().iter())
+    }
+}
+
+impl<I: Iterator> Iterator for EntryIter<I>
+where
+    I::Item: SortedEntry,
+{
+    type Item = (usize, <I::Item as SortedEntry>::Slot);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.into_slot())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for EntryIter<I>
+where
+    I::Item: SortedEntry,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next_back()?.into_slot())
+    }
+}
+
+/// Slots with their ordinals, taking the values out of the slots.
+pub(crate) struct TakeSlots<I> {
+    iter: I,
+}
+
+impl<I> TakeSlots<I> {
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        TakeSlots { iter }
+    }
+}
+
+impl<'a, V: 'a, I: Iterator<Item = (usize, &'a mut Option<V>)>> Iterator for TakeSlots<I> {
+    type Item = (usize, Option<V>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (ordinal, slot) = self.iter.next()?;
+        Some((ordinal, slot.take()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, V: 'a, I: DoubleEndedIterator<Item = (usize, &'a mut Option<V>)>> DoubleEndedIterator
+    for TakeSlots<I>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (ordinal, slot) = self.iter.next_back()?;
+        Some((ordinal, slot.take()))
+    }
+}
+
+/// Entries removed by `drain` of maps storing sorted entries.
+pub(crate) enum DrainEntries<'a, V> {
+    /// Values are taken, entries are left in place.
+    Take(slice::IterMut<'a, (usize, Option<V>)>),
+    Drain(vec::Drain<'a, (usize, Option<V>)>),
+}
+
+impl<'a, V> Default for DrainEntries<'a, V> {
+    #[inline]
+    fn default() -> Self {
+        DrainEntries::Take(Default::default())
+    }
+}
+
+impl<'a, V> Iterator for DrainEntries<'a, V> {
+    type Item = (usize, Option<V>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            DrainEntries::Take(iter) => iter.next().map(|(o, v)| (*o, v.take())),
+            DrainEntries::Drain(iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            DrainEntries::Take(iter) => iter.size_hint(),
+            DrainEntries::Drain(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for DrainEntries<'a, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            DrainEntries::Take(iter) => iter.next_back().map(|(o, v)| (*o, v.take())),
+            DrainEntries::Drain(iter) => iter.next_back(),
+        }
+    }
+}
+
+/// Slots of a map with their ordinals:
+/// slots of pages (flat and paged storage) followed by slots of sorted entries.
+///
+/// Each map uses one of the two parts, and the other part is empty.
+#[derive(Clone)]
+pub(crate) struct Slots<P, E> {
+    pages: P,
+    entries: E,
+}
+
+impl<P, E> Slots<P, E> {
+    #[inline]
+    pub(crate) fn new(pages: P, entries: E) -> Self {
+        Slots { pages, entries }
+    }
+}
+
+impl<P: Iterator, E: Iterator<Item = P::Item>> Iterator for Slots<P, E> {
+    type Item = P::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pages.next() {
+            Some(next) => Some(next),
+            None => self.entries.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (pages_lower, pages_upper) = self.pages.size_hint();
+        let (entries_lower, entries_upper) = self.entries.size_hint();
+        let lower = pages_lower.saturating_add(entries_lower);
+        let upper = pages_upper.and_then(|p| p.checked_add(entries_upper?));
+        (lower, upper)
+    }
+}
+
+impl<P: DoubleEndedIterator, E: DoubleEndedIterator<Item = P::Item>> DoubleEndedIterator
+    for Slots<P, E>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.entries.next_back() {
+            Some(next) => Some(next),
+            None => self.pages.next_back(),
+        }
+    }
+}
+
+pub(crate) type SlotsRef<'a, V> =
+    Slots<PageIter<slice::Iter<'a, Page<V>>>, EntryIter<slice::Iter<'a, (usize, Option<V>)>>>;
+pub(crate) type SlotsMut<'a, V> =
+    Slots<PageIter<slice::IterMut<'a, Page<V>>>, EntryIter<slice::IterMut<'a, (usize, Option<V>)>>>;
+pub(crate) type IntoSlots<V> =
+    Slots<PageIter<vec::IntoIter<Page<V>>>, EntryIter<vec::IntoIter<(usize, Option<V>)>>>;
+pub(crate) type DrainSlots<'a, V> =
+    Slots<TakeSlots<PageIter<slice::IterMut<'a, Page<V>>>>, DrainEntries<'a, V>>;
+
+impl<D: Iterator + Default, I: Default> Slots<PageIter<D>, EntryIter<I>>
+where
+    D::Item: PageSlots,
+{
+    /// Slots of flat storage.
+    #[inline]
+    pub(crate) fn flat(slots: <D::Item as PageSlots>::Slots) -> Self {
+        Slots::new(PageIter::flat(slots, 0), EntryIter::default())
+    }
+
+    /// Slots of pages of `1 << page_bits` slots.
+    #[inline]
+    pub(crate) fn paged(pages: D, page_bits: u32) -> Self {
+        Slots::new(PageIter::new(pages, page_bits), EntryIter::default())
+    }
+
+    /// Slots of entries sorted by ordinal.
+    #[inline]
+    pub(crate) fn sorted(entries: I) -> Self {
+        Slots::new(PageIter::default(), EntryIter::new(entries))
+    }
+}
+
+impl<D: Iterator, I> Slots<PageIter<D>, EntryIter<I>>
+where
+    D::Item: PageSlots,
+{
+    /// Immutable view of the remaining slots.
+    #[inline]
+    pub(crate) fn view<V>(&self) -> SlotsRef<'_, V>
+    where
+        D: AsSlice<Elem = Page<V>>,
+        <D::Item as PageSlots>::Slots: AsSlice<Elem = Option<V>>,
+        I: AsSlice<Elem = (usize, Option<V>)>,
+    {
+        Slots::new(self.pages.view(), self.entries.view())
+    }
+}
+
+impl<'a, V> DrainSlots<'a, V> {
+    /// Slots of flat storage.
+    #[inline]
+    pub(crate) fn flat(slots: slice::IterMut<'a, Option<V>>) -> Self {
+        Slots::new(
+            TakeSlots::new(PageIter::flat(slots, 0)),
+            DrainEntries::default(),
+        )
+    }
+
+    /// Slots of pages of `1 << page_bits` slots.
+    #[inline]
+    pub(crate) fn paged(pages: slice::IterMut<'a, Page<V>>, page_bits: u32) -> Self {
+        Slots::new(
+            TakeSlots::new(PageIter::new(pages, page_bits)),
+            DrainEntries::default(),
+        )
+    }
+
+    /// Entries sorted by ordinal.
+    #[inline]
+    pub(crate) fn sorted(entries: DrainEntries<'a, V>) -> Self {
+        Slots::new(TakeSlots::new(PageIter::default()), entries)
+    }
+}
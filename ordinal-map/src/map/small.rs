@@ -0,0 +1,441 @@
+use std::array;
+use std::fmt;
+use std::fmt::Debug;
+use std::mem;
+
+use crate::map::iter::ValuesMut;
+use crate::map::slots::DrainEntries;
+use crate::map::slots::DrainSlots;
+use crate::map::slots::IntoSlots;
+use crate::map::slots::SlotsMut;
+use crate::map::slots::SlotsRef;
+use crate::map::Drain;
+use crate::map::Entry;
+use crate::map::IntoIter;
+use crate::map::Iter;
+use crate::map::IterMut;
+use crate::map::Keys;
+use crate::map::OrdinalMap;
+use crate::map::Values;
+use crate::Ordinal;
+
+/// Map [`Ordinal`] keys to values, storing up to `N` entries inline.
+///
+/// Up to `N` entries are kept in an array sorted by key ordinal
+/// without heap allocation, and lookup is a linear scan of the array.
+/// Inserting more keys moves the entries to the heap-allocated [`OrdinalMap`]
+/// (the map does not move back).
+///
+/// Unlike [`OrdinalArrayMap`](crate::map::OrdinalArrayMap),
+/// the size of the map does not depend on `K::ORDINAL_SIZE`
+/// until the entries are moved to the heap.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::map::OrdinalSmallMap;
+///
+/// let mut map: OrdinalSmallMap<u16, &str, 2> = OrdinalSmallMap::new();
+/// map.insert(20, "twenty");
+/// map.insert(10, "ten");
+/// assert!(!map.spilled());
+/// map.insert(30, "thirty");
+/// assert!(map.spilled());
+/// assert_eq!(vec![10, 20, 30], map.keys().collect::<Vec<_>>());
+/// ```
+pub struct OrdinalSmallMap<K, V, const N: usize> {
+    map: SmallImpl<K, V, N>,
+}
+
+enum SmallImpl<K, V, const N: usize> {
+    /// First `len` entries are sorted by ordinal, the rest have `None` slots.
+    /// Slot is `None` if a vacant entry was dropped without insertion.
+    Inline {
+        len: usize,
+        entries: [(usize, Option<V>); N],
+    },
+    Heap(OrdinalMap<K, V>),
+}
+
+impl<K: Ordinal, V, const N: usize> OrdinalSmallMap<K, V, N> {
+    /// Create a new empty map.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalSmallMap {
+            map: SmallImpl::Inline {
+                len: 0,
+                entries: array::from_fn(|_| (0, None)),
+            },
+        }
+    }
+
+    /// Index of the entry with the given ordinal,
+    /// or the index where it should be inserted.
+    #[inline]
+    fn search(entries: &[(usize, Option<V>)], ordinal: usize) -> Result<usize, usize> {
+        for (i, (o, _)) in entries.iter().enumerate() {
+            if *o == ordinal {
+                return Ok(i);
+            }
+            if *o > ordinal {
+                return Err(i);
+            }
+        }
+        Err(entries.len())
+    }
+
+    /// Returns `true` if the entries were moved to the heap.
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        matches!(self.map, SmallImpl::Heap(_))
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V> {
+        match &self.map {
+            SmallImpl::Inline { len, entries } => {
+                let index = Self::search(&entries[..*len], key.ordinal()).ok()?;
+                entries[index].1.as_ref()
+            }
+            SmallImpl::Heap(map) => map.get(key),
+        }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V> {
+        match &mut self.map {
+            SmallImpl::Inline { len, entries } => {
+                let index = Self::search(&entries[..*len], key.ordinal()).ok()?;
+                entries[index].1.as_mut()
+            }
+            SmallImpl::Heap(map) => map.get_mut(key),
+        }
+    }
+
+    /// Returns `true` if the map contains the key.
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of elements in the map.
+    /// This is an `O(N)` operation for inline entries,
+    /// and an `O(K::ORDINAL_SIZE)` operation after the map is spilled.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Return true if the map container no elements.
+    /// Like [`len`](OrdinalSmallMap::len), this is an `O(N)` operation for inline entries,
+    /// and an `O(K::ORDINAL_SIZE)` operation after the map is spilled.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Move the entries with values to the front keeping them sorted,
+    /// and return the number of such entries.
+    fn compact(entries: &mut [(usize, Option<V>)]) -> usize {
+        let mut len = 0;
+        for i in 0..entries.len() {
+            if entries[i].1.is_some() {
+                entries.swap(len, i);
+                len += 1;
+            }
+        }
+        len
+    }
+
+    /// Move the entries to the heap.
+    fn spill(&mut self) {
+        if let SmallImpl::Inline { len, entries } = &mut self.map {
+            let entries = DrainEntries::Take(entries[..*len].iter_mut());
+            let map = Drain::new(DrainSlots::sorted(entries)).collect();
+            self.map = SmallImpl::Heap(map);
+        }
+    }
+
+    /// Return the slot for the key, moving the entries to the heap
+    /// if there is no room for the key.
+    fn slot(&mut self, key: &K) -> Result<&mut Option<V>, &mut OrdinalMap<K, V>> {
+        let ordinal = key.ordinal();
+        if let SmallImpl::Inline { len, entries } = &mut self.map {
+            if *len == N && Self::search(&entries[..*len], ordinal).is_err() {
+                // Slots of dropped vacant entries are reused before spilling.
+                *len = Self::compact(&mut entries[..*len]);
+            }
+        }
+        let index = match &mut self.map {
+            SmallImpl::Inline { len, entries } => match Self::search(&entries[..*len], ordinal) {
+                Ok(index) => Some(index),
+                Err(index) if *len < N => {
+                    entries[index..=*len].rotate_right(1);
+                    // Slot may hold a value if `Drain` was leaked.
+                    entries[index] = (ordinal, None);
+                    *len += 1;
+                    Some(index)
+                }
+                Err(_) => None,
+            },
+            SmallImpl::Heap(_) => None,
+        };
+        if index.is_none() {
+            self.spill();
+        }
+        match (&mut self.map, index) {
+            (SmallImpl::Inline { entries, .. }, Some(index)) => Ok(&mut entries[index].1),
+            (SmallImpl::Heap(map), _) => Err(map),
+            (SmallImpl::Inline { .. }, None) => unreachable!(),
+        }
+    }
+
+    /// Insert a value into the map, returning the previous value if it existed.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.slot(&key) {
+            Ok(slot) => slot.replace(value),
+            Err(map) => map.insert(key, value),
+        }
+    }
+
+    /// Get an entry in the map for the given key.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.slot(&key) {
+            Ok(slot) => Entry::new(key, slot),
+            Err(map) => map.entry(key),
+        }
+    }
+
+    /// Remove a value from the map, returning it if it existed.
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        match &mut self.map {
+            SmallImpl::Inline { len, entries } => {
+                let index = Self::search(&entries[..*len], key.ordinal()).ok()?;
+                let value = entries[index].1.take();
+                entries[index..*len].rotate_left(1);
+                *len -= 1;
+                value
+            }
+            SmallImpl::Heap(map) => map.remove(key),
+        }
+    }
+
+    /// Iterate over the map.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(match &self.map {
+            SmallImpl::Inline { len, entries } => SlotsRef::sorted(entries[..*len].iter()),
+            SmallImpl::Heap(map) => map.slots(),
+        })
+    }
+
+    /// Iterate over the map mutably.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(match &mut self.map {
+            SmallImpl::Inline { len, entries } => SlotsMut::sorted(entries[..*len].iter_mut()),
+            SmallImpl::Heap(map) => map.slots_mut(),
+        })
+    }
+
+    /// Iterate over the keys of the map.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self.iter())
+    }
+
+    /// Iterate over the values of the map.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values::new(self.iter())
+    }
+
+    /// Iterate over the mutable references to the values of the map.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut::new(self.iter_mut())
+    }
+
+    /// Clears the map, returning all key-value pairs as an iterator.
+    /// Spilled map stays on the heap.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        Drain::new(match &mut self.map {
+            SmallImpl::Inline { len, entries } => {
+                let len = mem::take(len);
+                DrainSlots::sorted(DrainEntries::Take(entries[..len].iter_mut()))
+            }
+            SmallImpl::Heap(map) => map.drain_slots(),
+        })
+    }
+
+    /// Remove all elements from the map. Spilled map stays on the heap.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.drain();
+    }
+}
+
+impl<K: Ordinal, V, const N: usize> Default for OrdinalSmallMap<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ordinal, V, const N: usize> FromIterator<(K, V)> for OrdinalSmallMap<K, V, N> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OrdinalSmallMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ordinal, V, const N: usize> Extend<(K, V)> for OrdinalSmallMap<K, V, N> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K, V: Clone, const N: usize> Clone for OrdinalSmallMap<K, V, N> {
+    fn clone(&self) -> Self {
+        OrdinalSmallMap {
+            map: match &self.map {
+                SmallImpl::Inline { len, entries } => SmallImpl::Inline {
+                    len: *len,
+                    entries: entries.clone(),
+                },
+                SmallImpl::Heap(map) => SmallImpl::Heap(map.clone()),
+            },
+        }
+    }
+}
+
+impl<K: Ordinal + Debug, V: Debug, const N: usize> Debug for OrdinalSmallMap<K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ordinal, V, const N: usize> IntoIterator for OrdinalSmallMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// Inline entries are moved to a vector.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(match self.map {
+            SmallImpl::Inline { len, entries } => {
+                let entries = Vec::from_iter(entries.into_iter().take(len));
+                IntoSlots::sorted(entries.into_iter())
+            }
+            SmallImpl::Heap(map) => map.into_slots(),
+        })
+    }
+}
+
+impl<'a, K: Ordinal, V, const N: usize> IntoIterator for &'a OrdinalSmallMap<K, V, N> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::map::Entry;
+    use crate::map::OrdinalSmallMap;
+
+    #[quickcheck]
+    fn qc(values: Vec<(u8, u32)>, check: Vec<u8>, remove: Vec<u8>) {
+        let mut map: OrdinalSmallMap<u8, u32, 4> = OrdinalSmallMap::new();
+        let mut control: BTreeMap<u8, u32> = BTreeMap::new();
+
+        for (key, value) in &values {
+            assert_eq!(control.insert(*key, *value), map.insert(*key, *value));
+            assert_eq!(control.len() > 4, map.spilled());
+        }
+        for key in &remove {
+            assert_eq!(control.remove(key), map.remove(key));
+        }
+        for key in &check {
+            assert_eq!(control.get(key), map.get(key));
+            assert_eq!(control.contains_key(key), map.contains_key(key));
+        }
+
+        assert_eq!(control.len(), map.len());
+        assert!(map.iter().eq(control.iter().map(|(k, v)| (*k, v))));
+        assert!(map
+            .iter()
+            .rev()
+            .eq(control.iter().rev().map(|(k, v)| (*k, v))));
+        assert!(map.clone().into_iter().eq(control.clone()));
+        assert!(map.drain().eq(control));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut map: OrdinalSmallMap<u8, u32, 2> = OrdinalSmallMap::new();
+        *map.entry(3).or_insert(0) += 1;
+        *map.entry(3).or_insert(0) += 1;
+        assert_eq!(Some(&2), map.get(&3));
+
+        // Vacant entry dropped without insertion.
+        assert!(matches!(map.entry(5), Entry::Vacant(_)));
+        assert!(!map.contains_key(&5));
+        assert_eq!(vec![(3, &2)], map.iter().collect::<Vec<_>>());
+
+        // Slot of the vacant entry is reused.
+        *map.entry(1).or_insert(0) += 10;
+        assert!(!map.spilled());
+
+        // Spill.
+        *map.entry(7).or_insert(0) += 100;
+        assert!(map.spilled());
+        assert_eq!(
+            vec![(1, &10), (3, &2), (7, &100)],
+            map.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_vacant_entries_not_spilled() {
+        let mut map: OrdinalSmallMap<u8, u32, 2> = OrdinalSmallMap::new();
+        assert!(matches!(map.entry(1), Entry::Vacant(_)));
+        assert!(matches!(map.entry(2), Entry::Vacant(_)));
+        map.insert(3, 30);
+        assert!(!map.spilled());
+        map.insert(0, 0);
+        assert!(!map.spilled());
+        assert_eq!(vec![(0, &0), (3, &30)], map.iter().collect::<Vec<_>>());
+        map.insert(2, 20);
+        assert!(map.spilled());
+        assert_eq!(3, map.len());
+    }
+
+    #[test]
+    fn test_drain_reuse() {
+        let mut map: OrdinalSmallMap<u8, String, 3> = OrdinalSmallMap::new();
+        map.insert(2, "b".to_owned());
+        map.insert(1, "a".to_owned());
+        assert_eq!(
+            vec![(1, "a".to_owned()), (2, "b".to_owned())],
+            map.drain().collect::<Vec<_>>()
+        );
+        map.insert(7, "c".to_owned());
+        assert_eq!(vec![7], map.keys().collect::<Vec<_>>());
+        assert!(!map.spilled());
+    }
+}
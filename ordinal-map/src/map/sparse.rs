@@ -0,0 +1,405 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::mem;
+
+use crate::map::iter::ValuesMut;
+use crate::map::slots::DrainEntries;
+use crate::map::slots::DrainSlots;
+use crate::map::slots::IntoSlots;
+use crate::map::slots::SlotsMut;
+use crate::map::slots::SlotsRef;
+use crate::map::Drain;
+use crate::map::Entry;
+use crate::map::IntoIter;
+use crate::map::Iter;
+use crate::map::IterMut;
+use crate::map::Keys;
+use crate::map::OrdinalMap;
+use crate::map::Values;
+use crate::Ordinal;
+
+/// [`OrdinalSparseMap`] switches to the dense representation
+/// when it would contain more than `K::ORDINAL_SIZE / DENSE_RATIO` entries.
+const DENSE_RATIO: usize = 8;
+
+/// Map [`Ordinal`] keys to values, compact while few keys are present.
+///
+/// Entries are stored in a vector sorted by key ordinal:
+/// lookup is `O(log(len))` and insertion is `O(len)`.
+/// When the number of entries grows above `K::ORDINAL_SIZE / 8`,
+/// the map switches to the dense [`OrdinalMap`] representation with constant time operations.
+/// [`shrink_to_fit`](OrdinalSparseMap::shrink_to_fit) switches back
+/// if there are few entries left.
+///
+/// Like other maps, iteration is in key ordinal order.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::map::OrdinalSparseMap;
+///
+/// let mut ports: OrdinalSparseMap<u16, &str> = OrdinalSparseMap::new();
+/// ports.insert(443, "https");
+/// ports.insert(22, "ssh");
+/// assert!(!ports.is_dense());
+/// assert_eq!(Some(&"ssh"), ports.get(&22));
+/// assert_eq!(vec![22, 443], ports.keys().collect::<Vec<_>>());
+/// ```
+pub struct OrdinalSparseMap<K, V> {
+    map: SparseImpl<K, V>,
+}
+
+enum SparseImpl<K, V> {
+    /// Sorted by ordinal. Slot is `None` if a vacant entry was dropped without insertion.
+    Sparse(Vec<(usize, Option<V>)>),
+    Dense(OrdinalMap<K, V>),
+}
+
+impl<K: Ordinal, V> OrdinalSparseMap<K, V> {
+    const MAX_SPARSE_LEN: usize = K::ORDINAL_SIZE / DENSE_RATIO;
+
+    /// Create a new empty map. This operation does not allocate memory.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalSparseMap {
+            map: SparseImpl::Sparse(Vec::new()),
+        }
+    }
+
+    #[inline]
+    fn search(vec: &[(usize, Option<V>)], ordinal: usize) -> Result<usize, usize> {
+        vec.binary_search_by_key(&ordinal, |(o, _)| *o)
+    }
+
+    /// Returns `true` if the map uses the dense representation.
+    #[inline]
+    pub fn is_dense(&self) -> bool {
+        matches!(self.map, SparseImpl::Dense(_))
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V> {
+        match &self.map {
+            SparseImpl::Sparse(vec) => {
+                let index = Self::search(vec, key.ordinal()).ok()?;
+                vec[index].1.as_ref()
+            }
+            SparseImpl::Dense(map) => map.get(key),
+        }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V> {
+        match &mut self.map {
+            SparseImpl::Sparse(vec) => {
+                let index = Self::search(vec, key.ordinal()).ok()?;
+                vec[index].1.as_mut()
+            }
+            SparseImpl::Dense(map) => map.get_mut(key),
+        }
+    }
+
+    /// Returns `true` if the map contains the key.
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of elements in the map.
+    /// This is an `O(len)` operation for the sparse representation,
+    /// and an `O(K::ORDINAL_SIZE)` operation for the dense representation.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Return true if the map container no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Switch to the dense representation if adding the key
+    /// would make the sorted vector too long.
+    fn prepare_insert(&mut self, ordinal: usize) {
+        if let SparseImpl::Sparse(vec) = &mut self.map {
+            if vec.len() >= Self::MAX_SPARSE_LEN && Self::search(vec, ordinal).is_err() {
+                // Slots of dropped vacant entries do not count.
+                vec.retain(|(_, v)| v.is_some());
+            }
+            if vec.len() >= Self::MAX_SPARSE_LEN && Self::search(vec, ordinal).is_err() {
+                let vec = mem::take(vec);
+                let map = IntoIter::new(IntoSlots::sorted(vec.into_iter())).collect();
+                self.map = SparseImpl::Dense(map);
+            }
+        }
+    }
+
+    /// Insert a value into the map, returning the previous value if it existed.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let ordinal = key.ordinal();
+        self.prepare_insert(ordinal);
+        match &mut self.map {
+            SparseImpl::Sparse(vec) => match Self::search(vec, ordinal) {
+                Ok(index) => vec[index].1.replace(value),
+                Err(index) => {
+                    vec.insert(index, (ordinal, Some(value)));
+                    None
+                }
+            },
+            SparseImpl::Dense(map) => map.insert(key, value),
+        }
+    }
+
+    /// Get an entry in the map for the given key.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let ordinal = key.ordinal();
+        self.prepare_insert(ordinal);
+        match &mut self.map {
+            SparseImpl::Sparse(vec) => {
+                let index = match Self::search(vec, ordinal) {
+                    Ok(index) => index,
+                    Err(index) => {
+                        vec.insert(index, (ordinal, None));
+                        index
+                    }
+                };
+                Entry::new(key, &mut vec[index].1)
+            }
+            SparseImpl::Dense(map) => map.entry(key),
+        }
+    }
+
+    /// Remove a value from the map, returning it if it existed.
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        match &mut self.map {
+            SparseImpl::Sparse(vec) => {
+                let index = Self::search(vec, key.ordinal()).ok()?;
+                vec.remove(index).1
+            }
+            SparseImpl::Dense(map) => map.remove(key),
+        }
+    }
+
+    /// Iterate over the map.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(match &self.map {
+            SparseImpl::Sparse(vec) => SlotsRef::sorted(vec.iter()),
+            SparseImpl::Dense(map) => map.slots(),
+        })
+    }
+
+    /// Iterate over the map mutably.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(match &mut self.map {
+            SparseImpl::Sparse(vec) => SlotsMut::sorted(vec.iter_mut()),
+            SparseImpl::Dense(map) => map.slots_mut(),
+        })
+    }
+
+    /// Iterate over the keys of the map.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self.iter())
+    }
+
+    /// Iterate over the values of the map.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values::new(self.iter())
+    }
+
+    /// Iterate over the mutable references to the values of the map.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut::new(self.iter_mut())
+    }
+
+    /// Clears the map, returning all key-value pairs as an iterator.
+    /// The representation of the map is kept.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        Drain::new(match &mut self.map {
+            SparseImpl::Sparse(vec) => DrainSlots::sorted(DrainEntries::Drain(vec.drain(..))),
+            SparseImpl::Dense(map) => map.drain_slots(),
+        })
+    }
+
+    /// Remove all elements from the map. The representation of the map is kept.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.drain();
+    }
+
+    /// Shrink the memory used by the map, switching back to the sparse representation
+    /// if the map contains few enough entries.
+    pub fn shrink_to_fit(&mut self) {
+        if let SparseImpl::Dense(map) = &mut self.map {
+            if map.len() > Self::MAX_SPARSE_LEN {
+                return;
+            }
+            let vec = mem::take(map)
+                .into_iter()
+                .map(|(k, v)| (k.ordinal(), Some(v)))
+                .collect();
+            self.map = SparseImpl::Sparse(vec);
+        }
+        if let SparseImpl::Sparse(vec) = &mut self.map {
+            vec.retain(|(_, v)| v.is_some());
+            vec.shrink_to_fit();
+        }
+    }
+}
+
+impl<K: Ordinal, V> Default for OrdinalSparseMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ordinal, V> FromIterator<(K, V)> for OrdinalSparseMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OrdinalSparseMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ordinal, V> Extend<(K, V)> for OrdinalSparseMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K, V: Clone> Clone for OrdinalSparseMap<K, V> {
+    fn clone(&self) -> Self {
+        OrdinalSparseMap {
+            map: match &self.map {
+                SparseImpl::Sparse(vec) => SparseImpl::Sparse(vec.clone()),
+                SparseImpl::Dense(map) => SparseImpl::Dense(map.clone()),
+            },
+        }
+    }
+}
+
+impl<K: Ordinal + Debug, V: Debug> Debug for OrdinalSparseMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ordinal, V> IntoIterator for OrdinalSparseMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(match self.map {
+            SparseImpl::Sparse(vec) => IntoSlots::sorted(vec.into_iter()),
+            SparseImpl::Dense(map) => map.into_slots(),
+        })
+    }
+}
+
+impl<'a, K: Ordinal, V> IntoIterator for &'a OrdinalSparseMap<K, V> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::map::Entry;
+    use crate::map::OrdinalSparseMap;
+
+    #[quickcheck]
+    fn qc(values: Vec<(u8, u32)>, check: Vec<u8>, remove: Vec<u8>) {
+        let mut map: OrdinalSparseMap<u8, u32> = OrdinalSparseMap::new();
+        let mut control: BTreeMap<u8, u32> = BTreeMap::new();
+
+        for (key, value) in &values {
+            assert_eq!(control.insert(*key, *value), map.insert(*key, *value));
+            assert_eq!(control.len() > 32, map.is_dense());
+        }
+        for key in &remove {
+            assert_eq!(control.remove(key), map.remove(key));
+        }
+        for key in &check {
+            assert_eq!(control.get(key), map.get(key));
+            assert_eq!(control.contains_key(key), map.contains_key(key));
+        }
+
+        assert_eq!(control.len(), map.len());
+        assert!(map.iter().eq(control.iter().map(|(k, v)| (*k, v))));
+        assert!(map
+            .iter()
+            .rev()
+            .eq(control.iter().rev().map(|(k, v)| (*k, v))));
+
+        map.shrink_to_fit();
+        assert_eq!(control.len() > 32, map.is_dense());
+        assert!(map.clone().into_iter().eq(control.clone()));
+        assert!(map.drain().eq(control));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut map: OrdinalSparseMap<u8, u32> = OrdinalSparseMap::new();
+        *map.entry(3).or_insert(0) += 1;
+        *map.entry(3).or_insert(0) += 1;
+        assert_eq!(Some(&2), map.get(&3));
+
+        // Vacant entry dropped without insertion.
+        assert!(matches!(map.entry(5), Entry::Vacant(_)));
+        assert!(!map.contains_key(&5));
+        assert_eq!(vec![(3, &2)], map.iter().collect::<Vec<_>>());
+        assert_eq!(1, map.len());
+    }
+
+    #[test]
+    fn test_vacant_entries_not_counted() {
+        let mut map: OrdinalSparseMap<u8, u32> = OrdinalSparseMap::new();
+        for k in 0..32 {
+            assert!(matches!(map.entry(k), Entry::Vacant(_)));
+        }
+        for k in 100..132 {
+            map.insert(k, 1);
+        }
+        assert!(!map.is_dense());
+        assert_eq!(32, map.len());
+        map.insert(200, 2);
+        assert!(map.is_dense());
+        assert_eq!(33, map.len());
+    }
+
+    #[test]
+    fn test_dense_and_back() {
+        let mut map: OrdinalSparseMap<u8, u8> = (0..=255).map(|i| (i, i)).collect();
+        assert!(map.is_dense());
+        for k in 0..=255 {
+            if k % 16 != 0 {
+                map.remove(&k);
+            }
+        }
+        map.shrink_to_fit();
+        assert!(!map.is_dense());
+        assert_eq!(16, map.len());
+        assert_eq!(Some(&32), map.get(&32));
+    }
+}
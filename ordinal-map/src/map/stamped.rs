@@ -0,0 +1,388 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::iter;
+use std::marker::PhantomData;
+use std::mem;
+use std::slice;
+
+use crate::map::enumerate::OrdinalEnumerate;
+use crate::Ordinal;
+
+/// Slot of a stamped map: the generation in which the value was inserted and the value.
+type Slot<V> = (u32, Option<V>);
+
+/// Map with [`Ordinal`] keys which can be cleared in constant time.
+///
+/// Each slot stores the generation in which it was written,
+/// and the map considers only slots of the current generation as present.
+/// [`clear`](OrdinalStampedMap::clear) increments the generation,
+/// so a map can be reused cheaply, for example for per-query state of a graph search.
+/// Once every `u32::MAX` clears the generation wraps around,
+/// and that clear resets all the slots.
+///
+/// Values are not dropped by `clear`,
+/// they are dropped when their slot is overwritten or the map is dropped.
+///
+/// The slots are allocated on the first insertion,
+/// iteration visits every slot.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::map::OrdinalStampedMap;
+///
+/// let mut distance: OrdinalStampedMap<u8, u32> = OrdinalStampedMap::new();
+/// for query in 0..3 {
+///     distance.clear();
+///     distance.insert(query, 0);
+///     *distance.get_or_insert_with(query + 1, || 1) += 1;
+///     assert_eq!(2, distance.len());
+///     assert_eq!(Some(&2), distance.get(&(query + 1)));
+///     assert_eq!(None, distance.get(&(query + 2)));
+/// }
+/// ```
+pub struct OrdinalStampedMap<K, V> {
+    slots: Box<[Slot<V>]>,
+    /// Current generation, never zero. Slots with stamp zero are never present.
+    generation: u32,
+    len: usize,
+    _phantom: PhantomData<K>,
+}
+
+impl<K: Ordinal, V> OrdinalStampedMap<K, V> {
+    /// Create a new empty map. Does not allocate.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalStampedMap {
+            slots: Box::new([]),
+            generation: 1,
+            len: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V> {
+        match self.slots.get(key.ordinal())? {
+            (stamp, value) if *stamp == self.generation => value.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V> {
+        match self.slots.get_mut(key.ordinal())? {
+            (stamp, value) if *stamp == self.generation => value.as_mut(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of elements in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn slot(&mut self, key: &K) -> &mut Slot<V> {
+        if self.slots.is_empty() {
+            self.slots = iter::repeat_with(|| (0, None))
+                .take(K::ORDINAL_SIZE)
+                .collect();
+        }
+        &mut self.slots[key.ordinal()]
+    }
+
+    /// Insert a value into the map, returning the previous value if it existed.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let generation = self.generation;
+        let slot = self.slot(&key);
+        if slot.0 == generation {
+            return slot.1.replace(value);
+        }
+        *slot = (generation, Some(value));
+        self.len += 1;
+        None
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key,
+    /// inserting the result of `f` if the key is not present.
+    #[inline]
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> &mut V {
+        let generation = self.generation;
+        let slot = self.slot(&key);
+        if slot.0 != generation {
+            *slot = (generation, Some(f()));
+            self.len += 1;
+        }
+        // Borrow again to keep the borrow checker happy about `self.len`.
+        let slot = &mut self.slots[key.ordinal()];
+        slot.1.as_mut().unwrap()
+    }
+
+    /// Remove a key from the map, returning the value if it existed.
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        match self.slots.get_mut(key.ordinal())? {
+            slot if slot.0 == self.generation => {
+                self.len -= 1;
+                mem::take(slot).1
+            }
+            _ => None,
+        }
+    }
+
+    /// Remove all elements from the map.
+    ///
+    /// This is a constant time operation except once every `u32::MAX` calls
+    /// when the generation counter wraps around.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+        match self.generation.checked_add(1) {
+            Some(generation) => self.generation = generation,
+            None => {
+                for slot in &mut *self.slots {
+                    *slot = (0, None);
+                }
+                self.generation = 1;
+            }
+        }
+    }
+
+    /// Iterate over the entries of the map in ordinal order.
+    #[inline]
+    pub fn iter(&self) -> IterStamped<'_, K, V> {
+        IterStamped {
+            iter: OrdinalEnumerate::new(self.slots.iter(), 0),
+            generation: self.generation,
+        }
+    }
+
+    /// Iterate over the entries of the map in ordinal order with mutable references to values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMutStamped<'_, K, V> {
+        IterMutStamped {
+            iter: OrdinalEnumerate::new(self.slots.iter_mut(), 0),
+            generation: self.generation,
+        }
+    }
+}
+
+impl<K: Ordinal, V> Default for OrdinalStampedMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ordinal, V> FromIterator<(K, V)> for OrdinalStampedMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OrdinalStampedMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ordinal, V> Extend<(K, V)> for OrdinalStampedMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V: Clone> Clone for OrdinalStampedMap<K, V> {
+    fn clone(&self) -> Self {
+        OrdinalStampedMap {
+            slots: self.slots.clone(),
+            generation: self.generation,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: Ordinal + Debug, V: Debug> Debug for OrdinalStampedMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: Ordinal, V> IntoIterator for &'a OrdinalStampedMap<K, V> {
+    type Item = (K, &'a V);
+    type IntoIter = IterStamped<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Ordinal, V> IntoIterator for &'a mut OrdinalStampedMap<K, V> {
+    type Item = (K, &'a mut V);
+    type IntoIter = IterMutStamped<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the entries of [`OrdinalStampedMap`].
+pub struct IterStamped<'a, K, V> {
+    iter: OrdinalEnumerate<K, slice::Iter<'a, Slot<V>>>,
+    generation: u32,
+}
+
+impl<'a, K: Ordinal, V> Iterator for IterStamped<'a, K, V> {
+    type Item = (K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let generation = self.generation;
+        self.iter.find_map(|(k, (stamp, v))| match v {
+            Some(v) if *stamp == generation => Some((k, v)),
+            _ => None,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, K: Ordinal, V> DoubleEndedIterator for IterStamped<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let generation = self.generation;
+        self.iter
+            .by_ref()
+            .rev()
+            .find_map(|(k, (stamp, v))| match v {
+                Some(v) if *stamp == generation => Some((k, v)),
+                _ => None,
+            })
+    }
+}
+
+impl<'a, K, V> Clone for IterStamped<'a, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        IterStamped {
+            iter: self.iter.clone(),
+            generation: self.generation,
+        }
+    }
+}
+
+/// Iterator over the entries of [`OrdinalStampedMap`] with mutable references to values.
+pub struct IterMutStamped<'a, K, V> {
+    iter: OrdinalEnumerate<K, slice::IterMut<'a, Slot<V>>>,
+    generation: u32,
+}
+
+impl<'a, K: Ordinal, V> Iterator for IterMutStamped<'a, K, V> {
+    type Item = (K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let generation = self.generation;
+        self.iter.find_map(|(k, (stamp, v))| match v {
+            Some(v) if *stamp == generation => Some((k, v)),
+            _ => None,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, K: Ordinal, V> DoubleEndedIterator for IterMutStamped<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let generation = self.generation;
+        self.iter
+            .by_ref()
+            .rev()
+            .find_map(|(k, (stamp, v))| match v {
+                Some(v) if *stamp == generation => Some((k, v)),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::map::OrdinalStampedMap;
+
+    #[quickcheck]
+    fn qc(ops: Vec<(u8, Option<u32>)>, clear_every: u8) {
+        let mut map: OrdinalStampedMap<u8, u32> = OrdinalStampedMap::new();
+        let mut control: BTreeMap<u8, u32> = BTreeMap::new();
+        for (i, (key, value)) in ops.into_iter().enumerate() {
+            if clear_every != 0 && i % clear_every as usize == 0 {
+                map.clear();
+                control.clear();
+            }
+            match value {
+                Some(value) => assert_eq!(control.insert(key, value), map.insert(key, value)),
+                None => assert_eq!(control.remove(&key), map.remove(&key)),
+            }
+            assert_eq!(control.len(), map.len());
+            assert_eq!(control.get(&key), map.get(&key));
+        }
+        assert!(map.iter().eq(control.iter().map(|(k, v)| (*k, v))));
+        assert!(map
+            .iter()
+            .rev()
+            .eq(control.iter().rev().map(|(k, v)| (*k, v))));
+        for (_, v) in &mut map {
+            *v = v.wrapping_add(1);
+        }
+        for (k, v) in &control {
+            assert_eq!(Some(&v.wrapping_add(1)), map.get(k));
+        }
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut map: OrdinalStampedMap<u8, String> = OrdinalStampedMap::new();
+        map.insert(1, "one".to_owned());
+        map.generation = u32::MAX - 1;
+        map.insert(2, "two".to_owned());
+        map.clear();
+        assert_eq!(u32::MAX, map.generation);
+        assert_eq!(None, map.get(&2));
+        map.insert(3, "three".to_owned());
+        map.clear();
+        assert_eq!(1, map.generation);
+        // Stale slots must not come back to life with the reused generation.
+        assert_eq!(None, map.get(&1));
+        assert!(map.is_empty());
+        assert_eq!(0, map.iter().count());
+        map.insert(4, "four".to_owned());
+        assert_eq!("{4: \"four\"}", format!("{:?}", map));
+    }
+}
@@ -6,7 +6,7 @@ use std::slice;
 use std::vec;
 
 use crate::map::enumerate::OrdinalEnumerate;
-use crate::Ordinal;
+use crate::DynOrdinal;
 
 /// Iterator created from
 /// [`OrdinalTotalMap`](crate::map::total::OrdinalTotalMap) and
@@ -23,7 +23,7 @@ impl<'a, K, V> Iter<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal, V> Iterator for Iter<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Iterator for Iter<'a, K, V> {
     type Item = (K, &'a V);
 
     #[inline]
@@ -36,13 +36,13 @@ impl<'a, K: Ordinal, V> Iterator for Iter<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal, V> ExactSizeIterator for Iter<'a, K, V> {
+impl<'a, K: DynOrdinal, V> ExactSizeIterator for Iter<'a, K, V> {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<'a, K: Ordinal, V> DoubleEndedIterator for Iter<'a, K, V> {
+impl<'a, K: DynOrdinal, V> DoubleEndedIterator for Iter<'a, K, V> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
@@ -57,7 +57,7 @@ impl<'a, K, V> Clone for Iter<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal + Debug, V: Debug> Debug for Iter<'a, K, V> {
+impl<'a, K: DynOrdinal + Debug, V: Debug> Debug for Iter<'a, K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
@@ -70,7 +70,7 @@ pub struct IterMut<'a, K, V> {
     iter: OrdinalEnumerate<K, slice::IterMut<'a, V>>,
 }
 
-impl<'a, K: Ordinal, V> IterMut<'a, K, V> {
+impl<'a, K: DynOrdinal, V> IterMut<'a, K, V> {
     #[inline]
     pub(crate) fn new(iter: slice::IterMut<'a, V>) -> Self {
         IterMut {
@@ -83,7 +83,7 @@ impl<'a, K: Ordinal, V> IterMut<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal, V> Iterator for IterMut<'a, K, V> {
+impl<'a, K: DynOrdinal, V> Iterator for IterMut<'a, K, V> {
     type Item = (K, &'a mut V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -95,19 +95,19 @@ impl<'a, K: Ordinal, V> Iterator for IterMut<'a, K, V> {
     }
 }
 
-impl<'a, K: Ordinal, V> ExactSizeIterator for IterMut<'a, K, V> {
+impl<'a, K: DynOrdinal, V> ExactSizeIterator for IterMut<'a, K, V> {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<'a, K: Ordinal, V> DoubleEndedIterator for IterMut<'a, K, V> {
+impl<'a, K: DynOrdinal, V> DoubleEndedIterator for IterMut<'a, K, V> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
     }
 }
 
-impl<'a, K: Ordinal + Debug, V: Debug> Debug for IterMut<'a, K, V> {
+impl<'a, K: DynOrdinal + Debug, V: Debug> Debug for IterMut<'a, K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
@@ -131,7 +131,7 @@ impl<K, V, const S: usize> IntoIterArray<K, V, S> {
     }
 }
 
-impl<K: Ordinal, V, const S: usize> Iterator for IntoIterArray<K, V, S> {
+impl<K: DynOrdinal, V, const S: usize> Iterator for IntoIterArray<K, V, S> {
     type Item = (K, V);
 
     #[inline]
@@ -140,13 +140,13 @@ impl<K: Ordinal, V, const S: usize> Iterator for IntoIterArray<K, V, S> {
     }
 }
 
-impl<K: Ordinal, V, const S: usize> ExactSizeIterator for IntoIterArray<K, V, S> {
+impl<K: DynOrdinal, V, const S: usize> ExactSizeIterator for IntoIterArray<K, V, S> {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<K: Ordinal, V, const S: usize> DoubleEndedIterator for IntoIterArray<K, V, S> {
+impl<K: DynOrdinal, V, const S: usize> DoubleEndedIterator for IntoIterArray<K, V, S> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
@@ -161,7 +161,7 @@ impl<K, V: Clone, const S: usize> Clone for IntoIterArray<K, V, S> {
     }
 }
 
-impl<K: Ordinal + Debug, V: Debug, const S: usize> Debug for IntoIterArray<K, V, S> {
+impl<K: DynOrdinal + Debug, V: Debug, const S: usize> Debug for IntoIterArray<K, V, S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
@@ -185,7 +185,7 @@ impl<K, V> IntoIter<K, V> {
     }
 }
 
-impl<K: Ordinal, V> Iterator for IntoIter<K, V> {
+impl<K: DynOrdinal, V> Iterator for IntoIter<K, V> {
     type Item = (K, V);
 
     #[inline]
@@ -199,21 +199,21 @@ impl<K: Ordinal, V> Iterator for IntoIter<K, V> {
     }
 }
 
-impl<K: Ordinal, V> ExactSizeIterator for IntoIter<K, V> {
+impl<K: DynOrdinal, V> ExactSizeIterator for IntoIter<K, V> {
     #[inline]
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<K: Ordinal, V> DoubleEndedIterator for IntoIter<K, V> {
+impl<K: DynOrdinal, V> DoubleEndedIterator for IntoIter<K, V> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
     }
 }
 
-impl<K: Ordinal + Debug, V: Debug> Debug for IntoIter<K, V> {
+impl<K: DynOrdinal + Debug, V: Debug> Debug for IntoIter<K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
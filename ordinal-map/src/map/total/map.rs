@@ -7,6 +7,7 @@ use std::ops::Index;
 use std::ops::IndexMut;
 use std::slice;
 
+use crate::map::patch::OrdinalMapPatch;
 use crate::map::total::iter::Iter;
 use crate::map::total::IntoIter;
 use crate::map::total::IterMut;
@@ -107,6 +108,37 @@ impl<K: Ordinal, V> OrdinalTotalMap<K, V> {
     pub fn insert(&mut self, key: K, value: V) -> V {
         mem::replace(&mut self.map[key.ordinal()], value)
     }
+
+    /// Iterate over the entries whose values differ between this map and `other`
+    /// in ordinal order, yielding the key, the value in this map and the value in `other`.
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a OrdinalTotalMap<K, V>,
+    ) -> impl Iterator<Item = (K, &'a V, &'a V)>
+    where
+        V: PartialEq,
+    {
+        self.iter()
+            .zip(other.values())
+            .filter(|((_, a), b)| a != b)
+            .map(|((k, a), b)| (k, a, b))
+    }
+
+    /// Create a patch which turns this map into `other` when applied
+    /// with [`apply_patch`](OrdinalTotalMap::apply_patch).
+    pub fn patch(&self, other: &OrdinalTotalMap<K, V>) -> OrdinalMapPatch<K, V>
+    where
+        V: PartialEq + Clone,
+    {
+        self.diff(other).map(|(k, _, v)| (k, v.clone())).collect()
+    }
+
+    /// Overwrite the values of the entries of the patch.
+    pub fn apply_patch(&mut self, patch: OrdinalMapPatch<K, V>) {
+        for (key, value) in patch {
+            self.insert(key, value);
+        }
+    }
 }
 
 impl<K: Ordinal, V: Default> Default for OrdinalTotalMap<K, V> {
@@ -164,6 +196,27 @@ impl<'a, K: Ordinal, V> IntoIterator for &'a OrdinalTotalMap<K, V> {
 
 #[cfg(test)]
 mod tests {
+    use crate::map::total::OrdinalTotalMap;
+
     #[test]
     fn test() {}
+
+    #[quickcheck]
+    fn qc_diff_patch(old: Vec<u8>, new: Vec<u8>) {
+        let value = |values: &[u8], k: u8| values.get(k as usize).copied().unwrap_or(0);
+        let old_map = OrdinalTotalMap::new(|k: u8| value(&old, k));
+        let new_map = OrdinalTotalMap::new(|k: u8| value(&new, k));
+
+        let expected = (0..=u8::MAX)
+            .map(|k| (k, value(&old, k), value(&new, k)))
+            .filter(|(_, a, b)| a != b);
+        assert!(old_map
+            .diff(&new_map)
+            .map(|(k, a, b)| (k, *a, *b))
+            .eq(expected));
+
+        let mut patched = old_map.clone();
+        patched.apply_patch(old_map.patch(&new_map));
+        assert!(patched.iter().eq(new_map.iter()));
+    }
 }
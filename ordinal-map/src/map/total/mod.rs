@@ -3,6 +3,7 @@
 pub(crate) mod array_map;
 pub(crate) mod iter;
 pub(crate) mod map;
+pub(crate) mod persistent;
 
 pub use crate::map::total::array_map::OrdinalTotalArrayMap;
 pub use crate::map::total::iter::IntoIter;
@@ -10,3 +11,5 @@ pub use crate::map::total::iter::IntoIterArray;
 pub use crate::map::total::iter::Iter;
 pub use crate::map::total::iter::IterMut;
 pub use crate::map::total::map::OrdinalTotalMap;
+pub use crate::map::total::persistent::IterPersistent;
+pub use crate::map::total::persistent::OrdinalPersistentMap;
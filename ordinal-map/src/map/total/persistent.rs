@@ -0,0 +1,314 @@
+use std::convert::Infallible;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::iter;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Index;
+use std::slice;
+use std::sync::Arc;
+
+use crate::map::total::iter::Iter;
+use crate::Ordinal;
+
+/// Number of values in a chunk, the unit of copy-on-write.
+const CHUNK_LEN: usize = 64;
+
+/// Total map with constant time [`clone`](Clone::clone) for keeping snapshots.
+///
+/// Values are stored in fixed size chunks shared with [`Arc`] between clones.
+/// Cloning the map only increments a reference count,
+/// and the first mutation of a chunk after cloning copies only that chunk
+/// (and the table of chunk pointers if it is shared too).
+///
+/// [`diff`](OrdinalPersistentMap::diff) skips chunks shared between two maps,
+/// so comparing snapshots costs time proportional to the number of modified chunks
+/// plus the number of chunks.
+///
+/// For a total map with contiguous storage,
+/// see [`OrdinalTotalMap`](crate::map::total::OrdinalTotalMap).
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::map::total::OrdinalPersistentMap;
+///
+/// let mut cells: OrdinalPersistentMap<u16, u32> = OrdinalPersistentMap::new(|_| 0);
+/// let snapshot = cells.clone();
+/// cells.insert(1000, 7);
+/// *cells.get_mut(&2000) += 1;
+/// assert_eq!(0, snapshot[&1000]);
+/// assert_eq!(7, cells[&1000]);
+/// assert_eq!(vec![1000, 2000], snapshot.diff(&cells).collect::<Vec<_>>());
+/// ```
+pub struct OrdinalPersistentMap<K, V> {
+    /// Chunks of `CHUNK_LEN` values, the last chunk may be shorter.
+    chunks: Arc<[Arc<[V]>]>,
+    _phantom: PhantomData<K>,
+}
+
+impl<K: Ordinal, V> OrdinalPersistentMap<K, V> {
+    /// Create a new map by initializing each value with a function.
+    pub fn try_new<E>(mut init: impl FnMut(K) -> Result<V, E>) -> Result<Self, E> {
+        let mut chunks = Vec::with_capacity(K::ORDINAL_SIZE.div_ceil(CHUNK_LEN));
+        let mut chunk = Vec::with_capacity(CHUNK_LEN);
+        for k in K::all_values() {
+            chunk.push(init(k)?);
+            if chunk.len() == CHUNK_LEN {
+                chunks.push(Arc::from(mem::take(&mut chunk)));
+            }
+        }
+        if !chunk.is_empty() {
+            chunks.push(Arc::from(chunk));
+        }
+        Ok(OrdinalPersistentMap {
+            chunks: Arc::from(chunks),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Create a new map by initializing each value with a function.
+    pub fn new(mut init: impl FnMut(K) -> V) -> Self {
+        match Self::try_new(move |k| Ok::<_, Infallible>(init(k))) {
+            Ok(map) => map,
+            Err(infallible) => match infallible {},
+        }
+    }
+
+    /// Returns the number of elements in the map, which is
+    /// always equal to [`K::ORDINAL_SIZE`](Ordinal::ORDINAL_SIZE).
+    pub const fn len(&self) -> usize {
+        K::ORDINAL_SIZE
+    }
+
+    /// Return true if the map contains no elements.
+    /// This is only if `<K>` is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<'a>(&'a self, key: &K) -> &'a V {
+        let ordinal = key.ordinal();
+        &self.chunks[ordinal / CHUNK_LEN][ordinal % CHUNK_LEN]
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    ///
+    /// If the chunk containing the value is shared with a clone of this map,
+    /// the chunk is copied first.
+    #[inline]
+    pub fn get_mut<'a>(&'a mut self, key: &K) -> &'a mut V
+    where
+        V: Clone,
+    {
+        let ordinal = key.ordinal();
+        let chunk = &mut Arc::make_mut(&mut self.chunks)[ordinal / CHUNK_LEN];
+        &mut Arc::make_mut(chunk)[ordinal % CHUNK_LEN]
+    }
+
+    /// Insert a value into the map, returning the previous value.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> V
+    where
+        V: Clone,
+    {
+        mem::replace(self.get_mut(&key), value)
+    }
+
+    /// Iterate keys of the map, which is equivalent to iterating all possible values of `K`.
+    pub fn keys(&self) -> crate::OrdinalValues<K> {
+        K::all_values()
+    }
+
+    /// Iterate over the map.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> IterPersistent<'a, K, V> {
+        IterPersistent {
+            front: Iter::new([].iter(), 0),
+            chunks: self.chunks.iter().enumerate(),
+            back: Iter::new([].iter(), 0),
+        }
+    }
+
+    /// Iterate over the keys whose values differ between this map and `other`
+    /// in ordinal order.
+    ///
+    /// Chunks shared between the maps are skipped without comparing values.
+    pub fn diff<'a>(&'a self, other: &'a OrdinalPersistentMap<K, V>) -> impl Iterator<Item = K> + 'a
+    where
+        V: PartialEq,
+    {
+        iter::zip(self.chunks.iter(), other.chunks.iter())
+            .enumerate()
+            .filter(|(_, (a, b))| !Arc::ptr_eq(a, b))
+            .flat_map(|(index, (a, b))| {
+                iter::zip(a.iter(), b.iter())
+                    .enumerate()
+                    .filter(|(_, (a, b))| a != b)
+                    // SAFETY: chunks hold `K::ORDINAL_SIZE` values, so the index is less than that.
+                    .map(move |(i, _)| unsafe { K::from_ordinal_unchecked(index * CHUNK_LEN + i) })
+            })
+    }
+}
+
+impl<K: Ordinal, V: Default> Default for OrdinalPersistentMap<K, V> {
+    fn default() -> Self {
+        OrdinalPersistentMap::new(|_| V::default())
+    }
+}
+
+impl<'a, K: Ordinal, V> Index<&'a K> for OrdinalPersistentMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: &'a K) -> &Self::Output {
+        self.get(key)
+    }
+}
+
+impl<K, V> Clone for OrdinalPersistentMap<K, V> {
+    /// Constant time clone sharing all the chunks.
+    fn clone(&self) -> Self {
+        OrdinalPersistentMap {
+            chunks: Arc::clone(&self.chunks),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: Ordinal + Debug, V: Debug> Debug for OrdinalPersistentMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: Ordinal, V> IntoIterator for &'a OrdinalPersistentMap<K, V> {
+    type Item = (K, &'a V);
+    type IntoIter = IterPersistent<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator created from [`OrdinalPersistentMap`].
+pub struct IterPersistent<'a, K, V> {
+    front: Iter<'a, K, V>,
+    chunks: iter::Enumerate<slice::Iter<'a, Arc<[V]>>>,
+    back: Iter<'a, K, V>,
+}
+
+impl<'a, K: Ordinal, V> Iterator for IterPersistent<'a, K, V> {
+    type Item = (K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.front.next() {
+                return Some(entry);
+            }
+            match self.chunks.next() {
+                Some((index, chunk)) => self.front = Iter::new(chunk.iter(), index * CHUNK_LEN),
+                None => return self.back.next(),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len() + self.chunks.len() * CHUNK_LEN;
+        // The last chunk may be shorter.
+        (len.saturating_sub(CHUNK_LEN), Some(len))
+    }
+}
+
+impl<'a, K: Ordinal, V> DoubleEndedIterator for IterPersistent<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.back.next_back() {
+                return Some(entry);
+            }
+            match self.chunks.next_back() {
+                Some((index, chunk)) => self.back = Iter::new(chunk.iter(), index * CHUNK_LEN),
+                None => return self.front.next_back(),
+            }
+        }
+    }
+}
+
+impl<'a, K, V> Clone for IterPersistent<'a, K, V> {
+    fn clone(&self) -> Self {
+        IterPersistent {
+            front: self.front.clone(),
+            chunks: self.chunks.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::map::total::OrdinalPersistentMap;
+
+    #[quickcheck]
+    fn qc(ops: Vec<(u8, u32)>, snapshot_every: u8) {
+        let mut map: OrdinalPersistentMap<u8, u32> = OrdinalPersistentMap::new(|k| k as u32);
+        let mut control: Vec<u32> = (0..=255).collect();
+        let mut snapshots = Vec::new();
+        for (i, (key, value)) in ops.into_iter().enumerate() {
+            if snapshot_every != 0 && i % snapshot_every as usize == 0 {
+                snapshots.push((map.clone(), control.clone()));
+            }
+            assert_eq!(control[key as usize], map.insert(key, value));
+            control[key as usize] = value;
+        }
+        assert!(map.iter().eq((0..=255).zip(&control)));
+        assert!(map.iter().rev().eq((0..=255).zip(&control).rev()));
+        for (snapshot, snapshot_control) in &snapshots {
+            assert!(snapshot.iter().eq((0..=255).zip(snapshot_control)));
+            let expected =
+                (0..=255).filter(|k| snapshot_control[*k as usize] != control[*k as usize]);
+            assert!(snapshot.diff(&map).eq(expected));
+        }
+    }
+
+    #[test]
+    fn test_copy_on_write() {
+        let mut map: OrdinalPersistentMap<u16, u32> = OrdinalPersistentMap::new(|_| 0);
+        let snapshot = map.clone();
+        assert!(Arc::ptr_eq(&map.chunks, &snapshot.chunks));
+        map.insert(100, 1);
+        let shared = shared_chunks(&map, &snapshot);
+        assert_eq!(map.chunks.len() - 1, shared);
+        assert_eq!(vec![100], map.diff(&snapshot).collect::<Vec<_>>());
+        // Writing the same value still copies the chunk but is not a difference.
+        map.insert(5000, 0);
+        assert_eq!(map.chunks.len() - 2, shared_chunks(&map, &snapshot));
+        assert_eq!(vec![100], map.diff(&snapshot).collect::<Vec<_>>());
+        assert_eq!(u16::MAX as usize + 1, map.iter().count());
+    }
+
+    fn shared_chunks(
+        a: &OrdinalPersistentMap<u16, u32>,
+        b: &OrdinalPersistentMap<u16, u32>,
+    ) -> usize {
+        a.chunks
+            .iter()
+            .zip(b.chunks.iter())
+            .filter(|(a, b)| Arc::ptr_eq(a, b))
+            .count()
+    }
+
+    #[test]
+    fn test_short_last_chunk() {
+        let map: OrdinalPersistentMap<Option<u8>, u32> =
+            OrdinalPersistentMap::new(|k: Option<u8>| k.map_or(1000, u32::from));
+        assert_eq!(257, map.iter().count());
+        assert_eq!(Some((Some(255), &255)), map.iter().next_back());
+        assert_eq!(1000, map[&None]);
+        assert_eq!(0, map.diff(&map.clone()).count());
+    }
+}
@@ -0,0 +1,17 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// Error returned from `FromStr` generated by `#[derive(Ordinal)]` with `#[ordinal(names)]`
+/// when the string is not a name of any variant.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ParseNameError;
+
+impl Display for ParseNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown variant name")
+    }
+}
+
+impl Error for ParseNameError {}
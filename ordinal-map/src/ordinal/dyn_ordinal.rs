@@ -0,0 +1,85 @@
+use crate::Ordinal;
+
+/// Keys of a [`Domain`](crate::Domain) whose size is known only at runtime,
+/// for example, shard indices when the number of shards is read from config.
+///
+/// It is implemented for every type implementing [`Ordinal`] with the same ordinals,
+/// and for `usize`. Typed indices can implement it by wrapping `usize`:
+///
+/// ```
+/// use ordinal_map::DynOrdinal;
+///
+/// #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// struct ShardId(usize);
+///
+/// impl DynOrdinal for ShardId {
+///     fn dyn_ordinal(&self) -> usize {
+///         self.0
+///     }
+///
+///     fn from_dyn_ordinal(ordinal: usize) -> Option<Self> {
+///         Some(ShardId(ordinal))
+///     }
+/// }
+/// ```
+///
+/// Ordinals of values must be contiguous: if `from_dyn_ordinal(n)` returns `Some`,
+/// `from_dyn_ordinal(m)` must return `Some` for every `m < n`.
+pub trait DynOrdinal: Sized {
+    /// Return the ordinal of this value.
+    fn dyn_ordinal(&self) -> usize;
+
+    /// Inverse of [`dyn_ordinal`](DynOrdinal::dyn_ordinal).
+    /// Return `None` if the type has no value with this ordinal.
+    fn from_dyn_ordinal(ordinal: usize) -> Option<Self>;
+
+    /// Inverse of [`dyn_ordinal`](DynOrdinal::dyn_ordinal) without bounds check.
+    ///
+    /// Default implementation calls [`from_dyn_ordinal`](DynOrdinal::from_dyn_ordinal)
+    /// and panics if it returns `None`. Implementations may override it to skip the check
+    /// only if `from_dyn_ordinal` returns `Some` for every ordinal allowed below.
+    ///
+    /// # Safety
+    ///
+    /// `ordinal` must be less than the size of a [`Domain`](crate::Domain) of this type
+    /// or be returned by [`dyn_ordinal`](DynOrdinal::dyn_ordinal) for some value.
+    #[inline]
+    unsafe fn from_dyn_ordinal_unchecked(ordinal: usize) -> Self {
+        Self::from_dyn_ordinal(ordinal).expect("no value for ordinal within the domain")
+    }
+}
+
+impl<T: Ordinal> DynOrdinal for T {
+    #[inline]
+    fn dyn_ordinal(&self) -> usize {
+        self.ordinal()
+    }
+
+    #[inline]
+    fn from_dyn_ordinal(ordinal: usize) -> Option<Self> {
+        T::from_ordinal(ordinal)
+    }
+
+    #[inline]
+    unsafe fn from_dyn_ordinal_unchecked(ordinal: usize) -> Self {
+        // SAFETY: domains of `Ordinal` types are not larger than `ORDINAL_SIZE`.
+        unsafe { T::from_ordinal_unchecked(ordinal) }
+    }
+}
+
+impl DynOrdinal for usize {
+    #[inline]
+    fn dyn_ordinal(&self) -> usize {
+        *self
+    }
+
+    #[inline]
+    fn from_dyn_ordinal(ordinal: usize) -> Option<Self> {
+        Some(ordinal)
+    }
+
+    #[inline]
+    unsafe fn from_dyn_ordinal_unchecked(ordinal: usize) -> Self {
+        ordinal
+    }
+}
@@ -1,5 +1,8 @@
 use crate::array_builder::ArrayBuilder;
+use crate::ordinal::wide::product_max;
+use crate::ordinal::wide::wrapping_size;
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 impl<T: Ordinal, const N: usize> Ordinal for [T; N] {
     const ORDINAL_SIZE: usize = {
@@ -39,6 +42,56 @@ impl<T: Ordinal, const N: usize> Ordinal for [T; N] {
             Some(array)
         }
     }
+
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        let mut n = ordinal;
+        let mut array = ArrayBuilder::new();
+        for _ in 0..N {
+            array.push(unsafe { T::from_ordinal_unchecked(n % T::ORDINAL_SIZE) });
+            n /= T::ORDINAL_SIZE;
+        }
+        let mut array = array.finish();
+        array.reverse();
+        array
+    }
+}
+
+impl<T: WideOrdinal, const N: usize> WideOrdinal for [T; N] {
+    const WIDE_ORDINAL_MAX: Option<u128> = {
+        let mut max = Some(0);
+        let mut i = 0;
+        while i < N {
+            max = product_max(max, T::WIDE_ORDINAL_MAX);
+            i += 1;
+        }
+        max
+    };
+
+    fn wide_ordinal(&self) -> u128 {
+        const { Self::WIDE_ORDINAL_MAX };
+        let mut r = 0u128;
+        for v in self {
+            // Size wraps to zero only if `T` has `2^128` values, then `N <= 1` and `r == 0`.
+            r = r * wrapping_size(T::WIDE_ORDINAL_MAX) + v.wide_ordinal();
+        }
+        r
+    }
+
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        if ordinal > Self::WIDE_ORDINAL_MAX? {
+            return None;
+        }
+        let size = wrapping_size(T::WIDE_ORDINAL_MAX);
+        let mut n = ordinal;
+        let mut array = ArrayBuilder::new();
+        for _ in 0..N {
+            array.push(T::from_wide_ordinal(n.checked_rem(size).unwrap_or(n))?);
+            n = n.checked_div(size).unwrap_or(0);
+        }
+        let mut array = array.finish();
+        array.reverse();
+        Some(array)
+    }
 }
 
 #[cfg(test)]
@@ -46,6 +99,8 @@ mod tests {
     use std::convert::Infallible;
 
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_wide_ordinal;
+    use crate::WideOrdinal;
 
     #[test]
     fn test_array_of_empty() {
@@ -70,4 +125,26 @@ mod tests {
         // Should be compatible with ordinal for tuple.
         test_ordinal([(false, false), (false, true), (true, false), (true, true)]);
     }
+
+    #[test]
+    fn test_wide_array() {
+        assert_eq!(Some(u64::MAX as u128), <[u8; 8]>::WIDE_ORDINAL_MAX);
+        assert_eq!(Some(u128::MAX), <[u64; 2]>::WIDE_ORDINAL_MAX);
+        assert_eq!(Some(u128::MAX), <[u128; 1]>::WIDE_ORDINAL_MAX);
+        assert_eq!(Some(0), <[u128; 0]>::WIDE_ORDINAL_MAX);
+        assert_eq!(None, <[Infallible; 1]>::WIDE_ORDINAL_MAX);
+        assert_eq!(None, <[Infallible; 1]>::from_wide_ordinal(0));
+        // Same as big-endian bytes.
+        for v in [0u64, 1, 0x0102030405060708, u64::MAX] {
+            assert_eq!(v as u128, v.to_be_bytes().wide_ordinal());
+            assert_eq!(
+                Some(v.to_be_bytes()),
+                <[u8; 8]>::from_wide_ordinal(v as u128)
+            );
+        }
+        test_wide_ordinal([[0u8; 8], [0, 0, 0, 0, 0, 0, 1, 0], [255; 8]]);
+        test_wide_ordinal([[0u64, 0], [0, u64::MAX], [1, 0], [u64::MAX, u64::MAX]]);
+        test_wide_ordinal([[0u128], [u128::MAX]]);
+        test_wide_ordinal([[false, false], [false, true], [true, false], [true, true]]);
+    }
 }
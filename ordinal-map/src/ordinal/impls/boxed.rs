@@ -1,4 +1,5 @@
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 impl<T: Ordinal> Ordinal for Box<T> {
     const ORDINAL_SIZE: usize = T::ORDINAL_SIZE;
@@ -10,14 +11,33 @@ impl<T: Ordinal> Ordinal for Box<T> {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         Some(Box::new(T::from_ordinal(ordinal)?))
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        Box::new(unsafe { T::from_ordinal_unchecked(ordinal) })
+    }
+}
+
+impl<T: WideOrdinal> WideOrdinal for Box<T> {
+    const WIDE_ORDINAL_MAX: Option<u128> = T::WIDE_ORDINAL_MAX;
+
+    fn wide_ordinal(&self) -> u128 {
+        (**self).wide_ordinal()
+    }
+
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        Some(Box::new(T::from_wide_ordinal(ordinal)?))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_wide_ordinal;
 
     #[test]
     fn test_box() {
-        test_ordinal([Box::new(false), Box::new(true)])
+        test_ordinal([Box::new(false), Box::new(true)]);
+        test_wide_ordinal([Box::new(0u128), Box::new(u128::MAX)]);
     }
 }
@@ -1,4 +1,5 @@
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 const SURROGATE_START: u32 = 0xd800;
 
@@ -21,14 +22,38 @@ impl Ordinal for char {
             char::from_u32((ordinal as u32).checked_add(0x800)?)
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        let c = if ordinal < SURROGATE_START as usize {
+            ordinal as u32
+        } else {
+            ordinal as u32 + 0x800
+        };
+        unsafe { char::from_u32_unchecked(c) }
+    }
+}
+
+impl WideOrdinal for char {
+    const WIDE_ORDINAL_MAX: Option<u128> = Some(char::ORDINAL_SIZE as u128 - 1);
+
+    fn wide_ordinal(&self) -> u128 {
+        self.ordinal() as u128
+    }
+
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        char::from_ordinal(usize::try_from(ordinal).ok()?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_wide_ordinal;
 
     #[test]
     fn test_char() {
-        test_ordinal((0..=(char::MAX as u32)).filter_map(char::from_u32))
+        test_ordinal((0..=(char::MAX as u32)).filter_map(char::from_u32));
+        test_wide_ordinal((0..=(char::MAX as u32)).filter_map(char::from_u32));
     }
 }
@@ -2,7 +2,9 @@
 
 use either::Either;
 
+use crate::ordinal::wide::wrapping_size;
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 impl<A: Ordinal, B: Ordinal> Ordinal for Either<A, B> {
     const ORDINAL_SIZE: usize = Result::<A, B>::ORDINAL_SIZE;
@@ -21,6 +23,34 @@ impl<A: Ordinal, B: Ordinal> Ordinal for Either<A, B> {
             B::from_ordinal(ordinal - A::ORDINAL_SIZE).map(Either::Right)
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        if ordinal < A::ORDINAL_SIZE {
+            Either::Left(unsafe { A::from_ordinal_unchecked(ordinal) })
+        } else {
+            Either::Right(unsafe { B::from_ordinal_unchecked(ordinal - A::ORDINAL_SIZE) })
+        }
+    }
+}
+
+impl<A: WideOrdinal, B: WideOrdinal> WideOrdinal for Either<A, B> {
+    const WIDE_ORDINAL_MAX: Option<u128> = Result::<A, B>::WIDE_ORDINAL_MAX;
+
+    fn wide_ordinal(&self) -> u128 {
+        const { Self::WIDE_ORDINAL_MAX };
+        match self {
+            Either::Left(a) => a.wide_ordinal(),
+            Either::Right(b) => wrapping_size(A::WIDE_ORDINAL_MAX) + b.wide_ordinal(),
+        }
+    }
+
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        Some(match Result::<A, B>::from_wide_ordinal(ordinal)? {
+            Ok(a) => Either::Left(a),
+            Err(b) => Either::Right(b),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -28,9 +58,16 @@ mod tests {
     use either::Either;
 
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_wide_ordinal;
 
     #[test]
     fn test_either() {
         test_ordinal([Either::Left(false), Either::Left(true), Either::Right(())]);
+        test_wide_ordinal([
+            Either::Left(0u64),
+            Either::Left(u64::MAX),
+            Either::Right(false),
+            Either::Right(true),
+        ]);
     }
 }
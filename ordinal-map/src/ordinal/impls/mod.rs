@@ -7,3 +7,4 @@ mod ordering;
 mod primitive;
 mod std;
 mod tuple;
+mod wide;
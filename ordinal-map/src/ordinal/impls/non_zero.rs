@@ -1,8 +1,10 @@
+use std::num::NonZeroI128;
 use std::num::NonZeroI16;
 use std::num::NonZeroI32;
 use std::num::NonZeroI64;
 use std::num::NonZeroI8;
 use std::num::NonZeroIsize;
+use std::num::NonZeroU128;
 use std::num::NonZeroU16;
 use std::num::NonZeroU32;
 use std::num::NonZeroU64;
@@ -10,6 +12,7 @@ use std::num::NonZeroU8;
 use std::num::NonZeroUsize;
 
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 impl Ordinal for NonZeroU8 {
     const ORDINAL_SIZE: usize = u8::MAX as usize;
@@ -23,6 +26,11 @@ impl Ordinal for NonZeroU8 {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         NonZeroU8::try_from(u8::try_from(ordinal.checked_add(1)?).ok()?).ok()
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        unsafe { NonZeroU8::new_unchecked(ordinal as u8 + 1) }
+    }
 }
 
 impl Ordinal for NonZeroI8 {
@@ -48,6 +56,12 @@ impl Ordinal for NonZeroI8 {
             Some(rem) => NonZeroI8::new(i8::try_from(rem + 1).ok()?),
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        let v = i8::MIN.wrapping_add_unsigned(ordinal as u8);
+        unsafe { NonZeroI8::new_unchecked(if v >= 0 { v + 1 } else { v }) }
+    }
 }
 
 impl Ordinal for NonZeroU16 {
@@ -62,6 +76,11 @@ impl Ordinal for NonZeroU16 {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         NonZeroU16::try_from(u16::try_from(ordinal.checked_add(1)?).ok()?).ok()
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        unsafe { NonZeroU16::new_unchecked(ordinal as u16 + 1) }
+    }
 }
 
 impl Ordinal for NonZeroI16 {
@@ -87,6 +106,12 @@ impl Ordinal for NonZeroI16 {
             Some(rem) => NonZeroI16::new(i16::try_from(rem + 1).ok()?),
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        let v = i16::MIN.wrapping_add_unsigned(ordinal as u16);
+        unsafe { NonZeroI16::new_unchecked(if v >= 0 { v + 1 } else { v }) }
+    }
 }
 
 impl Ordinal for NonZeroU32 {
@@ -101,6 +126,11 @@ impl Ordinal for NonZeroU32 {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         NonZeroU32::try_from(u32::try_from(ordinal.checked_add(1)?).ok()?).ok()
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        unsafe { NonZeroU32::new_unchecked(ordinal as u32 + 1) }
+    }
 }
 
 impl Ordinal for NonZeroI32 {
@@ -126,6 +156,12 @@ impl Ordinal for NonZeroI32 {
             Some(rem) => NonZeroI32::new(i32::try_from(rem + 1).ok()?),
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        let v = i32::MIN.wrapping_add_unsigned(ordinal as u32);
+        unsafe { NonZeroI32::new_unchecked(if v >= 0 { v + 1 } else { v }) }
+    }
 }
 
 impl Ordinal for NonZeroUsize {
@@ -140,6 +176,11 @@ impl Ordinal for NonZeroUsize {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         NonZeroUsize::new(ordinal.checked_add(1)?)
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        unsafe { NonZeroUsize::new_unchecked(ordinal + 1) }
+    }
 }
 
 impl Ordinal for NonZeroIsize {
@@ -161,6 +202,12 @@ impl Ordinal for NonZeroIsize {
             Some(rem) => NonZeroIsize::new(isize::try_from(rem + 1).ok()?),
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        let v = isize::MIN.wrapping_add_unsigned(ordinal);
+        unsafe { NonZeroIsize::new_unchecked(if v >= 0 { v + 1 } else { v }) }
+    }
 }
 
 /// It is compile-time error to use this impl on 32-bit platforms.
@@ -180,6 +227,12 @@ impl Ordinal for NonZeroU64 {
         const { Self::ORDINAL_SIZE };
         Some(NonZeroU64::new(NonZeroUsize::from_ordinal(ordinal)?.get() as u64).unwrap())
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        const { Self::ORDINAL_SIZE };
+        unsafe { NonZeroU64::new_unchecked(ordinal as u64 + 1) }
+    }
 }
 
 impl Ordinal for NonZeroI64 {
@@ -194,15 +247,90 @@ impl Ordinal for NonZeroI64 {
         const { Self::ORDINAL_SIZE };
         Some(NonZeroI64::new(NonZeroIsize::from_ordinal(ordinal)?.get() as i64).unwrap())
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        const { Self::ORDINAL_SIZE };
+        let v = unsafe { NonZeroIsize::from_ordinal_unchecked(ordinal) };
+        unsafe { NonZeroI64::new_unchecked(v.get() as i64) }
+    }
 }
 
+macro_rules! impl_wide_ordinal_for_non_zero_unsigned {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl WideOrdinal for $t {
+                const WIDE_ORDINAL_MAX: Option<u128> = Some(<$u>::MAX as u128 - 1);
+
+                #[inline]
+                fn wide_ordinal(&self) -> u128 {
+                    self.get() as u128 - 1
+                }
+
+                #[inline]
+                fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+                    <$t>::new(<$u>::try_from(ordinal.checked_add(1)?).ok()?)
+                }
+            }
+        )*
+    };
+}
+
+/// Negative values first, then positive values, like [`Ordinal`] impls above.
+macro_rules! impl_wide_ordinal_for_non_zero_signed {
+    ($($t:ty => $i:ty, $u:ty),*) => {
+        $(
+            impl WideOrdinal for $t {
+                const WIDE_ORDINAL_MAX: Option<u128> = Some(<$u>::MAX as u128 - 1);
+
+                #[inline]
+                fn wide_ordinal(&self) -> u128 {
+                    if self.get() > 0 {
+                        <$i>::MAX as u128 + self.get() as u128
+                    } else {
+                        self.get().abs_diff(<$i>::MIN) as u128
+                    }
+                }
+
+                #[inline]
+                fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+                    if ordinal <= <$i>::MAX as u128 {
+                        <$t>::new(<$i>::MIN.wrapping_add_unsigned(ordinal as $u))
+                    } else {
+                        <$t>::new(<$i>::try_from(ordinal - <$i>::MAX as u128).ok()?)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_ordinal_for_non_zero_unsigned!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroUsize => usize,
+    NonZeroU128 => u128
+);
+impl_wide_ordinal_for_non_zero_signed!(
+    NonZeroI8 => i8, u8,
+    NonZeroI16 => i16, u16,
+    NonZeroI32 => i32, u32,
+    NonZeroI64 => i64, u64,
+    NonZeroIsize => isize, usize,
+    NonZeroI128 => i128, u128
+);
+
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroI128;
     use std::num::NonZeroI16;
     use std::num::NonZeroI32;
     use std::num::NonZeroI64;
     use std::num::NonZeroI8;
     use std::num::NonZeroIsize;
+    use std::num::NonZeroU128;
     use std::num::NonZeroU16;
     use std::num::NonZeroU64;
     use std::num::NonZeroU8;
@@ -211,6 +339,9 @@ mod tests {
     use crate::tests::util::test_ordinal;
     use crate::tests::util::test_ordinal_some;
     use crate::tests::util::test_ordinal_value;
+    use crate::tests::util::test_wide_ordinal;
+    use crate::Ordinal;
+    use crate::WideOrdinal;
 
     #[test]
     fn test_non_zero_u8() {
@@ -271,4 +402,24 @@ mod tests {
         test_ordinal_value(NonZeroIsize::new(-1));
         test_ordinal_value(NonZeroIsize::new(1));
     }
+
+    #[test]
+    fn test_wide_non_zero() {
+        test_wide_ordinal((1..=u8::MAX).map(|i| NonZeroU8::new(i).unwrap()));
+        test_wide_ordinal((i8::MIN..=i8::MAX).filter_map(NonZeroI8::new));
+        test_wide_ordinal(
+            [1, 2, u128::MAX]
+                .into_iter()
+                .map(|i| NonZeroU128::new(i).unwrap()),
+        );
+        test_wide_ordinal(
+            [i128::MIN, -1, 1, i128::MAX]
+                .into_iter()
+                .map(|i| NonZeroI128::new(i).unwrap()),
+        );
+        assert_eq!(Some(u128::MAX - 1), NonZeroI128::WIDE_ORDINAL_MAX);
+        for v in (i16::MIN..=i16::MAX).filter_map(NonZeroI16::new) {
+            assert_eq!(v.ordinal() as u128, v.wide_ordinal());
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 impl Ordinal for Ordering {
     const ORDINAL_SIZE: usize = 3;
@@ -21,6 +22,27 @@ impl Ordinal for Ordering {
             _ => None,
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        match ordinal {
+            0 => Ordering::Less,
+            1 => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
+    }
+}
+
+impl WideOrdinal for Ordering {
+    const WIDE_ORDINAL_MAX: Option<u128> = Some(2);
+
+    fn wide_ordinal(&self) -> u128 {
+        self.ordinal() as u128
+    }
+
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        Ordering::from_ordinal(usize::try_from(ordinal).ok()?)
+    }
 }
 
 #[cfg(test)]
@@ -28,9 +50,11 @@ mod tests {
     use std::cmp::Ordering;
 
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_wide_ordinal;
 
     #[test]
     fn test_ordering() {
         test_ordinal([Ordering::Less, Ordering::Equal, Ordering::Greater]);
+        test_wide_ordinal([Ordering::Less, Ordering::Equal, Ordering::Greater]);
     }
 }
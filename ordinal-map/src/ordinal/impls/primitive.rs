@@ -1,4 +1,5 @@
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 impl Ordinal for bool {
     const ORDINAL_SIZE: usize = 2;
@@ -14,6 +15,27 @@ impl Ordinal for bool {
             _ => None,
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        ordinal != 0
+    }
+}
+
+impl WideOrdinal for bool {
+    const WIDE_ORDINAL_MAX: Option<u128> = Some(1);
+
+    fn wide_ordinal(&self) -> u128 {
+        *self as u128
+    }
+
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        match ordinal {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
 }
 
 impl Ordinal for u8 {
@@ -28,6 +50,11 @@ impl Ordinal for u8 {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         u8::try_from(ordinal).ok()
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        ordinal as u8
+    }
 }
 
 impl Ordinal for i8 {
@@ -42,6 +69,11 @@ impl Ordinal for i8 {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         i8::MIN.checked_add_unsigned(u8::try_from(ordinal).ok()?)
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        i8::MIN.wrapping_add_unsigned(ordinal as u8)
+    }
 }
 
 impl Ordinal for u16 {
@@ -56,6 +88,11 @@ impl Ordinal for u16 {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         u16::try_from(ordinal).ok()
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        ordinal as u16
+    }
 }
 
 impl Ordinal for i16 {
@@ -70,6 +107,11 @@ impl Ordinal for i16 {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         i16::MIN.checked_add_unsigned(u16::try_from(ordinal).ok()?)
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        i16::MIN.wrapping_add_unsigned(ordinal as u16)
+    }
 }
 
 /// Accessing this type is compile time error on 32-bit platforms.
@@ -86,6 +128,12 @@ impl Ordinal for u32 {
     fn from_ordinal(ordinal: usize) -> Option<Self> {
         u32::try_from(ordinal).ok()
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        const { Self::ORDINAL_SIZE };
+        ordinal as u32
+    }
 }
 
 impl Ordinal for i32 {
@@ -102,16 +150,24 @@ impl Ordinal for i32 {
         const { Self::ORDINAL_SIZE };
         i32::MIN.checked_add_unsigned(u32::try_from(ordinal).ok()?)
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        const { Self::ORDINAL_SIZE };
+        i32::MIN.wrapping_add_unsigned(ordinal as u32)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_wide_ordinal;
     use crate::Ordinal;
 
     #[test]
     fn test_bool() {
         test_ordinal::<bool>([false, true]);
+        test_wide_ordinal([false, true]);
     }
 
     #[test]
@@ -1,6 +1,9 @@
 use std::convert::Infallible;
 
+use crate::ordinal::wide::sum_max;
+use crate::ordinal::wide::wrapping_size;
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 impl<A: Ordinal> Ordinal for Option<A> {
     const ORDINAL_SIZE: usize = A::ORDINAL_SIZE + 1;
@@ -19,6 +22,15 @@ impl<A: Ordinal> Ordinal for Option<A> {
             A::from_ordinal(ordinal - 1).map(Some)
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        if ordinal == 0 {
+            None
+        } else {
+            Some(unsafe { A::from_ordinal_unchecked(ordinal - 1) })
+        }
+    }
 }
 
 impl<A: Ordinal, B: Ordinal> Ordinal for Result<A, B> {
@@ -38,6 +50,15 @@ impl<A: Ordinal, B: Ordinal> Ordinal for Result<A, B> {
             B::from_ordinal(ordinal - A::ORDINAL_SIZE).map(Err)
         }
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        if ordinal < A::ORDINAL_SIZE {
+            Ok(unsafe { A::from_ordinal_unchecked(ordinal) })
+        } else {
+            Err(unsafe { B::from_ordinal_unchecked(ordinal - A::ORDINAL_SIZE) })
+        }
+    }
 }
 
 impl Ordinal for Infallible {
@@ -50,6 +71,63 @@ impl Ordinal for Infallible {
     fn from_ordinal(_: usize) -> Option<Self> {
         None
     }
+
+    #[inline]
+    unsafe fn from_ordinal_unchecked(_: usize) -> Self {
+        unsafe { std::hint::unreachable_unchecked() }
+    }
+}
+
+impl<A: WideOrdinal> WideOrdinal for Option<A> {
+    const WIDE_ORDINAL_MAX: Option<u128> = sum_max(Some(0), A::WIDE_ORDINAL_MAX);
+
+    fn wide_ordinal(&self) -> u128 {
+        const { Self::WIDE_ORDINAL_MAX };
+        match self {
+            None => 0,
+            Some(a) => a.wide_ordinal() + 1,
+        }
+    }
+
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        if ordinal == 0 {
+            Some(None)
+        } else {
+            A::from_wide_ordinal(ordinal - 1).map(Some)
+        }
+    }
+}
+
+impl<A: WideOrdinal, B: WideOrdinal> WideOrdinal for Result<A, B> {
+    const WIDE_ORDINAL_MAX: Option<u128> = sum_max(A::WIDE_ORDINAL_MAX, B::WIDE_ORDINAL_MAX);
+
+    fn wide_ordinal(&self) -> u128 {
+        const { Self::WIDE_ORDINAL_MAX };
+        match self {
+            Ok(a) => a.wide_ordinal(),
+            Err(b) => wrapping_size(A::WIDE_ORDINAL_MAX) + b.wide_ordinal(),
+        }
+    }
+
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        match A::WIDE_ORDINAL_MAX {
+            Some(max) if ordinal <= max => A::from_wide_ordinal(ordinal).map(Ok),
+            Some(max) => B::from_wide_ordinal(ordinal - max - 1).map(Err),
+            None => B::from_wide_ordinal(ordinal).map(Err),
+        }
+    }
+}
+
+impl WideOrdinal for Infallible {
+    const WIDE_ORDINAL_MAX: Option<u128> = None;
+
+    fn wide_ordinal(&self) -> u128 {
+        match *self {}
+    }
+
+    fn from_wide_ordinal(_: u128) -> Option<Self> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -57,10 +135,15 @@ mod tests {
     use std::convert::Infallible;
 
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_wide_ordinal;
+    use crate::WideOrdinal;
 
     #[test]
     fn test_option() {
         test_ordinal([None, Some(false), Some(true)]);
+        test_wide_ordinal([None, Some(false), Some(true)]);
+        assert_eq!(Some(1 << 64), Option::<u64>::WIDE_ORDINAL_MAX);
+        test_wide_ordinal([None, Some(0u64), Some(u64::MAX)]);
     }
 
     #[test]
@@ -72,10 +155,15 @@ mod tests {
             Err(Some(false)),
             Err(Some(true)),
         ]);
+        test_wide_ordinal([Ok(0u64), Ok(u64::MAX), Err(false), Err(true)]);
+        test_wide_ordinal::<Result<Infallible, u128>>([Err(0), Err(u128::MAX)]);
+        test_wide_ordinal::<Result<u128, Infallible>>([Ok(0), Ok(u128::MAX)]);
     }
 
     #[test]
     fn test_infallible() {
         test_ordinal::<Infallible>([]);
+        assert_eq!(None, Infallible::WIDE_ORDINAL_MAX);
+        assert_eq!(None, Infallible::from_wide_ordinal(0));
     }
 }
@@ -1,10 +1,15 @@
 use crate as ordinal_map;
 
 ordinal_map_derive::impl_ordinal_for_tuple! {}
+ordinal_map_derive::impl_wide_ordinal_for_tuple! {}
 
 #[cfg(test)]
 mod tests {
+    use std::convert::Infallible;
+
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_wide_ordinal;
+    use crate::WideOrdinal;
 
     #[test]
     fn test_tuple_0() {
@@ -29,4 +34,19 @@ mod tests {
                 .flat_map(move |b| [None, Some(false), Some(true)].map(move |o| (i, b, o)))
         }));
     }
+
+    #[test]
+    fn test_wide_tuple() {
+        test_wide_ordinal([()]);
+        test_wide_ordinal([(false, 0u8), (false, 255), (true, 0), (true, 255)]);
+        assert_eq!(Some(u64::MAX as u128), <(u32, u32)>::WIDE_ORDINAL_MAX);
+        assert_eq!((1 << 32) + 2, (1u32, 2u32).wide_ordinal());
+        test_wide_ordinal([(0u32, 0u32), (0, u32::MAX), (1, 0), (u32::MAX, u32::MAX)]);
+        assert_eq!(Some((1 << 65) - 1), <(u64, bool)>::WIDE_ORDINAL_MAX);
+        test_wide_ordinal([(0u64, false), (0, true), (u64::MAX, true)]);
+        assert_eq!(Some(u128::MAX), <((), u128, ())>::WIDE_ORDINAL_MAX);
+        test_wide_ordinal([((), 0u128, ()), ((), u128::MAX, ())]);
+        assert_eq!(None, <(Infallible, u8)>::WIDE_ORDINAL_MAX);
+        assert_eq!(None, <(Infallible, u8)>::from_wide_ordinal(0));
+    }
 }
@@ -0,0 +1,141 @@
+//! [`WideOrdinal`] for integers and IP addresses, including types
+//! which don't implement [`Ordinal`](crate::Ordinal) because they have too many values.
+
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+
+use crate::WideOrdinal;
+
+macro_rules! impl_wide_ordinal_for_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl WideOrdinal for $t {
+                const WIDE_ORDINAL_MAX: Option<u128> = Some(<$t>::MAX as u128);
+
+                #[inline]
+                fn wide_ordinal(&self) -> u128 {
+                    *self as u128
+                }
+
+                #[inline]
+                fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+                    <$t>::try_from(ordinal).ok()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_wide_ordinal_for_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl WideOrdinal for $t {
+                const WIDE_ORDINAL_MAX: Option<u128> = Some(<$u>::MAX as u128);
+
+                #[inline]
+                fn wide_ordinal(&self) -> u128 {
+                    self.abs_diff(<$t>::MIN) as u128
+                }
+
+                #[inline]
+                fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+                    <$t>::MIN.checked_add_unsigned(<$u>::try_from(ordinal).ok()?)
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_ordinal_for_unsigned!(u8, u16, u32, u64, usize, u128);
+impl_wide_ordinal_for_signed!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    isize => usize,
+    i128 => u128
+);
+
+impl WideOrdinal for Ipv4Addr {
+    const WIDE_ORDINAL_MAX: Option<u128> = u32::WIDE_ORDINAL_MAX;
+
+    #[inline]
+    fn wide_ordinal(&self) -> u128 {
+        u32::from(*self) as u128
+    }
+
+    #[inline]
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        u32::try_from(ordinal).ok().map(Ipv4Addr::from)
+    }
+}
+
+impl WideOrdinal for Ipv6Addr {
+    const WIDE_ORDINAL_MAX: Option<u128> = u128::WIDE_ORDINAL_MAX;
+
+    #[inline]
+    fn wide_ordinal(&self) -> u128 {
+        u128::from(*self)
+    }
+
+    #[inline]
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self> {
+        Some(Ipv6Addr::from(ordinal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+    use std::net::Ipv6Addr;
+
+    use crate::tests::util::test_wide_ordinal;
+    use crate::WideOrdinal;
+
+    #[test]
+    fn test_unsigned() {
+        assert_eq!(Some(u32::MAX as u128), u32::WIDE_ORDINAL_MAX);
+        assert_eq!(None, u32::from_wide_ordinal(1 << 32));
+        test_wide_ordinal([0u32, 1, u32::MAX]);
+        assert_eq!(Some(u64::MAX as u128), u64::WIDE_ORDINAL_MAX);
+        assert_eq!(Some(u128::MAX), u128::WIDE_ORDINAL_MAX);
+        assert_eq!(None, u64::from_wide_ordinal(1 << 64));
+        assert_eq!(Some(u128::MAX), u128::from_wide_ordinal(u128::MAX));
+        test_wide_ordinal([0u64, 1, 2, u64::MAX - 1, u64::MAX]);
+        test_wide_ordinal([0usize, 1, usize::MAX]);
+        test_wide_ordinal([0u128, 1, u128::MAX]);
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(Some(u64::MAX as u128), i64::WIDE_ORDINAL_MAX);
+        assert_eq!(0, i64::MIN.wide_ordinal());
+        assert_eq!(1 << 63, 0i64.wide_ordinal());
+        assert_eq!(None, i64::from_wide_ordinal(1 << 64));
+        test_wide_ordinal(i8::MIN..=i8::MAX);
+        test_wide_ordinal([i32::MIN, -1, 0, 1, i32::MAX]);
+        test_wide_ordinal([i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX]);
+        test_wide_ordinal([isize::MIN, 0, isize::MAX]);
+        test_wide_ordinal([i128::MIN, -1, 0, 1, i128::MAX]);
+    }
+
+    #[test]
+    fn test_ip() {
+        assert_eq!(Some(u32::MAX as u128), Ipv4Addr::WIDE_ORDINAL_MAX);
+        assert_eq!(Some(u128::MAX), Ipv6Addr::WIDE_ORDINAL_MAX);
+        assert_eq!(0x7f000001, Ipv4Addr::LOCALHOST.wide_ordinal());
+        assert_eq!(1, Ipv6Addr::LOCALHOST.wide_ordinal());
+        test_wide_ordinal([
+            Ipv4Addr::UNSPECIFIED,
+            Ipv4Addr::LOCALHOST,
+            Ipv4Addr::BROADCAST,
+        ]);
+        test_wide_ordinal([
+            Ipv6Addr::UNSPECIFIED,
+            Ipv6Addr::LOCALHOST,
+            Ipv6Addr::new(
+                0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
+            ),
+        ]);
+    }
+}
@@ -1,2 +1,4 @@
+pub(crate) mod dyn_ordinal;
 pub(crate) mod impls;
 pub(crate) mod traits;
+pub(crate) mod wide;
@@ -15,8 +15,11 @@
 ///
 /// Implementations provided in this crate and generated with `#[derive(Ordinal)]`
 /// are compatible with `Ord` and `PartialOrd`, meaning `a < b <=> a.ordinal() < b.ordinal()`
-/// **with the exception** of derive on enums with explicit discriminants
-/// (derive ignores them and assigns ordinal numbers in order of declaration).
+/// **with the exceptions** of
+/// - derive on enums with explicit discriminants
+///   (derive ignores them and assigns ordinal numbers in order of declaration,
+///   unless `#[ordinal(discriminant)]` is specified)
+/// - derive with ordering attributes described below
 ///
 /// This is not enforced by the trait itself, but it is a good practice to follow.
 ///
@@ -51,6 +54,297 @@
 /// assert_eq!(4, MaybeColor::Colored(Color::Cyan, Bright(true)).ordinal());
 /// ```
 ///
+/// ## Generics
+///
+/// For generic types, the derive adds `FieldType: Ordinal` bound
+/// for each field type which mentions a type parameter.
+/// `#[ordinal(bound = "T: Ordinal")]` replaces these bounds with the given ones.
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// #[derive(Ordinal)]
+/// struct Pair<A, B>(A, Option<B>);
+///
+/// assert_eq!(6, Pair::<bool, bool>::ORDINAL_SIZE);
+/// ```
+///
+/// ## Field attributes
+///
+/// `#[ordinal(range = start..end)]` (or `start..=end`) on an integer field
+/// restricts the field to the given range, so only the values in the range
+/// contribute to `ORDINAL_SIZE`. Calling [`ordinal`](Ordinal::ordinal) on a value
+/// with the field outside of the range panics in debug builds.
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// struct Digit(#[ordinal(range = 0..10)] u8);
+///
+/// assert_eq!(10, Digit::ORDINAL_SIZE);
+/// assert_eq!(7, Digit(7).ordinal());
+/// assert_eq!(Some(Digit(9)), Digit::from_ordinal(9));
+/// assert_eq!(None, Digit::from_ordinal(10));
+/// ```
+///
+/// ## Discriminants
+///
+/// For fieldless enums, `#[ordinal(discriminant)]` makes the ordinal equal to the discriminant.
+/// Discriminants must be exactly `0..N` in any order, gaps are compile errors:
+/// every ordinal below `ORDINAL_SIZE` must have a value,
+/// because iterators and total maps construct a key for each of them.
+///
+/// `#[ordinal(discriminant(dense))]` instead assigns ordinals `0..N`
+/// to variants in the order of their discriminants, which may have gaps.
+/// Such ordinals are not raw codes, so a table indexed by raw codes
+/// needs a map keyed by the integer type, for example `OrdinalMap<u8, V>` with `op as u8` keys.
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// #[ordinal(discriminant)]
+/// #[repr(u8)]
+/// enum Opcode {
+///     Load = 1,
+///     Store = 2,
+///     Nop = 0,
+/// }
+///
+/// assert_eq!(3, Opcode::ORDINAL_SIZE);
+/// assert_eq!(1, Opcode::Load.ordinal());
+/// assert_eq!(Some(Opcode::Nop), Opcode::from_ordinal(0));
+///
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// #[ordinal(discriminant(dense))]
+/// #[repr(u8)]
+/// enum Flag {
+///     Read = 0x04,
+///     Write = 0x02,
+///     Exec = 0x01,
+/// }
+///
+/// assert_eq!(3, Flag::ORDINAL_SIZE);
+/// assert_eq!(0, Flag::Exec.ordinal());
+/// assert_eq!(Some(Flag::Read), Flag::from_ordinal(2));
+/// ```
+///
+/// ## Ordering attributes
+///
+/// By default, enum variants are ordered in declaration order,
+/// and struct fields are ordered from the most significant to the least significant
+/// in declaration order. This can be changed with attributes:
+/// - `#[ordinal(order = N)]` on every enum variant: variants are ordered by `N`
+/// - `#[ordinal(field_order = [b, a])]` on a struct or enum variant:
+///   fields from the most significant to the least significant
+///   (tuple fields are referenced by index, like `[1, 0]`)
+/// - `#[ordinal(reverse)]` on a struct or enum: reverse the order of all values
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// enum Opcode {
+///     #[ordinal(order = 1)]
+///     Load,
+///     #[ordinal(order = 0)]
+///     Nop,
+/// }
+///
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// #[ordinal(field_order = [minor, major])]
+/// struct Version {
+///     major: bool,
+///     minor: bool,
+/// }
+///
+/// assert_eq!(0, Opcode::Nop.ordinal());
+/// assert_eq!(1, Opcode::Load.ordinal());
+/// assert_eq!(
+///     1,
+///     Version {
+///         major: true,
+///         minor: false
+///     }
+///     .ordinal()
+/// );
+/// ```
+///
+/// ## Names
+///
+/// `#[ordinal(names)]` on a fieldless enum generates:
+/// - `const NAMES: [&str; N]`: variant names in ordinal order
+/// - `fn name(&self) -> &'static str`
+/// - `fn from_name(name: &str) -> Option<Self>`
+/// - [`FromStr`](std::str::FromStr) returning [`ParseNameError`](crate::ParseNameError)
+///   and [`Display`](std::fmt::Display)
+///
+/// Names can be changed with `#[ordinal(rename = "...")]` on a variant
+/// or `#[ordinal(rename_all = "...")]` on the enum
+/// (`"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`,
+/// `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` or `"SCREAMING-KEBAB-CASE"`).
+/// `#[ordinal(case_insensitive)]` makes `from_name` ignore ASCII case.
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// #[ordinal(names, rename_all = "kebab-case", case_insensitive)]
+/// enum LogLevel {
+///     Debug,
+///     Info,
+///     #[ordinal(rename = "warn")]
+///     Warning,
+/// }
+///
+/// assert_eq!(["debug", "info", "warn"], LogLevel::NAMES);
+/// assert_eq!(Ok(LogLevel::Warning), "WARN".parse());
+/// assert_eq!("info", LogLevel::Info.to_string());
+/// ```
+///
+/// ## All values
+///
+/// `#[ordinal(all_values)]` on a fieldless enum generates `const ALL_VALUES: [Self; N]`
+/// with all values in ordinal order. Unlike [`all_values`](Ordinal::all_values),
+/// it can be used in `const` context or as a `&'static [Self]` slice.
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// #[ordinal(all_values)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// const RED: [Suit; 2] = {
+///     let [_, diamonds, hearts, _] = Suit::ALL_VALUES;
+///     [diamonds, hearts]
+/// };
+/// let all: &'static [Suit] = &Suit::ALL_VALUES;
+/// assert_eq!([Suit::Diamonds, Suit::Hearts], RED);
+/// assert_eq!(4, all.len());
+/// ```
+///
+/// ## Forwarding
+///
+/// `#[ordinal(transparent)]` on a struct with a single field
+/// forwards the implementation to the field type.
+///
+/// `#[ordinal(via = "From<T>")]` implements `Ordinal` using conversions
+/// to and from `T: Ordinal`: `Self: From<T> + Clone + Into<T>`.
+/// `ORDINAL_SIZE` is the same as for `T`.
+/// The trait must be the standard one: `From`, `core::convert::From` or `std::convert::From`.
+///
+/// `#[ordinal(via = "TryFrom<T>")]` is the same, but with `Self: TryFrom<T>`,
+/// and [`from_ordinal`](Ordinal::from_ordinal) returns `None` for values rejected by `try_from`.
+/// Ordinals are then not a bijection onto `0..ORDINAL_SIZE`: ordinals of rejected values
+/// have no value, so [`all_values`](Ordinal::all_values), total maps
+/// and other APIs which create a value for every ordinal (like complements of sets)
+/// panic on them. Maps and sets create keys only for the inserted entries.
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// #[derive(Ordinal, Clone, Copy, Debug, Eq, PartialEq)]
+/// #[ordinal(via = "From<bool>")]
+/// enum Switch {
+///     Off,
+///     On,
+/// }
+///
+/// impl From<bool> for Switch {
+///     fn from(on: bool) -> Switch {
+///         if on { Switch::On } else { Switch::Off }
+///     }
+/// }
+///
+/// impl From<Switch> for bool {
+///     fn from(switch: Switch) -> bool {
+///         switch == Switch::On
+///     }
+/// }
+///
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// #[ordinal(transparent)]
+/// struct Key(Switch);
+///
+/// assert_eq!(2, Key::ORDINAL_SIZE);
+/// assert_eq!(1, Key(Switch::On).ordinal());
+/// assert_eq!(Some(Key(Switch::Off)), Key::from_ordinal(0));
+///
+/// #[derive(Ordinal, Clone, Debug, Eq, PartialEq)]
+/// #[ordinal(via = "TryFrom<u8>")]
+/// struct Digit(u8);
+///
+/// impl TryFrom<u8> for Digit {
+///     type Error = ();
+///
+///     fn try_from(value: u8) -> Result<Self, ()> {
+///         if value < 10 { Ok(Digit(value)) } else { Err(()) }
+///     }
+/// }
+///
+/// impl From<Digit> for u8 {
+///     fn from(digit: Digit) -> u8 {
+///         digit.0
+///     }
+/// }
+///
+/// assert_eq!(256, Digit::ORDINAL_SIZE);
+/// assert_eq!(7, Digit(7).ordinal());
+/// assert_eq!(None, Digit::from_ordinal(10));
+/// ```
+///
+/// ## Wide ordinal
+///
+/// `#[ordinal(wide)]` also implements [`WideOrdinal`](crate::WideOrdinal)
+/// with the same ordinals.
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// use ordinal_map::WideOrdinal;
+/// #[derive(Ordinal, Debug, Eq, PartialEq)]
+/// #[ordinal(wide)]
+/// struct Cell(u8, bool);
+///
+/// assert_eq!(Some(511), Cell::WIDE_ORDINAL_MAX);
+/// assert_eq!(Cell(3, true).ordinal() as u128, Cell(3, true).wide_ordinal());
+/// assert_eq!(Some(Cell(3, true)), Cell::from_wide_ordinal(7));
+/// ```
+///
+/// ## Unchecked conversion
+///
+/// Derived implementations override
+/// [`from_ordinal_unchecked`](Ordinal::from_ordinal_unchecked) without checks,
+/// which makes iteration over maps and sets faster.
+/// The generated `unsafe` code does not trigger the `unsafe_code` lint,
+/// so the derive can be used in crates with `#![forbid(unsafe_code)]`.
+///
+/// ## Size limit
+///
+/// It is a compile-time error to use `ORDINAL_SIZE` when it overflows `usize`,
+/// the error points to the field or the variant where the overflow happens.
+/// Maps, sets and iterators use `ORDINAL_SIZE`, so the error is reported
+/// when the type is used as a key, not where it is defined: the size depends
+/// on the target (for example, `struct S(u16, u16)` only overflows on 32-bit targets),
+/// and a type which is never used as a key on such target should still compile.
+///
+/// `#[ordinal(max_size = N)]` checks the size where the type is defined
+/// and makes it a compile-time error if `ORDINAL_SIZE` is greater than `N`, for example
+/// to keep the type small enough for
+/// [`OrdinalTotalArrayMap`](crate::map::total::OrdinalTotalArrayMap).
+///
+/// ```
+/// use ordinal_map::Ordinal;
+/// #[derive(Ordinal)]
+/// #[ordinal(max_size = 1024)]
+/// struct Key(u8, bool);
+/// ```
+///
+/// ## Crate path
+///
+/// Generated code refers to this crate as `ordinal_map` resolved at the derive site.
+/// When the crate is renamed or re-exported, specify the path with
+/// `#[ordinal(crate = "path::to::ordinal_map")]`.
+///
 /// # See also
 ///
 /// - [`Iter`](crate::OrdinalValues) to iterate over all possible values.
@@ -86,6 +380,29 @@ pub trait Ordinal: Sized {
     /// ```
     fn from_ordinal(ordinal: usize) -> Option<Self>;
 
+    /// Returns the value from the index without checking that the index is in range.
+    ///
+    /// Default implementation calls [`from_ordinal`](Ordinal::from_ordinal)
+    /// and panics if it returns `None`. Implementations may override it to skip the check
+    /// only if `from_ordinal` returns `Some` for every ordinal allowed below.
+    ///
+    /// # Safety
+    ///
+    /// `ordinal` must be less than [`ORDINAL_SIZE`](Ordinal::ORDINAL_SIZE)
+    /// or be returned by [`ordinal`](Ordinal::ordinal) for some value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ordinal_map::Ordinal;
+    ///
+    /// assert_eq!(i16::MIN + 1, unsafe { i16::from_ordinal_unchecked(1) });
+    /// ```
+    #[inline]
+    unsafe fn from_ordinal_unchecked(ordinal: usize) -> Self {
+        Self::from_ordinal(ordinal).expect("no value for ordinal below `ORDINAL_SIZE`")
+    }
+
     /// Iterate over all possible values.
     ///
     /// Values are returned in order of their ordinal numbers.
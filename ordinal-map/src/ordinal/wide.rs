@@ -0,0 +1,129 @@
+use crate::WideOrdinalValues;
+
+/// Like [`Ordinal`], but ordinals are `u128`, so it can be implemented
+/// for types with more than `usize::MAX` values, like `u64` on 32-bit platforms,
+/// `u128` or [`Ipv6Addr`](std::net::Ipv6Addr).
+///
+/// It is implemented for integers, [`bool`], [`char`], [`Option`], [`Result`],
+/// tuples, arrays and other standard types implementing [`Ordinal`],
+/// with the same ordinals. For types deriving [`Ordinal`], `#[ordinal(wide)]`
+/// implements `WideOrdinal` with the same ordinals, otherwise derive both traits.
+///
+/// There is no blanket implementation for all [`Ordinal`] types: it would
+/// conflict with the implementations for generic types like `Option<T>`, tuples
+/// and arrays, which must also cover types too large for [`Ordinal`]
+/// like `(u64, u64)`, and with `#[derive(WideOrdinal)]` on types which are also [`Ordinal`].
+///
+/// Maps and sets in this crate require [`Ordinal`], this trait is meant for
+/// hashing, encoding or enumeration of large key spaces.
+///
+/// # Size
+///
+/// Types like `u128` have `2^128` values, which does not fit in `u128`,
+/// so instead of the number of values the trait provides
+/// the largest ordinal, [`WIDE_ORDINAL_MAX`](WideOrdinal::WIDE_ORDINAL_MAX).
+///
+/// # Derive
+///
+/// `WideOrdinal` can be derived for structs and enums
+/// whose fields implement `WideOrdinal`. Ordinals are assigned
+/// the same way as for [`Ordinal`], without the support for `#[ordinal(...)]` attributes
+/// except `bound` and `crate`.
+/// Derived types must have at most `2^128` values.
+///
+/// ```
+/// use ordinal_map::WideOrdinal;
+/// #[derive(WideOrdinal, Debug, Eq, PartialEq)]
+/// struct Span {
+///     start: u64,
+///     len: u32,
+/// }
+///
+/// assert_eq!(Some((1 << 96) - 1), Span::WIDE_ORDINAL_MAX);
+/// assert_eq!((1 << 32) + 2, Span { start: 1, len: 2 }.wide_ordinal());
+/// assert_eq!(
+///     Some(Span { start: 1, len: 2 }),
+///     Span::from_wide_ordinal((1 << 32) + 2)
+/// );
+/// ```
+///
+/// [`Ordinal`]: crate::Ordinal
+pub trait WideOrdinal: Sized {
+    /// The largest ordinal, or `None` if the type has no values.
+    ///
+    /// The number of values is `WIDE_ORDINAL_MAX + 1`.
+    const WIDE_ORDINAL_MAX: Option<u128>;
+
+    /// Return the wide ordinal of this value, not greater than
+    /// [`WIDE_ORDINAL_MAX`](WideOrdinal::WIDE_ORDINAL_MAX).
+    fn wide_ordinal(&self) -> u128;
+
+    /// Inverse of [`wide_ordinal`](WideOrdinal::wide_ordinal).
+    /// Return `None` if the ordinal is greater than
+    /// [`WIDE_ORDINAL_MAX`](WideOrdinal::WIDE_ORDINAL_MAX).
+    fn from_wide_ordinal(ordinal: u128) -> Option<Self>;
+
+    /// Iterate over all values of the type in wide ordinal order.
+    #[inline]
+    fn all_wide_values() -> WideOrdinalValues<Self> {
+        WideOrdinalValues::new()
+    }
+}
+
+/// Largest wide ordinal of a type whose values are values of either of two types
+/// with largest wide ordinals `a` and `b`, like [`Result`].
+pub(crate) const fn sum_max(a: Option<u128>, b: Option<u128>) -> Option<u128> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(
+            a.checked_add(b)
+                .expect("`WideOrdinal` overflows `u128`")
+                .checked_add(1)
+                .expect("`WideOrdinal` overflows `u128`"),
+        ),
+        (Some(max), None) | (None, Some(max)) => Some(max),
+        (None, None) => None,
+    }
+}
+
+/// Largest wide ordinal of a pair of types with largest wide ordinals `a` and `b`,
+/// `(a + 1) * (b + 1) - 1` computed without overflowing on `2^128` values.
+pub(crate) const fn product_max(a: Option<u128>, b: Option<u128>) -> Option<u128> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(
+            a.checked_mul(b)
+                .expect("`WideOrdinal` overflows `u128`")
+                .checked_add(a)
+                .expect("`WideOrdinal` overflows `u128`")
+                .checked_add(b)
+                .expect("`WideOrdinal` overflows `u128`"),
+        ),
+        _ => None,
+    }
+}
+
+/// Number of values of a type with the given largest wide ordinal,
+/// wrapped to `0` for `2^128` values.
+pub(crate) const fn wrapping_size(max: Option<u128>) -> u128 {
+    match max {
+        Some(max) => max.wrapping_add(1),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::tests::util::test_wide_ordinal;
+    use crate::WideOrdinal;
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(Some(255), u8::WIDE_ORDINAL_MAX);
+        assert_eq!(Some(1), bool::WIDE_ORDINAL_MAX);
+        assert_eq!(None, Infallible::WIDE_ORDINAL_MAX);
+        assert_eq!(None, u8::from_wide_ordinal(1 << 64));
+        test_wide_ordinal([false, true]);
+        test_wide_ordinal(i8::MIN..=i8::MAX);
+    }
+}
@@ -0,0 +1,921 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::iter;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::RangeInclusive;
+use std::slice;
+
+use crate::Ordinal;
+
+/// Number of low ordinal bits stored in a container.
+const CHUNK_BITS: u32 = 16;
+const BITMAP_WORDS: usize = (1 << CHUNK_BITS) / u64::BITS as usize;
+/// Array container with more elements is larger than a bitmap container.
+const ARRAY_MAX: usize = 4096;
+/// Bitmap container is converted back to an array when it shrinks to this many elements,
+/// lower than `ARRAY_MAX` so that alternating insertions and removals don't convert every time.
+const ARRAY_MIN: usize = ARRAY_MAX / 2;
+/// Run container with more runs is larger than a bitmap container.
+const RUNS_MAX: usize = 2048;
+
+type Bitmap = Box<[u64; BITMAP_WORDS]>;
+
+/// Which elements to keep when combining two sets.
+#[derive(Clone, Copy)]
+struct Op {
+    only_a: bool,
+    only_b: bool,
+    both: bool,
+}
+
+impl Op {
+    const UNION: Op = Op {
+        only_a: true,
+        only_b: true,
+        both: true,
+    };
+    const INTERSECTION: Op = Op {
+        only_a: false,
+        only_b: false,
+        both: true,
+    };
+    const DIFFERENCE: Op = Op {
+        only_a: true,
+        only_b: false,
+        both: false,
+    };
+    const SYMMETRIC_DIFFERENCE: Op = Op {
+        only_a: true,
+        only_b: true,
+        both: false,
+    };
+
+    #[inline]
+    fn word(self, a: u64, b: u64) -> u64 {
+        let mut r = 0;
+        if self.only_a {
+            r |= a & !b;
+        }
+        if self.only_b {
+            r |= !a & b;
+        }
+        if self.both {
+            r |= a & b;
+        }
+        r
+    }
+}
+
+/// Set of the low 16 bits of the ordinals of a chunk. Never empty.
+#[derive(Clone)]
+enum Container {
+    /// Sorted elements, at most `ARRAY_MAX`.
+    Array(Vec<u16>),
+    /// Bit per element, more than `ARRAY_MIN` elements.
+    Bitmap { words: Bitmap, len: usize },
+    /// Sorted disjoint non-adjacent inclusive ranges `(first, last)`, at most `RUNS_MAX`,
+    /// and the number of elements in them.
+    Run { runs: Vec<(u16, u16)>, len: usize },
+}
+
+impl Container {
+    fn len(&self) -> usize {
+        match self {
+            Container::Array(array) => array.len(),
+            Container::Bitmap { len, .. } | Container::Run { len, .. } => *len,
+        }
+    }
+
+    /// Index of the run which may contain the value.
+    #[inline]
+    fn run_index(runs: &[(u16, u16)], value: u16) -> Option<usize> {
+        runs.partition_point(|(first, _)| *first <= value)
+            .checked_sub(1)
+    }
+
+    fn contains(&self, value: u16) -> bool {
+        match self {
+            Container::Array(array) => array.binary_search(&value).is_ok(),
+            Container::Bitmap { words, .. } => {
+                words[value as usize / 64] & (1 << (value % 64)) != 0
+            }
+            Container::Run { runs, .. } => match Self::run_index(runs, value) {
+                Some(i) => value <= runs[i].1,
+                None => false,
+            },
+        }
+    }
+
+    fn insert(&mut self, value: u16) -> bool {
+        match self {
+            Container::Array(array) => match array.binary_search(&value) {
+                Ok(_) => false,
+                Err(i) => {
+                    array.insert(i, value);
+                    if array.len() > ARRAY_MAX {
+                        *self = Container::from_words(self.to_words()).unwrap();
+                    }
+                    true
+                }
+            },
+            Container::Bitmap { words, len } => {
+                let word = &mut words[value as usize / 64];
+                let bit = 1 << (value % 64);
+                if *word & bit != 0 {
+                    return false;
+                }
+                *word |= bit;
+                *len += 1;
+                true
+            }
+            Container::Run { runs, len } => {
+                let i = match Self::run_index(runs, value) {
+                    Some(i) if value <= runs[i].1 => return false,
+                    Some(i) => i + 1,
+                    None => 0,
+                };
+                *len += 1;
+                // Previous run ends before `value`, next run starts after `value`.
+                let extend_prev = i > 0 && runs[i - 1].1 + 1 == value;
+                let extend_next = i < runs.len() && runs[i].0 - 1 == value;
+                match (extend_prev, extend_next) {
+                    (true, true) => {
+                        runs[i - 1].1 = runs[i].1;
+                        runs.remove(i);
+                    }
+                    (true, false) => runs[i - 1].1 = value,
+                    (false, true) => runs[i].0 = value,
+                    (false, false) => {
+                        runs.insert(i, (value, value));
+                        if runs.len() > RUNS_MAX {
+                            *self = Container::from_words(self.to_words()).unwrap();
+                        }
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    /// Remove the value, returning `true` if it was present.
+    /// The container may become empty.
+    fn remove(&mut self, value: u16) -> bool {
+        match self {
+            Container::Array(array) => match array.binary_search(&value) {
+                Ok(i) => {
+                    array.remove(i);
+                    true
+                }
+                Err(_) => false,
+            },
+            Container::Bitmap { words, len } => {
+                let word = &mut words[value as usize / 64];
+                let bit = 1 << (value % 64);
+                if *word & bit == 0 {
+                    return false;
+                }
+                *word &= !bit;
+                *len -= 1;
+                if *len <= ARRAY_MIN {
+                    *self = Container::Array(self.iter().collect());
+                }
+                true
+            }
+            Container::Run { runs, len } => {
+                let i = match Self::run_index(runs, value) {
+                    Some(i) if value <= runs[i].1 => i,
+                    _ => return false,
+                };
+                *len -= 1;
+                let (first, last) = runs[i];
+                if first == last {
+                    runs.remove(i);
+                } else if value == first {
+                    runs[i].0 = value + 1;
+                } else if value == last {
+                    runs[i].1 = value - 1;
+                } else {
+                    runs[i].1 = value - 1;
+                    runs.insert(i + 1, (value + 1, last));
+                    if runs.len() > RUNS_MAX {
+                        *self = Container::from_words(self.to_words()).unwrap();
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    fn to_words(&self) -> Bitmap {
+        if let Container::Bitmap { words, .. } = self {
+            return words.clone();
+        }
+        let mut words: Bitmap = Box::new([0; BITMAP_WORDS]);
+        for value in self.iter() {
+            words[value as usize / 64] |= 1 << (value % 64);
+        }
+        words
+    }
+
+    /// Container of the smallest representation, or `None` if there are no elements.
+    fn from_words(words: Bitmap) -> Option<Container> {
+        let len = words.iter().map(|w| w.count_ones() as usize).sum::<usize>();
+        if len == 0 {
+            return None;
+        }
+        let mut runs = 0;
+        let mut prev_high = 0;
+        for &word in words.iter() {
+            // Bits which start a run.
+            runs += (word & !((word << 1) | prev_high)).count_ones() as usize;
+            prev_high = word >> 63;
+        }
+        let array_bytes = if len <= ARRAY_MAX {
+            2 * len
+        } else {
+            usize::MAX
+        };
+        let run_bytes = 4 * runs;
+        let bitmap_bytes = 8 * BITMAP_WORDS;
+        let container = if run_bytes < array_bytes && run_bytes < bitmap_bytes {
+            let mut runs: Vec<(u16, u16)> = Vec::with_capacity(runs);
+            for value in ContainerIter::bitmap(&words) {
+                match runs.last_mut() {
+                    Some((_, last)) if *last + 1 == value => *last = value,
+                    _ => runs.push((value, value)),
+                }
+            }
+            Container::Run { runs, len }
+        } else if array_bytes <= bitmap_bytes {
+            Container::Array(ContainerIter::bitmap(&words).collect())
+        } else {
+            Container::Bitmap { words, len }
+        };
+        Some(container)
+    }
+
+    /// Convert to the smallest representation.
+    fn optimize(&mut self) {
+        *self = Container::from_words(self.to_words()).unwrap();
+        if let Container::Array(array) = self {
+            array.shrink_to_fit();
+        }
+    }
+
+    fn combine(a: &Container, b: &Container, op: Op) -> Option<Container> {
+        if let (Container::Array(a), Container::Array(b)) = (a, b) {
+            let mut r = Vec::new();
+            let (mut i, mut j) = (0, 0);
+            while i < a.len() || j < b.len() {
+                let order = match (a.get(i), b.get(j)) {
+                    (Some(x), Some(y)) => x.cmp(y),
+                    (Some(_), None) => Ordering::Less,
+                    (None, _) => Ordering::Greater,
+                };
+                match order {
+                    Ordering::Equal => {
+                        if op.both {
+                            r.push(a[i]);
+                        }
+                        i += 1;
+                        j += 1;
+                    }
+                    Ordering::Less => {
+                        if op.only_a {
+                            r.push(a[i]);
+                        }
+                        i += 1;
+                    }
+                    Ordering::Greater => {
+                        if op.only_b {
+                            r.push(b[j]);
+                        }
+                        j += 1;
+                    }
+                }
+            }
+            return if r.is_empty() {
+                None
+            } else if r.len() > ARRAY_MAX {
+                Container::from_words(Container::Array(r).to_words())
+            } else {
+                Some(Container::Array(r))
+            };
+        }
+        let mut words = a.to_words();
+        let b = b.to_words();
+        for (a, b) in words.iter_mut().zip(b.iter()) {
+            *a = op.word(*a, *b);
+        }
+        Container::from_words(words)
+    }
+
+    fn is_subset(&self, other: &Container) -> bool {
+        match self {
+            Container::Array(array) => array.iter().all(|v| other.contains(*v)),
+            _ => {
+                let other = other.to_words();
+                let words = self.to_words();
+                words.iter().zip(other.iter()).all(|(a, b)| a & !b == 0)
+            }
+        }
+    }
+
+    fn is_disjoint(&self, other: &Container) -> bool {
+        match (self, other) {
+            (Container::Array(array), other) | (other, Container::Array(array)) => {
+                !array.iter().any(|v| other.contains(*v))
+            }
+            _ => {
+                let other = other.to_words();
+                let words = self.to_words();
+                words.iter().zip(other.iter()).all(|(a, b)| a & b == 0)
+            }
+        }
+    }
+
+    fn iter(&self) -> ContainerIter<'_> {
+        match self {
+            Container::Array(array) => ContainerIter::Array(array.iter()),
+            Container::Bitmap { words, .. } => ContainerIter::bitmap(words),
+            Container::Run { runs, .. } => {
+                ContainerIter::Run(runs.iter().flat_map(|(first, last)| *first..=*last))
+            }
+        }
+    }
+}
+
+type RunIter<'a> = iter::FlatMap<
+    slice::Iter<'a, (u16, u16)>,
+    RangeInclusive<u16>,
+    fn(&(u16, u16)) -> RangeInclusive<u16>,
+>;
+
+#[derive(Clone)]
+enum ContainerIter<'a> {
+    Array(slice::Iter<'a, u16>),
+    /// Bits `front..back` are not visited yet.
+    Bitmap {
+        words: &'a [u64; BITMAP_WORDS],
+        front: usize,
+        back: usize,
+    },
+    Run(RunIter<'a>),
+}
+
+impl<'a> ContainerIter<'a> {
+    fn bitmap(words: &'a [u64; BITMAP_WORDS]) -> Self {
+        ContainerIter::Bitmap {
+            words,
+            front: 0,
+            back: 1 << CHUNK_BITS,
+        }
+    }
+}
+
+impl<'a> Iterator for ContainerIter<'a> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        match self {
+            ContainerIter::Array(iter) => iter.next().copied(),
+            ContainerIter::Bitmap { words, front, back } => {
+                while *front < *back {
+                    let i = *front / 64;
+                    let word = words[i] & (u64::MAX << (*front % 64));
+                    if word != 0 {
+                        let bit = i * 64 + word.trailing_zeros() as usize;
+                        if bit >= *back {
+                            break;
+                        }
+                        *front = bit + 1;
+                        return Some(bit as u16);
+                    }
+                    *front = (i + 1) * 64;
+                }
+                *front = *back;
+                None
+            }
+            ContainerIter::Run(iter) => iter.next(),
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for ContainerIter<'a> {
+    fn next_back(&mut self) -> Option<u16> {
+        match self {
+            ContainerIter::Array(iter) => iter.next_back().copied(),
+            ContainerIter::Bitmap { words, front, back } => {
+                while *front < *back {
+                    let last = *back - 1;
+                    let i = last / 64;
+                    let word = words[i] & (u64::MAX >> (63 - last % 64));
+                    if word != 0 {
+                        let bit = i * 64 + 63 - word.leading_zeros() as usize;
+                        if bit < *front {
+                            break;
+                        }
+                        *back = bit;
+                        return Some(bit as u16);
+                    }
+                    *back = i * 64;
+                }
+                *back = *front;
+                None
+            }
+            ContainerIter::Run(iter) => iter.next_back(),
+        }
+    }
+}
+
+/// Container for ordinals `key << CHUNK_BITS .. (key + 1) << CHUNK_BITS`.
+#[derive(Clone)]
+struct Chunk {
+    key: usize,
+    container: Container,
+}
+
+/// Compressed set for [`Ordinal`] types with a large number of values, like `u32` or `char`.
+///
+/// This is a [roaring bitmap](https://roaringbitmap.org/):
+/// ordinals are split into chunks of `65536` values,
+/// and each non-empty chunk is stored as a sorted array of elements,
+/// a bitmap or a list of runs, whichever is smaller.
+/// Empty chunks take no memory.
+///
+/// Lookup is `O(log(chunks) + log(chunk elements))`, set algebra processes whole chunks.
+/// A chunk changes its representation when it outgrows the current one
+/// or shrinks well below it,
+/// call [`optimize`](OrdinalCompressedSet::optimize) after bulk updates
+/// to convert every chunk to the smallest representation.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::set::OrdinalCompressedSet;
+///
+/// let mut letters: OrdinalCompressedSet<char> = ('a'..='z').collect();
+/// letters.extend('Α'..='Ω');
+/// letters.optimize();
+/// assert!(letters.contains(&'q'));
+/// assert!(!letters.contains(&'@'));
+/// assert_eq!(26 + 25, letters.len());
+/// ```
+pub struct OrdinalCompressedSet<T> {
+    /// Sorted by key.
+    chunks: Vec<Chunk>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Ordinal> OrdinalCompressedSet<T> {
+    /// Create a new empty set.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalCompressedSet {
+            chunks: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn split(value: &T) -> (usize, u16) {
+        let ordinal = value.ordinal();
+        (ordinal >> CHUNK_BITS, ordinal as u16)
+    }
+
+    #[inline]
+    fn search(&self, key: usize) -> Result<usize, usize> {
+        self.chunks.binary_search_by_key(&key, |chunk| chunk.key)
+    }
+
+    /// Check if the set contains an element.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        let (key, low) = Self::split(value);
+        match self.search(key) {
+            Ok(i) => self.chunks[i].container.contains(low),
+            Err(_) => false,
+        }
+    }
+
+    /// Insert an element into the set, returning `true` if the element was not already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let (key, low) = Self::split(&value);
+        match self.search(key) {
+            Ok(i) => self.chunks[i].container.insert(low),
+            Err(i) => {
+                let container = Container::Array(vec![low]);
+                self.chunks.insert(i, Chunk { key, container });
+                true
+            }
+        }
+    }
+
+    /// Remove an element from the set, returning `true` if the element was present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let (key, low) = Self::split(value);
+        let Ok(i) = self.search(key) else {
+            return false;
+        };
+        let container = &mut self.chunks[i].container;
+        if !container.remove(low) {
+            return false;
+        }
+        if container.len() == 0 {
+            self.chunks.remove(i);
+        }
+        true
+    }
+
+    /// Number of elements in the set.
+    /// This is an `O(chunks)` operation.
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|c| c.container.len()).sum()
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Remove all elements from the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+    }
+
+    /// Convert every chunk to its smallest representation.
+    pub fn optimize(&mut self) {
+        for chunk in &mut self.chunks {
+            chunk.container.optimize();
+        }
+        self.chunks.shrink_to_fit();
+    }
+
+    fn combine_with(&mut self, other: &OrdinalCompressedSet<T>, op: Op) {
+        let mut chunks = Vec::with_capacity(self.chunks.len());
+        let mut a = mem::take(&mut self.chunks).into_iter().peekable();
+        let mut b = other.chunks.iter().peekable();
+        loop {
+            let order = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => x.key.cmp(&y.key),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+            match order {
+                Ordering::Equal => {
+                    let (x, y) = (a.next().unwrap(), b.next().unwrap());
+                    if let Some(container) = Container::combine(&x.container, &y.container, op) {
+                        chunks.push(Chunk {
+                            key: x.key,
+                            container,
+                        });
+                    }
+                }
+                Ordering::Less => {
+                    let x = a.next().unwrap();
+                    if op.only_a {
+                        chunks.push(x);
+                    }
+                }
+                Ordering::Greater => {
+                    let y = b.next().unwrap();
+                    if op.only_b {
+                        chunks.push(y.clone());
+                    }
+                }
+            }
+        }
+        self.chunks = chunks;
+    }
+
+    /// Add all elements of `other` to this set.
+    pub fn union_with(&mut self, other: &OrdinalCompressedSet<T>) {
+        self.combine_with(other, Op::UNION);
+    }
+
+    /// Keep only elements which are also in `other`.
+    pub fn intersect_with(&mut self, other: &OrdinalCompressedSet<T>) {
+        self.combine_with(other, Op::INTERSECTION);
+    }
+
+    /// Remove all elements which are in `other`.
+    pub fn difference_with(&mut self, other: &OrdinalCompressedSet<T>) {
+        self.combine_with(other, Op::DIFFERENCE);
+    }
+
+    /// Keep elements which are in exactly one of the sets.
+    pub fn symmetric_difference_with(&mut self, other: &OrdinalCompressedSet<T>) {
+        self.combine_with(other, Op::SYMMETRIC_DIFFERENCE);
+    }
+
+    /// Check if every element of this set is in `other`.
+    pub fn is_subset(&self, other: &OrdinalCompressedSet<T>) -> bool {
+        self.chunks
+            .iter()
+            .all(|chunk| match other.search(chunk.key) {
+                Ok(i) => chunk.container.is_subset(&other.chunks[i].container),
+                Err(_) => false,
+            })
+    }
+
+    /// Check if the sets have no elements in common.
+    pub fn is_disjoint(&self, other: &OrdinalCompressedSet<T>) -> bool {
+        self.chunks
+            .iter()
+            .all(|chunk| match other.search(chunk.key) {
+                Ok(i) => chunk.container.is_disjoint(&other.chunks[i].container),
+                Err(_) => true,
+            })
+    }
+
+    /// Iterate over the elements of the set in ordinal order.
+    #[inline]
+    pub fn iter(&self) -> IterCompressed<'_, T> {
+        IterCompressed {
+            front: None,
+            chunks: self.chunks.iter(),
+            back: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Ordinal> Default for OrdinalCompressedSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ordinal> FromIterator<T> for OrdinalCompressedSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = OrdinalCompressedSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ordinal> Extend<T> for OrdinalCompressedSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> Clone for OrdinalCompressedSet<T> {
+    fn clone(&self) -> Self {
+        OrdinalCompressedSet {
+            chunks: self.chunks.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for OrdinalCompressedSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // Representation of equal chunks may differ.
+        self.chunks.len() == other.chunks.len()
+            && self.chunks.iter().zip(&other.chunks).all(|(a, b)| {
+                a.key == b.key
+                    && a.container.len() == b.container.len()
+                    && a.container.iter().eq(b.container.iter())
+            })
+    }
+}
+
+impl<T> Eq for OrdinalCompressedSet<T> {}
+
+impl<T: Ordinal + Debug> Debug for OrdinalCompressedSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Ordinal> IntoIterator for &'a OrdinalCompressedSet<T> {
+    type Item = T;
+    type IntoIter = IterCompressed<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over [`OrdinalCompressedSet`].
+pub struct IterCompressed<'a, T> {
+    front: Option<(usize, ContainerIter<'a>)>,
+    chunks: slice::Iter<'a, Chunk>,
+    back: Option<(usize, ContainerIter<'a>)>,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T: Ordinal> IterCompressed<'a, T> {
+    #[inline]
+    fn value(key: usize, low: u16) -> T {
+        // SAFETY: the set contains only ordinals returned by `ordinal` of inserted values.
+        unsafe { T::from_ordinal_unchecked((key << CHUNK_BITS) | low as usize) }
+    }
+}
+
+impl<'a, T: Ordinal> Iterator for IterCompressed<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, iter)) = &mut self.front {
+                if let Some(low) = iter.next() {
+                    return Some(Self::value(*key, low));
+                }
+                self.front = None;
+            }
+            match self.chunks.next() {
+                Some(chunk) => self.front = Some((chunk.key, chunk.container.iter())),
+                None => {
+                    let (key, iter) = self.back.as_mut()?;
+                    return iter.next().map(|low| Self::value(*key, low));
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T: Ordinal> DoubleEndedIterator for IterCompressed<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, iter)) = &mut self.back {
+                if let Some(low) = iter.next_back() {
+                    return Some(Self::value(*key, low));
+                }
+                self.back = None;
+            }
+            match self.chunks.next_back() {
+                Some(chunk) => self.back = Some((chunk.key, chunk.container.iter())),
+                None => {
+                    let (key, iter) = self.front.as_mut()?;
+                    return iter.next_back().map(|low| Self::value(*key, low));
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T> Clone for IterCompressed<'a, T> {
+    fn clone(&self) -> Self {
+        IterCompressed {
+            front: self.front.clone(),
+            chunks: self.chunks.clone(),
+            back: self.back.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Ordinal + Debug> Debug for IterCompressed<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::set::compressed::Container;
+    use crate::set::compressed::ARRAY_MAX;
+    use crate::set::compressed::ARRAY_MIN;
+    use crate::set::OrdinalCompressedSet;
+
+    /// Values in three chunks, so chunks are shared between sets.
+    fn value((chunk, low): (u8, u16)) -> u32 {
+        (chunk as u32 % 3) << 16 | low as u32
+    }
+
+    /// Values and ranges of values.
+    fn values(single: &[(u8, u16)], ranges: &[(u8, u16, u16)]) -> Vec<u32> {
+        let mut values = Vec::from_iter(single.iter().copied().map(value));
+        for (chunk, first, len) in ranges.iter().take(4) {
+            let first = value((*chunk, *first));
+            values.extend(first..=first.saturating_add(*len as u32 % 6000));
+        }
+        values
+    }
+
+    #[quickcheck]
+    fn qc(single: Vec<(u8, u16)>, ranges: Vec<(u8, u16, u16)>, remove: Vec<(u8, u16)>) {
+        let mut set = OrdinalCompressedSet::new();
+        let mut control = BTreeSet::new();
+        for value in values(&single, &ranges) {
+            assert_eq!(control.insert(value), set.insert(value));
+        }
+        for (i, value) in remove.into_iter().map(value).enumerate() {
+            assert_eq!(control.remove(&value), set.remove(&value));
+            if i == 3 {
+                set.optimize();
+            }
+        }
+        for value in values(&single, &[]) {
+            assert_eq!(control.contains(&value), set.contains(&value));
+        }
+        assert_eq!(control.len(), set.len());
+        assert!(set.iter().eq(control.iter().copied()));
+        assert!(set.iter().rev().eq(control.iter().rev().copied()));
+        set.optimize();
+        assert!(set.iter().eq(control.iter().copied()));
+        assert_eq!(set, OrdinalCompressedSet::from_iter(control));
+    }
+
+    #[quickcheck]
+    fn qc_set_algebra(
+        a_single: Vec<(u8, u16)>,
+        a_ranges: Vec<(u8, u16, u16)>,
+        b_single: Vec<(u8, u16)>,
+        b_ranges: Vec<(u8, u16, u16)>,
+        optimize: bool,
+    ) {
+        let control_a = BTreeSet::from_iter(values(&a_single, &a_ranges));
+        let control_b = BTreeSet::from_iter(values(&b_single, &b_ranges));
+        let mut set_a = OrdinalCompressedSet::from_iter(control_a.iter().copied());
+        let set_b = OrdinalCompressedSet::from_iter(control_b.iter().copied());
+        if optimize {
+            set_a.optimize();
+        }
+
+        assert_eq!(control_a.is_subset(&control_b), set_a.is_subset(&set_b));
+        assert_eq!(control_a.is_disjoint(&control_b), set_a.is_disjoint(&set_b));
+
+        let mut union = set_a.clone();
+        union.union_with(&set_b);
+        assert!(union.iter().eq(&control_a | &control_b));
+
+        let mut intersection = set_a.clone();
+        intersection.intersect_with(&set_b);
+        assert!(intersection.iter().eq(&control_a & &control_b));
+
+        let mut difference = set_a.clone();
+        difference.difference_with(&set_b);
+        assert!(difference.iter().eq(&control_a - &control_b));
+
+        let mut symmetric_difference = set_a.clone();
+        symmetric_difference.symmetric_difference_with(&set_b);
+        assert!(symmetric_difference.iter().eq(&control_a ^ &control_b));
+    }
+
+    #[test]
+    fn test_containers() {
+        let mut set: OrdinalCompressedSet<u32> = (0..10000).step_by(2).collect();
+        assert!(matches!(set.chunks[0].container, Container::Bitmap { .. }));
+        set.extend((1..10000).step_by(2));
+        set.optimize();
+        assert!(matches!(set.chunks[0].container, Container::Run { .. }));
+        assert!(set.remove(&100));
+        assert!(!set.contains(&100));
+        assert_eq!(9999, set.len());
+        for v in (0..10000).step_by(2) {
+            set.remove(&v);
+        }
+        assert!(matches!(set.chunks[0].container, Container::Bitmap { .. }));
+        for v in (1..10000).step_by(4) {
+            set.remove(&v);
+        }
+        assert!(matches!(set.chunks[0].container, Container::Bitmap { .. }));
+        for v in (3..10000).step_by(8) {
+            set.remove(&v);
+        }
+        assert!(matches!(set.chunks[0].container, Container::Array(_)));
+        assert!(set.iter().eq((7..10000).step_by(8)));
+    }
+
+    #[test]
+    fn test_array_bitmap_hysteresis() {
+        let mut set: OrdinalCompressedSet<u32> = (0..ARRAY_MAX as u32 * 2).step_by(2).collect();
+        assert!(matches!(set.chunks[0].container, Container::Array(_)));
+        assert!(set.insert(1));
+        assert!(matches!(set.chunks[0].container, Container::Bitmap { .. }));
+        // Stays a bitmap until it shrinks to `ARRAY_MIN`.
+        assert!(set.remove(&1));
+        assert!(set.remove(&0));
+        assert!(matches!(set.chunks[0].container, Container::Bitmap { .. }));
+        for v in (2..).step_by(2).take(ARRAY_MAX - 1 - ARRAY_MIN) {
+            assert!(set.remove(&v));
+        }
+        assert_eq!(ARRAY_MIN, set.len());
+        assert!(matches!(set.chunks[0].container, Container::Array(_)));
+    }
+
+    #[test]
+    fn test_bitmap_iter() {
+        let values = [0, 1, 63, 64, 1000, 65534, 65535];
+        let mut set: OrdinalCompressedSet<u16> = (5000..10000).collect();
+        set.extend(values);
+        set.difference_with(&(5000..10000).collect());
+        assert!(set.iter().eq(values));
+        assert!(set.iter().rev().eq(values.into_iter().rev()));
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use crate::set::iter::Iter;
+use crate::set::set_mut::OrdinalSetMut;
+use crate::set::set_ref::OrdinalSetRef;
+use crate::Domain;
+use crate::DynOrdinal;
+
+/// Set of keys of a [`Domain`] whose size is known only at runtime.
+///
+/// This is the same bit set as [`OrdinalSet`](crate::set::OrdinalSet),
+/// but the number of bits is taken from the domain.
+/// Storage is allocated when the set is created.
+///
+/// Set operations between sets of different domains panic.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::set::DynOrdinalSet;
+/// use ordinal_map::Domain;
+///
+/// let shards: Domain<usize> = Domain::new(100);
+/// let mut busy = DynOrdinalSet::new(shards);
+/// busy.insert(3);
+/// busy.insert(97);
+/// assert_eq!(vec![3, 97], busy.iter().collect::<Vec<_>>());
+/// ```
+pub struct DynOrdinalSet<T> {
+    domain: Domain<T>,
+    words: Box<[u64]>,
+}
+
+impl<T: DynOrdinal> DynOrdinalSet<T> {
+    /// Create a new empty set.
+    #[inline]
+    pub fn new(domain: Domain<T>) -> Self {
+        DynOrdinalSet {
+            domain,
+            words: vec![0; domain.size().div_ceil(u64::BITS as usize)].into_boxed_slice(),
+        }
+    }
+
+    /// Create a set containing every key of the domain.
+    pub fn all(domain: Domain<T>) -> Self {
+        let mut set = DynOrdinalSet::new(domain);
+        set.words.fill(u64::MAX);
+        let rem = domain.size() % u64::BITS as usize;
+        if let (Some(last), true) = (set.words.last_mut(), rem != 0) {
+            *last = (1 << rem) - 1;
+        }
+        set
+    }
+
+    /// The domain of the set.
+    #[inline]
+    pub fn domain(&self) -> Domain<T> {
+        self.domain
+    }
+
+    #[inline]
+    fn as_ref(&self) -> OrdinalSetRef<'_, T> {
+        OrdinalSetRef::new_dyn(&self.words, self.domain.size())
+    }
+
+    #[inline]
+    fn as_mut(&mut self) -> OrdinalSetMut<'_, T> {
+        OrdinalSetMut::new_dyn(&mut self.words, self.domain.size())
+    }
+
+    fn check_domain(&self, other: &DynOrdinalSet<T>) {
+        assert_eq!(self.domain, other.domain, "sets have different domains");
+    }
+
+    /// Check if the set contains an element.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.as_ref().contains(value)
+    }
+
+    /// Insert an element into the set, returning `true` if the element was not already present.
+    ///
+    /// # Panics
+    ///
+    /// If the element is not in the domain of the set.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.as_mut().insert(value)
+    }
+
+    /// Remove an element from the set, returning `true` if the element was present.
+    #[inline]
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.as_mut().remove(value)
+    }
+
+    /// Number of elements in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.as_ref().is_empty()
+    }
+
+    /// Remove all elements from the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.as_mut().clear();
+    }
+
+    /// Iterate over the elements of the set.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_ref().iter()
+    }
+
+    /// Add all elements of `other` to this set.
+    pub fn union_with(&mut self, other: &DynOrdinalSet<T>) {
+        self.check_domain(other);
+        self.as_mut().union_with(&other.as_ref());
+    }
+
+    /// Keep only elements which are also in `other`.
+    pub fn intersect_with(&mut self, other: &DynOrdinalSet<T>) {
+        self.check_domain(other);
+        self.as_mut().intersect_with(&other.as_ref());
+    }
+
+    /// Remove all elements which are in `other`.
+    pub fn difference_with(&mut self, other: &DynOrdinalSet<T>) {
+        self.check_domain(other);
+        self.as_mut().difference_with(&other.as_ref());
+    }
+
+    /// Keep elements which are in exactly one of the sets.
+    pub fn symmetric_difference_with(&mut self, other: &DynOrdinalSet<T>) {
+        self.check_domain(other);
+        self.as_mut().symmetric_difference_with(&other.as_ref());
+    }
+
+    /// Check if every element of this set is in `other`.
+    pub fn is_subset(&self, other: &DynOrdinalSet<T>) -> bool {
+        self.check_domain(other);
+        self.as_ref().is_subset(&other.as_ref())
+    }
+
+    /// Check if the sets have no elements in common.
+    pub fn is_disjoint(&self, other: &DynOrdinalSet<T>) -> bool {
+        self.check_domain(other);
+        self.as_ref().is_disjoint(&other.as_ref())
+    }
+}
+
+impl<T: DynOrdinal> Extend<T> for DynOrdinalSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> Clone for DynOrdinalSet<T> {
+    fn clone(&self) -> Self {
+        DynOrdinalSet {
+            domain: self.domain,
+            words: self.words.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for DynOrdinalSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.domain == other.domain && self.words == other.words
+    }
+}
+
+impl<T> Eq for DynOrdinalSet<T> {}
+
+impl<T: DynOrdinal + Debug> Debug for DynOrdinalSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.as_ref(), f)
+    }
+}
+
+impl<'a, T: DynOrdinal> IntoIterator for &'a DynOrdinalSet<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::set::DynOrdinalSet;
+    use crate::Domain;
+
+    #[quickcheck]
+    fn qc_set_algebra(size: u8, a: Vec<u8>, b: Vec<u8>) {
+        let domain: Domain<usize> = Domain::new(size as usize);
+        let a = Vec::from_iter(a.iter().map(|v| *v as usize).filter(|v| *v < domain.size()));
+        let b = Vec::from_iter(b.iter().map(|v| *v as usize).filter(|v| *v < domain.size()));
+
+        let mut set_a = DynOrdinalSet::new(domain);
+        set_a.extend(a.iter().copied());
+        let mut set_b = DynOrdinalSet::new(domain);
+        set_b.extend(b.iter().copied());
+        let control_a = BTreeSet::from_iter(a.iter().copied());
+        let control_b = BTreeSet::from_iter(b.iter().copied());
+
+        assert_eq!(control_a.len(), set_a.len());
+        assert!(set_a.iter().eq(control_a.iter().copied()));
+        assert_eq!(control_a.is_subset(&control_b), set_a.is_subset(&set_b));
+        assert_eq!(control_a.is_disjoint(&control_b), set_a.is_disjoint(&set_b));
+
+        let mut union = set_a.clone();
+        union.union_with(&set_b);
+        assert!(union.iter().eq(&control_a | &control_b));
+
+        let mut intersection = set_a.clone();
+        intersection.intersect_with(&set_b);
+        assert!(intersection.iter().eq(&control_a & &control_b));
+
+        let mut difference = set_a.clone();
+        difference.difference_with(&set_b);
+        assert!(difference.iter().eq(&control_a - &control_b));
+
+        let mut symmetric_difference = set_a.clone();
+        symmetric_difference.symmetric_difference_with(&set_b);
+        assert!(symmetric_difference.iter().eq(&control_a ^ &control_b));
+    }
+
+    #[test]
+    fn test_all() {
+        for size in [0, 1, 63, 64, 65, 130] {
+            let set = DynOrdinalSet::all(Domain::<usize>::new(size));
+            assert_eq!(size, set.len());
+            assert!(set.iter().eq(0..size));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "value is out of the set domain")]
+    fn test_insert_out_of_domain() {
+        DynOrdinalSet::new(Domain::<usize>::new(10)).insert(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "sets have different domains")]
+    fn test_different_domains() {
+        let mut a = DynOrdinalSet::new(Domain::<usize>::new(10));
+        a.union_with(&DynOrdinalSet::new(Domain::new(11)));
+    }
+}
@@ -1,51 +1,55 @@
 use std::fmt::Debug;
+use std::ops::Range;
 
 use crate::set::set_ref::OrdinalSetRef;
-use crate::Ordinal;
+use crate::DynOrdinal;
 
-/// Iterator over elements of [`OrdinalSet`](crate::set::OrdinalSet)
-/// or [`OrdinalArraySet`](crate::set::OrdinalArraySet).
+/// Iterator over elements of [`OrdinalSet`](crate::set::OrdinalSet),
+/// [`OrdinalArraySet`](crate::set::OrdinalArraySet)
+/// or [`DynOrdinalSet`](crate::set::DynOrdinalSet).
 pub struct Iter<'a, T> {
-    iter: crate::OrdinalValues<T>,
+    range: Range<usize>,
     set: OrdinalSetRef<'a, T>,
 }
 
-impl<'a, T: Ordinal> Iter<'a, T> {
+impl<'a, T: DynOrdinal> Iter<'a, T> {
     #[inline]
     pub(crate) fn new(set: OrdinalSetRef<'a, T>) -> Self {
         Iter {
-            iter: T::all_values(),
+            range: 0..set.size(),
             set,
         }
     }
 }
 
-impl<'a, T: Ordinal> Iterator for Iter<'a, T> {
+impl<'a, T: DynOrdinal> Iterator for Iter<'a, T> {
     type Item = T;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let next = self.iter.next()?;
-            if self.set.contains(&next) {
-                return Some(next);
+            let next = self.range.next()?;
+            if self.set.contains_ordinal(next) {
+                // SAFETY: `range` is within the domain of the set.
+                return Some(unsafe { T::from_dyn_ordinal_unchecked(next) });
             }
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.iter.len()))
+        (0, Some(self.range.len()))
     }
 }
 
-impl<'a, T: Ordinal> DoubleEndedIterator for Iter<'a, T> {
+impl<'a, T: DynOrdinal> DoubleEndedIterator for Iter<'a, T> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         loop {
-            let next = self.iter.next_back()?;
-            if self.set.contains(&next) {
-                return Some(next);
+            let next = self.range.next_back()?;
+            if self.set.contains_ordinal(next) {
+                // SAFETY: `range` is within the domain of the set.
+                return Some(unsafe { T::from_dyn_ordinal_unchecked(next) });
             }
         }
     }
@@ -55,13 +59,13 @@ impl<'a, T> Clone for Iter<'a, T> {
     #[inline]
     fn clone(&self) -> Self {
         Iter {
-            iter: self.iter.clone(),
+            range: self.range.clone(),
             set: self.set.clone(),
         }
     }
 }
 
-impl<'a, T: Ordinal + Debug> Debug for Iter<'a, T> {
+impl<'a, T: DynOrdinal + Debug> Debug for Iter<'a, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
@@ -2,15 +2,33 @@
 //! the [`Ordinal`](crate::Ordinal) trait.
 
 pub(crate) mod array;
+pub(crate) mod compressed;
+pub(crate) mod dyn_set;
 pub(crate) mod iter;
+pub(crate) mod patch;
+pub(crate) mod range_set;
 pub(crate) mod set;
 pub(crate) mod set64;
 pub(crate) mod set_mut;
 pub(crate) mod set_ref;
+pub(crate) mod sparse;
+pub(crate) mod stamped;
 
 pub use array::ordinal_array_set_s;
 pub use array::OrdinalArraySet;
+pub use compressed::IterCompressed;
+pub use compressed::OrdinalCompressedSet;
+pub use dyn_set::DynOrdinalSet;
 pub use iter::Iter;
+pub use patch::OrdinalSetPatch;
+pub use patch::SetChange;
+pub use range_set::IterRangeSet;
+pub use range_set::OrdinalRangeSet;
+pub use range_set::Ranges;
 pub use set::OrdinalSet;
 pub use set64::Iter64;
 pub use set64::OrdinalSet64;
+pub use sparse::IterSparse;
+pub use sparse::OrdinalSparseSet;
+pub use stamped::IterStamped;
+pub use stamped::OrdinalStampedSet;
@@ -0,0 +1,70 @@
+/// Change of an element between two [`OrdinalSet`](crate::set::OrdinalSet)s.
+///
+/// Produced by [`OrdinalSet::diff`](crate::set::OrdinalSet::diff).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetChange<T> {
+    /// Element is only in the new set.
+    Added(T),
+    /// Element is only in the old set.
+    Removed(T),
+}
+
+/// Elements to add to and remove from a set.
+///
+/// Created by [`OrdinalSet::patch`](crate::set::OrdinalSet::patch)
+/// and applied with [`OrdinalSet::apply_patch`](crate::set::OrdinalSet::apply_patch).
+/// With the `serde` feature the patch can be serialized.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::set::OrdinalSet;
+///
+/// let old = OrdinalSet::from_iter([1u8, 2, 3]);
+/// let new = OrdinalSet::from_iter([2u8, 3, 4]);
+/// let patch = old.patch(&new);
+/// assert_eq!(&[4], patch.added());
+/// assert_eq!(&[1], patch.removed());
+///
+/// let mut replica = old.clone();
+/// replica.apply_patch(patch);
+/// assert!(replica.iter().eq(new.iter()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrdinalSetPatch<T> {
+    added: Vec<T>,
+    removed: Vec<T>,
+}
+
+impl<T> OrdinalSetPatch<T> {
+    /// Create a patch from elements to add and elements to remove.
+    /// Elements are removed before added when the patch is applied.
+    pub fn new(added: Vec<T>, removed: Vec<T>) -> Self {
+        OrdinalSetPatch { added, removed }
+    }
+
+    /// Elements to add.
+    #[inline]
+    pub fn added(&self) -> &[T] {
+        &self.added
+    }
+
+    /// Elements to remove.
+    #[inline]
+    pub fn removed(&self) -> &[T] {
+        &self.removed
+    }
+
+    /// Check if the patch does not change anything.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Obtain elements to add and elements to remove.
+    #[inline]
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>) {
+        (self.added, self.removed)
+    }
+}
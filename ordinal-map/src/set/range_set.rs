@@ -0,0 +1,633 @@
+use std::cmp;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::iter;
+use std::marker::PhantomData;
+use std::ops::Bound;
+use std::ops::RangeBounds;
+use std::ops::RangeInclusive;
+use std::slice;
+
+use crate::Ordinal;
+
+/// Set of [`Ordinal`] values stored as a sorted list of disjoint ranges of ordinals.
+///
+/// Memory usage and the cost of set algebra depend on the number of ranges,
+/// not on the number of elements,
+/// which makes this set suitable for interval-shaped data like character classes,
+/// port ranges or IP blocks.
+/// Lookup is `O(log(ranges))`, insertion and removal are `O(ranges)` in the worst case.
+///
+/// Ranges are defined by the ordinal order,
+/// so for derived types they follow the declaration order.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::set::OrdinalRangeSet;
+///
+/// let mut word: OrdinalRangeSet<char> = OrdinalRangeSet::new();
+/// word.insert_range('a'..='z');
+/// word.insert_range('A'..='Z');
+/// word.insert_range('0'..='9');
+/// word.insert('_');
+/// assert!(word.contains(&'q'));
+/// assert!(!word.contains(&'-'));
+/// assert_eq!(
+///     vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')],
+///     word.ranges().collect::<Vec<_>>()
+/// );
+/// let not_word = word.complement();
+/// assert!(not_word.contains(&'-'));
+/// ```
+pub struct OrdinalRangeSet<T> {
+    /// Sorted, disjoint and non-adjacent inclusive ranges of ordinals `(first, last)`.
+    ranges: Vec<(usize, usize)>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Ordinal> OrdinalRangeSet<T> {
+    /// Create a new empty set.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalRangeSet {
+            ranges: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Inclusive range of ordinals, `None` if the range is empty.
+    fn ordinals(range: &impl RangeBounds<T>) -> Option<(usize, usize)> {
+        let first = match range.start_bound() {
+            Bound::Included(v) => v.ordinal(),
+            Bound::Excluded(v) => v.ordinal() + 1,
+            Bound::Unbounded => 0,
+        };
+        let last = match range.end_bound() {
+            Bound::Included(v) => v.ordinal(),
+            Bound::Excluded(v) => v.ordinal().checked_sub(1)?,
+            Bound::Unbounded => T::ORDINAL_SIZE.checked_sub(1)?,
+        };
+        (first <= last).then_some((first, last))
+    }
+
+    /// Check if the set contains an element.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        let ordinal = value.ordinal();
+        match self
+            .ranges
+            .partition_point(|r| r.0 <= ordinal)
+            .checked_sub(1)
+        {
+            Some(i) => ordinal <= self.ranges[i].1,
+            None => false,
+        }
+    }
+
+    fn insert_ordinals(&mut self, first: usize, last: usize) {
+        // Iterators decode every ordinal in the ranges without checks.
+        assert!(last < T::ORDINAL_SIZE, "ordinal is out of range");
+        // Ranges overlapping or adjacent to `first..=last`.
+        let lo = self
+            .ranges
+            .partition_point(|r| r.1.saturating_add(1) < first);
+        let hi = self
+            .ranges
+            .partition_point(|r| r.0 <= last.saturating_add(1));
+        let range = match self.ranges.get(lo..hi) {
+            Some([l, .., h]) | Some([l @ h]) => (cmp::min(first, l.0), cmp::max(last, h.1)),
+            _ => (first, last),
+        };
+        self.ranges.splice(lo..hi, [range]);
+    }
+
+    fn remove_ordinals(&mut self, first: usize, last: usize) {
+        // Ranges overlapping `first..=last`.
+        let lo = self.ranges.partition_point(|r| r.1 < first);
+        let hi = self.ranges.partition_point(|r| r.0 <= last);
+        if lo == hi {
+            return;
+        }
+        let left = (self.ranges[lo].0, first.wrapping_sub(1));
+        let right = (last.wrapping_add(1), self.ranges[hi - 1].1);
+        let left = (self.ranges[lo].0 < first).then_some(left);
+        let right = (last < self.ranges[hi - 1].1).then_some(right);
+        self.ranges.splice(lo..hi, left.into_iter().chain(right));
+    }
+
+    /// Insert an element into the set, returning `true` if the element was not already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        if self.contains(&value) {
+            return false;
+        }
+        let ordinal = value.ordinal();
+        self.insert_ordinals(ordinal, ordinal);
+        true
+    }
+
+    /// Remove an element from the set, returning `true` if the element was present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        if !self.contains(value) {
+            return false;
+        }
+        let ordinal = value.ordinal();
+        self.remove_ordinals(ordinal, ordinal);
+        true
+    }
+
+    /// Insert all elements of the range.
+    pub fn insert_range(&mut self, range: impl RangeBounds<T>) {
+        if let Some((first, last)) = Self::ordinals(&range) {
+            self.insert_ordinals(first, last);
+        }
+    }
+
+    /// Remove all elements of the range.
+    pub fn remove_range(&mut self, range: impl RangeBounds<T>) {
+        if let Some((first, last)) = Self::ordinals(&range) {
+            self.remove_ordinals(first, last);
+        }
+    }
+
+    /// Number of elements in the set. This is an `O(ranges)` operation.
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|(first, last)| last - first + 1)
+            .sum()
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Remove all elements from the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+
+    /// Set of all the values of `T` not in this set.
+    pub fn complement(&self) -> OrdinalRangeSet<T> {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut next = 0;
+        for (first, last) in &self.ranges {
+            if next < *first {
+                ranges.push((next, first - 1));
+            }
+            next = last + 1;
+        }
+        if next < T::ORDINAL_SIZE {
+            ranges.push((next, T::ORDINAL_SIZE - 1));
+        }
+        OrdinalRangeSet {
+            ranges,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Add all elements of `other` to this set.
+    pub fn union_with(&mut self, other: &OrdinalRangeSet<T>) {
+        let mut ranges: Vec<(usize, usize)> =
+            Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let mut a = self.ranges.iter().peekable();
+        let mut b = other.ranges.iter().peekable();
+        // Merge by the range start, coalescing overlapping and adjacent ranges.
+        while let Some(&(first, last)) = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) if y.0 < x.0 => b.next(),
+            (Some(_), _) => a.next(),
+            (None, _) => b.next(),
+        } {
+            match ranges.last_mut() {
+                Some(prev) if first <= prev.1 + 1 => prev.1 = cmp::max(prev.1, last),
+                _ => ranges.push((first, last)),
+            }
+        }
+        self.ranges = ranges;
+    }
+
+    /// Keep only elements which are also in `other`.
+    pub fn intersect_with(&mut self, other: &OrdinalRangeSet<T>) {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(x), Some(y)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let first = cmp::max(x.0, y.0);
+            let last = cmp::min(x.1, y.1);
+            if first <= last {
+                ranges.push((first, last));
+            }
+            if x.1 < y.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        self.ranges = ranges;
+    }
+
+    /// Remove all elements which are in `other`.
+    pub fn difference_with(&mut self, other: &OrdinalRangeSet<T>) {
+        self.intersect_with(&other.complement());
+    }
+
+    /// Keep elements which are in exactly one of the sets.
+    pub fn symmetric_difference_with(&mut self, other: &OrdinalRangeSet<T>) {
+        self.ranges = Self::symmetric_difference_ranges(&self.ranges, &other.ranges);
+    }
+
+    fn symmetric_difference_ranges(
+        a: &[(usize, usize)],
+        b: &[(usize, usize)],
+    ) -> Vec<(usize, usize)> {
+        let mut ranges = Vec::with_capacity(a.len() + b.len());
+        // Ordinals where membership changes: range starts and ordinals after range ends.
+        let mut a = a
+            .iter()
+            .flat_map(|(first, last)| [*first, last + 1])
+            .peekable();
+        let mut b = b
+            .iter()
+            .flat_map(|(first, last)| [*first, last + 1])
+            .peekable();
+        // Membership in exactly one set changes at every boundary of either set,
+        // except where both sets change at the same ordinal.
+        let mut start = None;
+        loop {
+            let boundary = match (a.peek().copied(), b.peek().copied()) {
+                (Some(x), Some(y)) if x == y => {
+                    a.next();
+                    b.next();
+                    continue;
+                }
+                (Some(x), Some(y)) if y < x => {
+                    b.next();
+                    y
+                }
+                (Some(x), _) => {
+                    a.next();
+                    x
+                }
+                (None, Some(y)) => {
+                    b.next();
+                    y
+                }
+                (None, None) => break,
+            };
+            match start.take() {
+                None => start = Some(boundary),
+                Some(first) => ranges.push((first, boundary - 1)),
+            }
+        }
+        ranges
+    }
+
+    /// Check if every element of this set is in `other`.
+    pub fn is_subset(&self, other: &OrdinalRangeSet<T>) -> bool {
+        self.ranges.iter().all(|(first, last)| {
+            match other
+                .ranges
+                .partition_point(|r| r.0 <= *first)
+                .checked_sub(1)
+            {
+                Some(i) => *last <= other.ranges[i].1,
+                None => false,
+            }
+        })
+    }
+
+    /// Check if the sets have no elements in common.
+    pub fn is_disjoint(&self, other: &OrdinalRangeSet<T>) -> bool {
+        let (mut i, mut j) = (0, 0);
+        while let (Some(x), Some(y)) = (self.ranges.get(i), other.ranges.get(j)) {
+            if cmp::max(x.0, y.0) <= cmp::min(x.1, y.1) {
+                return false;
+            }
+            if x.1 < y.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        true
+    }
+
+    /// Iterate over the elements of the set in ordinal order.
+    #[inline]
+    pub fn iter(&self) -> IterRangeSet<'_, T> {
+        IterRangeSet {
+            iter: self.ranges.iter().flat_map(|(first, last)| *first..=*last),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Iterate over the ranges of the set as `(first, last)` inclusive pairs in ordinal order.
+    /// Ranges are maximal: adjacent ranges are merged.
+    #[inline]
+    pub fn ranges(&self) -> Ranges<'_, T> {
+        Ranges {
+            iter: self.ranges.iter(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Ordinal> Default for OrdinalRangeSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ordinal> FromIterator<T> for OrdinalRangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = OrdinalRangeSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ordinal> Extend<T> for OrdinalRangeSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> Clone for OrdinalRangeSet<T> {
+    fn clone(&self) -> Self {
+        OrdinalRangeSet {
+            ranges: self.ranges.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for OrdinalRangeSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ranges == other.ranges
+    }
+}
+
+impl<T> Eq for OrdinalRangeSet<T> {}
+
+impl<T: Ordinal + Debug> Debug for OrdinalRangeSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.ranges().map(|(first, last)| first..=last))
+            .finish()
+    }
+}
+
+impl<'a, T: Ordinal> IntoIterator for &'a OrdinalRangeSet<T> {
+    type Item = T;
+    type IntoIter = IterRangeSet<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+type RangesFlatten<'a> = iter::FlatMap<
+    slice::Iter<'a, (usize, usize)>,
+    RangeInclusive<usize>,
+    fn(&(usize, usize)) -> RangeInclusive<usize>,
+>;
+
+/// Iterator over the elements of [`OrdinalRangeSet`].
+pub struct IterRangeSet<'a, T> {
+    iter: RangesFlatten<'a>,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T: Ordinal> Iterator for IterRangeSet<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordinal = self.iter.next()?;
+        // SAFETY: `insert_ordinals` checks that ranges are within `0..T::ORDINAL_SIZE`.
+        Some(unsafe { T::from_ordinal_unchecked(ordinal) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: Ordinal> DoubleEndedIterator for IterRangeSet<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ordinal = self.iter.next_back()?;
+        // SAFETY: `insert_ordinals` checks that ranges are within `0..T::ORDINAL_SIZE`.
+        Some(unsafe { T::from_ordinal_unchecked(ordinal) })
+    }
+}
+
+impl<'a, T> Clone for IterRangeSet<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        IterRangeSet {
+            iter: self.iter.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Ordinal + Debug> Debug for IterRangeSet<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Iterator over the ranges of [`OrdinalRangeSet`].
+///
+/// This iterator is created by [`OrdinalRangeSet::ranges`].
+pub struct Ranges<'a, T> {
+    iter: slice::Iter<'a, (usize, usize)>,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T: Ordinal> Ranges<'a, T> {
+    #[inline]
+    fn range((first, last): &(usize, usize)) -> (T, T) {
+        // SAFETY: `insert_ordinals` checks that ranges are within `0..T::ORDINAL_SIZE`.
+        unsafe {
+            (
+                T::from_ordinal_unchecked(*first),
+                T::from_ordinal_unchecked(*last),
+            )
+        }
+    }
+}
+
+impl<'a, T: Ordinal> Iterator for Ranges<'a, T> {
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Self::range)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: Ordinal> ExactSizeIterator for Ranges<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, T: Ordinal> DoubleEndedIterator for Ranges<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Self::range)
+    }
+}
+
+impl<'a, T> Clone for Ranges<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Ranges {
+            iter: self.iter.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Ordinal + Debug> Debug for Ranges<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::set::OrdinalRangeSet;
+
+    fn sets(ranges: &[(u8, u8)], remove: &[(u8, u8)]) -> (OrdinalRangeSet<u8>, BTreeSet<u8>) {
+        let mut set = OrdinalRangeSet::new();
+        let mut control = BTreeSet::new();
+        for (i, (a, b)) in ranges.iter().enumerate() {
+            if i % 3 == 0 {
+                assert_eq!(control.insert(*a), set.insert(*a));
+            } else {
+                set.insert_range(*a..*b);
+                control.extend(*a..*b);
+            }
+        }
+        for (i, (a, b)) in remove.iter().enumerate() {
+            if i % 3 == 0 {
+                assert_eq!(control.remove(a), set.remove(a));
+            } else {
+                set.remove_range(*a..=*b);
+                control.retain(|v| !(*a..=*b).contains(v));
+            }
+        }
+        (set, control)
+    }
+
+    #[quickcheck]
+    fn qc(ranges: Vec<(u8, u8)>, remove: Vec<(u8, u8)>, check: Vec<u8>) {
+        let (set, control) = sets(&ranges, &remove);
+        for value in &check {
+            assert_eq!(control.contains(value), set.contains(value));
+        }
+        assert_eq!(control.len(), set.len());
+        assert!(set.iter().eq(control.iter().copied()));
+        assert!(set.iter().rev().eq(control.iter().rev().copied()));
+        assert!(set
+            .ranges()
+            .flat_map(|(first, last)| first..=last)
+            .eq(control.iter().copied()));
+        // Ranges are maximal.
+        for ((_, last), (first, _)) in set.ranges().zip(set.ranges().skip(1)) {
+            assert!(last + 1 < first);
+        }
+        assert!(set
+            .complement()
+            .iter()
+            .eq((0..=255).filter(|v| !control.contains(v))));
+        assert_eq!(set, OrdinalRangeSet::from_iter(control));
+    }
+
+    #[quickcheck]
+    fn qc_set_algebra(a: Vec<(u8, u8)>, b: Vec<(u8, u8)>, remove: Vec<(u8, u8)>) {
+        let (set_a, control_a) = sets(&a, &remove);
+        let (set_b, control_b) = sets(&b, &[]);
+
+        assert_eq!(control_a.is_subset(&control_b), set_a.is_subset(&set_b));
+        assert_eq!(control_a.is_disjoint(&control_b), set_a.is_disjoint(&set_b));
+
+        let mut union = set_a.clone();
+        union.union_with(&set_b);
+        assert_eq!(OrdinalRangeSet::from_iter(&control_a | &control_b), union);
+
+        let mut intersection = set_a.clone();
+        intersection.intersect_with(&set_b);
+        assert_eq!(
+            OrdinalRangeSet::from_iter(&control_a & &control_b),
+            intersection
+        );
+
+        let mut difference = set_a.clone();
+        difference.difference_with(&set_b);
+        assert_eq!(
+            OrdinalRangeSet::from_iter(&control_a - &control_b),
+            difference
+        );
+
+        let mut symmetric_difference = set_a.clone();
+        symmetric_difference.symmetric_difference_with(&set_b);
+        assert_eq!(
+            OrdinalRangeSet::from_iter(&control_a ^ &control_b),
+            symmetric_difference
+        );
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let mut set: OrdinalRangeSet<u32> = OrdinalRangeSet::new();
+        set.insert_range(..);
+        assert_eq!(vec![(0, u32::MAX)], set.ranges().collect::<Vec<_>>());
+        set.remove_range(10..u32::MAX);
+        assert_eq!(
+            vec![(0, 9), (u32::MAX, u32::MAX)],
+            set.ranges().collect::<Vec<_>>()
+        );
+        assert_eq!(11, set.len());
+        assert_eq!("{0..=9, 4294967295..=4294967295}", format!("{:?}", set));
+        assert!(set.complement().complement() == set);
+    }
+
+    #[test]
+    fn test_shared_boundaries() {
+        let a: OrdinalRangeSet<u8> = (0..=10).chain(20..=255).collect();
+        let b: OrdinalRangeSet<u8> = (5..=10).chain(11..=19).chain(255..=255).collect();
+        assert!(!a.is_disjoint(&b));
+        assert!(a.is_disjoint(&OrdinalRangeSet::from_iter(11..=19)));
+        let mut symmetric_difference = a.clone();
+        symmetric_difference.symmetric_difference_with(&b);
+        assert_eq!(
+            vec![(0, 4), (11, 254)],
+            symmetric_difference.ranges().collect::<Vec<_>>()
+        );
+        symmetric_difference.symmetric_difference_with(&a.complement());
+        assert_eq!(
+            vec![(0, 4), (20, 254)],
+            symmetric_difference.ranges().collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,10 +1,14 @@
+use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::iter;
 use std::marker::PhantomData;
 use std::slice;
 
 use crate::set::iter::Iter;
+use crate::set::patch::OrdinalSetPatch;
+use crate::set::patch::SetChange;
 use crate::set::set_mut::OrdinalSetMut;
 use crate::set::set_ref::OrdinalSetRef;
 use crate::Ordinal;
@@ -59,11 +63,22 @@ impl<T: Ordinal> OrdinalSet<T> {
         self.as_ref().contains(ordinal)
     }
 
-    /// Insert an element into the set, returning `true` if the element was not already present.
+    /// Mutable access to the words, `None` if the storage is not allocated yet.
     #[inline]
-    pub fn insert(&mut self, ordinal: T) -> bool {
+    fn as_mut(&mut self) -> Option<OrdinalSetMut<'_, T>> {
         match (Self::IS_SMALL, &mut self.set) {
-            (true, SetImpl::Small(set)) => OrdinalSetMut::new(slice::from_mut(set)).insert(ordinal),
+            (true, SetImpl::Small(set)) => Some(OrdinalSetMut::new(slice::from_mut(set))),
+            (false, SetImpl::Large(set)) if set.is_empty() => None,
+            (false, SetImpl::Large(set)) => Some(OrdinalSetMut::new(set)),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Mutable access to the words, allocating the storage if needed.
+    #[inline]
+    fn as_mut_alloc(&mut self) -> OrdinalSetMut<'_, T> {
+        match (Self::IS_SMALL, &mut self.set) {
+            (true, SetImpl::Small(set)) => OrdinalSetMut::new(slice::from_mut(set)),
             (false, SetImpl::Large(set)) => {
                 if set.is_empty() {
                     *set = vec![
@@ -73,17 +88,139 @@ impl<T: Ordinal> OrdinalSet<T> {
                     ]
                     .into_boxed_slice();
                 }
-                OrdinalSetMut::new(set).insert(ordinal)
+                OrdinalSetMut::new(set)
             }
             _ => unreachable!(),
         }
     }
 
+    /// Insert an element into the set, returning `true` if the element was not already present.
+    #[inline]
+    pub fn insert(&mut self, ordinal: T) -> bool {
+        self.as_mut_alloc().insert(ordinal)
+    }
+
+    /// Remove an element from the set, returning `true` if the element was present.
+    #[inline]
+    pub fn remove(&mut self, ordinal: &T) -> bool {
+        match self.as_mut() {
+            Some(mut set) => set.remove(ordinal),
+            None => false,
+        }
+    }
+
+    /// Number of elements in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.as_ref().is_empty()
+    }
+
+    /// Remove all elements from the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        if let Some(mut set) = self.as_mut() {
+            set.clear();
+        }
+    }
+
+    /// Add all elements of `other` to this set.
+    pub fn union_with(&mut self, other: &OrdinalSet<T>) {
+        if !other.is_empty() {
+            self.as_mut_alloc().union_with(&other.as_ref());
+        }
+    }
+
+    /// Keep only elements which are also in `other`.
+    pub fn intersect_with(&mut self, other: &OrdinalSet<T>) {
+        if let Some(mut set) = self.as_mut() {
+            set.intersect_with(&other.as_ref());
+        }
+    }
+
+    /// Remove all elements which are in `other`.
+    pub fn difference_with(&mut self, other: &OrdinalSet<T>) {
+        if let Some(mut set) = self.as_mut() {
+            set.difference_with(&other.as_ref());
+        }
+    }
+
+    /// Keep elements which are in exactly one of the sets.
+    pub fn symmetric_difference_with(&mut self, other: &OrdinalSet<T>) {
+        if !other.is_empty() {
+            self.as_mut_alloc()
+                .symmetric_difference_with(&other.as_ref());
+        }
+    }
+
+    /// Check if every element of this set is in `other`.
+    pub fn is_subset(&self, other: &OrdinalSet<T>) -> bool {
+        self.as_ref().is_subset(&other.as_ref())
+    }
+
+    /// Check if the sets have no elements in common.
+    pub fn is_disjoint(&self, other: &OrdinalSet<T>) -> bool {
+        self.as_ref().is_disjoint(&other.as_ref())
+    }
+
     /// Iterate over the elements of the set.
     #[inline]
     pub fn iter(&self) -> Iter<'_, T> {
         self.as_ref().iter()
     }
+
+    /// Iterate over the elements which are added or removed in `other`
+    /// compared to this set, in ordinal order.
+    pub fn diff<'a>(&'a self, other: &'a OrdinalSet<T>) -> impl Iterator<Item = SetChange<T>> + 'a {
+        let mut old = self.iter().peekable();
+        let mut new = other.iter().peekable();
+        iter::from_fn(move || loop {
+            let order = match (old.peek(), new.peek()) {
+                (Some(a), Some(b)) => a.ordinal().cmp(&b.ordinal()),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => return None,
+            };
+            match order {
+                Ordering::Less => return old.next().map(SetChange::Removed),
+                Ordering::Greater => return new.next().map(SetChange::Added),
+                Ordering::Equal => {
+                    old.next();
+                    new.next();
+                }
+            }
+        })
+    }
+
+    /// Create a patch which turns this set into `other` when applied
+    /// with [`apply_patch`](OrdinalSet::apply_patch).
+    pub fn patch(&self, other: &OrdinalSet<T>) -> OrdinalSetPatch<T> {
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        for change in self.diff(other) {
+            match change {
+                SetChange::Added(v) => added.push(v),
+                SetChange::Removed(v) => removed.push(v),
+            }
+        }
+        OrdinalSetPatch::new(added, removed)
+    }
+
+    /// Remove the removed elements of the patch, then insert the added elements.
+    pub fn apply_patch(&mut self, patch: OrdinalSetPatch<T>) {
+        let (added, removed) = patch.into_parts();
+        for value in &removed {
+            self.remove(value);
+        }
+        for value in added {
+            self.insert(value);
+        }
+    }
 }
 
 impl<T: Ordinal> Default for OrdinalSet<T> {
@@ -119,10 +256,12 @@ impl<T: Ordinal + Debug> Debug for OrdinalSet<T> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
     use std::collections::HashSet;
     use std::num::NonZeroU16;
 
     use crate::set::OrdinalSet;
+    use crate::set::SetChange;
     use crate::tests::util::Example4;
 
     #[quickcheck]
@@ -170,4 +309,60 @@ mod tests {
         values.dedup();
         set.iter().collect::<Vec<_>>() == values
     }
+
+    #[quickcheck]
+    fn qc_set_algebra(a: Vec<NonZeroU16>, b: Vec<NonZeroU16>, remove: Vec<NonZeroU16>) {
+        let mut set_a = OrdinalSet::from_iter(a.iter().copied());
+        let set_b = OrdinalSet::from_iter(b.iter().copied());
+        let mut control_a: HashSet<NonZeroU16> = HashSet::from_iter(a.iter().copied());
+        let control_b: HashSet<NonZeroU16> = HashSet::from_iter(b.iter().copied());
+
+        for value in &remove {
+            assert_eq!(control_a.remove(value), set_a.remove(value));
+        }
+        assert_eq!(control_a.len(), set_a.len());
+        assert_eq!(control_a.is_subset(&control_b), set_a.is_subset(&set_b));
+        assert_eq!(control_a.is_disjoint(&control_b), set_a.is_disjoint(&set_b));
+
+        let check = |set: OrdinalSet<NonZeroU16>, control: HashSet<NonZeroU16>| {
+            let mut control = Vec::from_iter(control);
+            control.sort();
+            assert_eq!(control, set.iter().collect::<Vec<_>>());
+        };
+
+        let mut union = set_a.clone();
+        union.union_with(&set_b);
+        check(union, &control_a | &control_b);
+
+        let mut intersection = set_a.clone();
+        intersection.intersect_with(&set_b);
+        check(intersection, &control_a & &control_b);
+
+        let mut difference = set_a.clone();
+        difference.difference_with(&set_b);
+        check(difference, &control_a - &control_b);
+
+        let mut symmetric_difference = set_a.clone();
+        symmetric_difference.symmetric_difference_with(&set_b);
+        check(symmetric_difference, &control_a ^ &control_b);
+
+        set_a.clear();
+        assert!(set_a.is_empty());
+    }
+
+    #[quickcheck]
+    fn qc_diff_patch(old: BTreeSet<NonZeroU16>, new: BTreeSet<NonZeroU16>) {
+        let old_set = OrdinalSet::from_iter(old.iter().copied());
+        let new_set = OrdinalSet::from_iter(new.iter().copied());
+
+        let mut expected = Vec::new();
+        expected.extend(old.difference(&new).map(|v| SetChange::Removed(*v)));
+        expected.extend(new.difference(&old).map(|v| SetChange::Added(*v)));
+        expected.sort_by_key(|(SetChange::Added(v) | SetChange::Removed(v))| *v);
+        assert_eq!(expected, old_set.diff(&new_set).collect::<Vec<_>>());
+
+        let mut patched = old_set.clone();
+        patched.apply_patch(old_set.patch(&new_set));
+        assert!(patched.iter().eq(new.iter().copied()));
+    }
 }
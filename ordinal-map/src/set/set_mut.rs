@@ -2,10 +2,13 @@ use std::marker::PhantomData;
 
 use crate::set::array::ordinal_array_set_s;
 use crate::set::set_ref::OrdinalSetRef;
+use crate::DynOrdinal;
 use crate::Ordinal;
 
 pub(crate) struct OrdinalSetMut<'a, T> {
     words: &'a mut [u64],
+    /// Number of keys in the domain.
+    size: usize,
     _phantom: PhantomData<T>,
 }
 
@@ -13,22 +16,71 @@ impl<'a, T: Ordinal> OrdinalSetMut<'a, T> {
     #[inline]
     pub(crate) fn new(words: &'a mut [u64]) -> Self {
         debug_assert!(words.len() == ordinal_array_set_s::<T>());
+        OrdinalSetMut::new_dyn(words, T::ORDINAL_SIZE)
+    }
+}
+
+impl<'a, T: DynOrdinal> OrdinalSetMut<'a, T> {
+    #[inline]
+    pub(crate) fn new_dyn(words: &'a mut [u64], size: usize) -> Self {
+        debug_assert!(words.len() == size.div_ceil(u64::BITS as usize));
         OrdinalSetMut {
             words,
+            size,
             _phantom: PhantomData,
         }
     }
 
     #[inline]
     fn as_ref(&self) -> OrdinalSetRef<'_, T> {
-        OrdinalSetRef::new(self.words)
+        OrdinalSetRef::new_dyn(self.words, self.size)
     }
 
     #[inline]
     pub(crate) fn insert(&mut self, value: T) -> bool {
-        let prev = self.as_ref().contains(&value);
-        self.words[value.ordinal() / u64::BITS as usize] |=
-            1 << (value.ordinal() % u64::BITS as usize);
+        let ordinal = value.dyn_ordinal();
+        assert!(ordinal < self.size, "value is out of the set domain");
+        let prev = self.as_ref().contains_ordinal(ordinal);
+        self.words[ordinal / u64::BITS as usize] |= 1 << (ordinal % u64::BITS as usize);
         !prev
     }
+
+    #[inline]
+    pub(crate) fn remove(&mut self, value: &T) -> bool {
+        let ordinal = value.dyn_ordinal();
+        let prev = self.as_ref().contains_ordinal(ordinal);
+        if prev {
+            self.words[ordinal / u64::BITS as usize] &= !(1 << (ordinal % u64::BITS as usize));
+        }
+        prev
+    }
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub(crate) fn union_with(&mut self, other: &OrdinalSetRef<T>) {
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word |= other.word(i);
+        }
+    }
+
+    pub(crate) fn intersect_with(&mut self, other: &OrdinalSetRef<T>) {
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word &= other.word(i);
+        }
+    }
+
+    pub(crate) fn difference_with(&mut self, other: &OrdinalSetRef<T>) {
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word &= !other.word(i);
+        }
+    }
+
+    pub(crate) fn symmetric_difference_with(&mut self, other: &OrdinalSetRef<T>) {
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word ^= other.word(i);
+        }
+    }
 }
@@ -4,34 +4,81 @@ use std::marker::PhantomData;
 
 use crate::set::array::ordinal_array_set_s;
 use crate::set::Iter;
+use crate::DynOrdinal;
 use crate::Ordinal;
 
+/// Words of a bit set. Words may be empty, which is the same as all zeros.
 pub(crate) struct OrdinalSetRef<'a, T> {
     words: &'a [u64],
+    /// Number of keys in the domain.
+    size: usize,
     _phantom: PhantomData<T>,
 }
 
 impl<'a, T: Ordinal> OrdinalSetRef<'a, T> {
     #[inline]
-    pub(crate) fn iter(self) -> Iter<'a, T> {
-        Iter::new(self)
+    pub(crate) fn new(words: &'a [u64]) -> Self {
+        debug_assert!(words.len() == 0 || words.len() == ordinal_array_set_s::<T>());
+        OrdinalSetRef::new_dyn(words, T::ORDINAL_SIZE)
     }
+}
 
+impl<'a, T: DynOrdinal> OrdinalSetRef<'a, T> {
     #[inline]
-    pub(crate) fn new(words: &'a [u64]) -> Self {
-        debug_assert!(words.len() == 0 || words.len() == ordinal_array_set_s::<T>());
+    pub(crate) fn new_dyn(words: &'a [u64], size: usize) -> Self {
+        debug_assert!(words.len() == 0 || words.len() == size.div_ceil(u64::BITS as usize));
         OrdinalSetRef {
             words,
+            size,
             _phantom: PhantomData,
         }
     }
 
+    #[inline]
+    pub(crate) fn iter(self) -> Iter<'a, T> {
+        Iter::new(self)
+    }
+
+    #[inline]
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+
     #[inline]
     pub(crate) fn contains(&self, ordinal: &T) -> bool {
-        let Some(word) = self.words.get(ordinal.ordinal() / u64::BITS as usize) else {
+        self.contains_ordinal(ordinal.dyn_ordinal())
+    }
+
+    #[inline]
+    pub(crate) fn contains_ordinal(&self, ordinal: usize) -> bool {
+        let Some(word) = self.words.get(ordinal / u64::BITS as usize) else {
             return false;
         };
-        word & (1 << (ordinal.ordinal() % u64::BITS as usize)) != 0
+        word & (1 << (ordinal % u64::BITS as usize)) != 0
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    /// Word by index, missing words are zeros.
+    #[inline]
+    pub(crate) fn word(&self, i: usize) -> u64 {
+        self.words.get(i).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn is_subset(&self, other: &OrdinalSetRef<T>) -> bool {
+        (0..self.words.len()).all(|i| self.word(i) & !other.word(i) == 0)
+    }
+
+    pub(crate) fn is_disjoint(&self, other: &OrdinalSetRef<T>) -> bool {
+        (0..self.words.len()).all(|i| self.word(i) & other.word(i) == 0)
     }
 }
 
@@ -39,12 +86,13 @@ impl<'a, T> Clone for OrdinalSetRef<'a, T> {
     fn clone(&self) -> Self {
         OrdinalSetRef {
             words: self.words,
+            size: self.size,
             _phantom: PhantomData,
         }
     }
 }
 
-impl<'a, T: Ordinal + Debug> Debug for OrdinalSetRef<'a, T> {
+impl<'a, T: DynOrdinal + Debug> Debug for OrdinalSetRef<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_set().entries(self.clone().iter()).finish()
     }
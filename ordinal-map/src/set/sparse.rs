@@ -0,0 +1,291 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+use std::slice;
+
+use crate::Ordinal;
+
+/// Set of [`Ordinal`] values with constant time insert, remove, lookup and clear
+/// (Briggs–Torczon sparse set).
+///
+/// The set keeps a dense vector of the ordinals of its elements
+/// and a sparse array mapping each ordinal to its position in the dense vector.
+/// Clearing only truncates the dense vector,
+/// so this set is suitable for worklists which are cleared often.
+///
+/// Elements are iterated in insertion order.
+/// [`remove`](OrdinalSparseSet::remove) moves the last element into the position
+/// of the removed element, so it changes the order.
+///
+/// The sparse array is allocated on the first insertion
+/// and takes `T::ORDINAL_SIZE` words of memory,
+/// so this set is intended for reasonably small ordinal types.
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::set::OrdinalSparseSet;
+///
+/// let mut worklist: OrdinalSparseSet<u8> = OrdinalSparseSet::new();
+/// worklist.insert(30);
+/// worklist.insert(10);
+/// worklist.insert(20);
+/// assert!(!worklist.insert(10));
+/// assert_eq!(vec![30, 10, 20], worklist.iter().collect::<Vec<_>>());
+/// assert_eq!(Some(20), worklist.pop());
+/// worklist.clear();
+/// assert!(worklist.is_empty());
+/// ```
+pub struct OrdinalSparseSet<T> {
+    /// Ordinals of the elements in insertion order.
+    dense: Vec<usize>,
+    /// Position in `dense` for each ordinal. Entries of absent ordinals are arbitrary.
+    /// Empty until the first insertion.
+    sparse: Box<[usize]>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Ordinal> OrdinalSparseSet<T> {
+    /// Create a new empty set. Does not allocate.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalSparseSet {
+            dense: Vec::new(),
+            sparse: Box::new([]),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Position of the ordinal in the dense vector.
+    #[inline]
+    fn position(&self, ordinal: usize) -> Option<usize> {
+        let index = *self.sparse.get(ordinal)?;
+        (self.dense.get(index) == Some(&ordinal)).then_some(index)
+    }
+
+    /// Check if the set contains an element.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.position(value.ordinal()).is_some()
+    }
+
+    /// Insert an element into the set, returning `true` if the element was not already present.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        let ordinal = value.ordinal();
+        if self.position(ordinal).is_some() {
+            return false;
+        }
+        if self.sparse.is_empty() {
+            self.sparse = vec![0; T::ORDINAL_SIZE].into_boxed_slice();
+        }
+        self.sparse[ordinal] = self.dense.len();
+        self.dense.push(ordinal);
+        true
+    }
+
+    /// Remove an element from the set, returning `true` if the element was present.
+    ///
+    /// The last element takes the position of the removed element in the iteration order.
+    #[inline]
+    pub fn remove(&mut self, value: &T) -> bool {
+        let Some(index) = self.position(value.ordinal()) else {
+            return false;
+        };
+        self.dense.swap_remove(index);
+        if let Some(moved) = self.dense.get(index) {
+            self.sparse[*moved] = index;
+        }
+        true
+    }
+
+    /// Remove and return the most recently inserted element.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let ordinal = self.dense.pop()?;
+        // SAFETY: `dense` contains only ordinals returned by `ordinal` of inserted values.
+        Some(unsafe { T::from_ordinal_unchecked(ordinal) })
+    }
+
+    /// Number of elements in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+
+    /// Remove all elements from the set. This is a constant time operation.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.dense.clear();
+    }
+
+    /// Iterate over the elements of the set in insertion order.
+    #[inline]
+    pub fn iter(&self) -> IterSparse<'_, T> {
+        IterSparse {
+            iter: self.dense.iter(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Ordinal> Default for OrdinalSparseSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ordinal> FromIterator<T> for OrdinalSparseSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = OrdinalSparseSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ordinal> Extend<T> for OrdinalSparseSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> Clone for OrdinalSparseSet<T> {
+    fn clone(&self) -> Self {
+        OrdinalSparseSet {
+            dense: self.dense.clone(),
+            sparse: self.sparse.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Ordinal + Debug> Debug for OrdinalSparseSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Ordinal> IntoIterator for &'a OrdinalSparseSet<T> {
+    type Item = T;
+    type IntoIter = IterSparse<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the elements of [`OrdinalSparseSet`] in insertion order.
+pub struct IterSparse<'a, T> {
+    iter: slice::Iter<'a, usize>,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T: Ordinal> Iterator for IterSparse<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordinal = self.iter.next()?;
+        // SAFETY: `dense` contains only ordinals returned by `ordinal` of inserted values.
+        Some(unsafe { T::from_ordinal_unchecked(*ordinal) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: Ordinal> ExactSizeIterator for IterSparse<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, T: Ordinal> DoubleEndedIterator for IterSparse<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ordinal = self.iter.next_back()?;
+        // SAFETY: `dense` contains only ordinals returned by `ordinal` of inserted values.
+        Some(unsafe { T::from_ordinal_unchecked(*ordinal) })
+    }
+}
+
+impl<'a, T> Clone for IterSparse<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        IterSparse {
+            iter: self.iter.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Ordinal + Debug> Debug for IterSparse<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::set::OrdinalSparseSet;
+
+    #[quickcheck]
+    fn qc(values: Vec<(u8, bool)>, check: Vec<u8>) {
+        let mut set: OrdinalSparseSet<u8> = OrdinalSparseSet::new();
+        let mut control: Vec<u8> = Vec::new();
+        for (value, insert) in values {
+            if insert {
+                let inserted = !control.contains(&value);
+                if inserted {
+                    control.push(value);
+                }
+                assert_eq!(inserted, set.insert(value));
+            } else {
+                let position = control.iter().position(|v| *v == value);
+                if let Some(position) = position {
+                    control.swap_remove(position);
+                }
+                assert_eq!(position.is_some(), set.remove(&value));
+            }
+            assert_eq!(control.len(), set.len());
+        }
+        for value in &check {
+            assert_eq!(control.contains(value), set.contains(value));
+        }
+        assert_eq!(control, set.iter().collect::<Vec<_>>());
+        assert!(set.iter().rev().eq(control.iter().rev().copied()));
+        let control_set: BTreeSet<u8> = control.iter().copied().collect();
+        assert_eq!(control_set, set.iter().collect::<BTreeSet<_>>());
+        assert_eq!(control.last().copied(), set.clone().pop());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut set: OrdinalSparseSet<u16> = OrdinalSparseSet::new();
+        assert!(!set.contains(&7));
+        for round in 0..10 {
+            set.clear();
+            set.extend([round, 1000, round + 1]);
+            assert!(!set.contains(&(round + 2)));
+            assert!(set.contains(&1000));
+            assert_eq!(vec![round, 1000, round + 1], set.iter().collect::<Vec<_>>());
+        }
+        assert_eq!("{9, 1000, 10}", format!("{:?}", set));
+    }
+}
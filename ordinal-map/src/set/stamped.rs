@@ -0,0 +1,264 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+use std::slice;
+
+use crate::map::enumerate::OrdinalEnumerate;
+use crate::Ordinal;
+
+/// Set of [`Ordinal`] values which can be cleared in constant time.
+///
+/// Each slot stores the generation in which the element was inserted,
+/// and [`clear`](OrdinalStampedSet::clear) increments the generation.
+/// This makes the set suitable for "visited" sets reused across many queries.
+/// Once every `u32::MAX` clears the generation wraps around,
+/// and that clear resets all the slots.
+///
+/// The slots are allocated on the first insertion,
+/// iteration visits every slot.
+/// See also [`OrdinalStampedMap`](crate::map::OrdinalStampedMap).
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::set::OrdinalStampedSet;
+///
+/// let mut visited: OrdinalStampedSet<u16> = OrdinalStampedSet::new();
+/// for start in 0..3 {
+///     visited.clear();
+///     assert!(visited.insert(start));
+///     assert!(visited.insert(start + 1));
+///     assert!(!visited.insert(start));
+///     assert_eq!(vec![start, start + 1], visited.iter().collect::<Vec<_>>());
+/// }
+/// ```
+pub struct OrdinalStampedSet<T> {
+    /// Generation in which the element was inserted.
+    stamps: Box<[u32]>,
+    /// Current generation, never zero. Stamp zero is never present.
+    generation: u32,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Ordinal> OrdinalStampedSet<T> {
+    /// Create a new empty set. Does not allocate.
+    #[inline]
+    pub fn new() -> Self {
+        OrdinalStampedSet {
+            stamps: Box::new([]),
+            generation: 1,
+            len: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Check if the set contains an element.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.stamps.get(value.ordinal()) == Some(&self.generation)
+    }
+
+    /// Number of elements in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert an element into the set, returning `true` if the element was not already present.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        if self.stamps.is_empty() {
+            self.stamps = vec![0; T::ORDINAL_SIZE].into_boxed_slice();
+        }
+        let stamp = &mut self.stamps[value.ordinal()];
+        if *stamp == self.generation {
+            return false;
+        }
+        *stamp = self.generation;
+        self.len += 1;
+        true
+    }
+
+    /// Remove an element from the set, returning `true` if the element was present.
+    #[inline]
+    pub fn remove(&mut self, value: &T) -> bool {
+        match self.stamps.get_mut(value.ordinal()) {
+            Some(stamp) if *stamp == self.generation => {
+                *stamp = 0;
+                self.len -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Remove all elements from the set.
+    ///
+    /// This is a constant time operation except once every `u32::MAX` calls
+    /// when the generation counter wraps around.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+        match self.generation.checked_add(1) {
+            Some(generation) => self.generation = generation,
+            None => {
+                self.stamps.fill(0);
+                self.generation = 1;
+            }
+        }
+    }
+
+    /// Iterate over the elements of the set in ordinal order.
+    #[inline]
+    pub fn iter(&self) -> IterStamped<'_, T> {
+        IterStamped {
+            iter: OrdinalEnumerate::new(self.stamps.iter(), 0),
+            generation: self.generation,
+        }
+    }
+}
+
+impl<T: Ordinal> Default for OrdinalStampedSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ordinal> FromIterator<T> for OrdinalStampedSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = OrdinalStampedSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ordinal> Extend<T> for OrdinalStampedSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> Clone for OrdinalStampedSet<T> {
+    fn clone(&self) -> Self {
+        OrdinalStampedSet {
+            stamps: self.stamps.clone(),
+            generation: self.generation,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Ordinal + Debug> Debug for OrdinalStampedSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Ordinal> IntoIterator for &'a OrdinalStampedSet<T> {
+    type Item = T;
+    type IntoIter = IterStamped<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the elements of [`OrdinalStampedSet`].
+pub struct IterStamped<'a, T> {
+    iter: OrdinalEnumerate<T, slice::Iter<'a, u32>>,
+    generation: u32,
+}
+
+impl<'a, T: Ordinal> Iterator for IterStamped<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let generation = self.generation;
+        self.iter
+            .find_map(|(v, stamp)| (*stamp == generation).then_some(v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, T: Ordinal> DoubleEndedIterator for IterStamped<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let generation = self.generation;
+        self.iter
+            .by_ref()
+            .rev()
+            .find_map(|(v, stamp)| (*stamp == generation).then_some(v))
+    }
+}
+
+impl<'a, T> Clone for IterStamped<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        IterStamped {
+            iter: self.iter.clone(),
+            generation: self.generation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::set::OrdinalStampedSet;
+
+    #[quickcheck]
+    fn qc(ops: Vec<(u8, bool)>, clear_every: u8) {
+        let mut set: OrdinalStampedSet<u8> = OrdinalStampedSet::new();
+        let mut control: BTreeSet<u8> = BTreeSet::new();
+        for (i, (value, insert)) in ops.into_iter().enumerate() {
+            if clear_every != 0 && i % clear_every as usize == 0 {
+                set.clear();
+                control.clear();
+            }
+            if insert {
+                assert_eq!(control.insert(value), set.insert(value));
+            } else {
+                assert_eq!(control.remove(&value), set.remove(&value));
+            }
+            assert_eq!(control.len(), set.len());
+            assert_eq!(control.contains(&value), set.contains(&value));
+        }
+        assert!(set.iter().eq(control.iter().copied()));
+        assert!(set.iter().rev().eq(control.iter().rev().copied()));
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut set: OrdinalStampedSet<u8> = OrdinalStampedSet::new();
+        set.insert(1);
+        set.generation = u32::MAX;
+        set.insert(2);
+        set.clear();
+        assert_eq!(1, set.generation);
+        // Stale stamps must not come back to life with the reused generation.
+        assert!(!set.contains(&1));
+        assert!(!set.contains(&2));
+        assert!(set.is_empty());
+        set.insert(3);
+        assert_eq!("{3}", format!("{:?}", set));
+    }
+}
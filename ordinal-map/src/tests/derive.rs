@@ -1,9 +1,11 @@
 #![cfg(test)]
 
 use std::convert::Infallible;
+use std::net::Ipv6Addr;
 
 use crate as ordinal_map;
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 #[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
 struct UnitStruct;
@@ -64,21 +66,432 @@ struct EmptyStruct {
     _infallible: Infallible,
 }
 
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+struct Digit(#[ordinal(range = 0..10)] u8);
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+struct RangeRecordStruct {
+    #[ordinal(range = -1..=1)]
+    a: i32,
+    b: bool,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+enum RangeEnum {
+    A,
+    B(#[ordinal(range = 1..=3)] u64),
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+enum OrderedEnum {
+    #[ordinal(order = 20)]
+    A,
+    #[ordinal(order = 0)]
+    B(bool),
+    #[ordinal(order = 10)]
+    C,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(reverse)]
+enum ReverseEnum {
+    A,
+    B(bool),
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(reverse)]
+struct ReverseStruct(bool, Option<()>);
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(reverse)]
+struct ReverseEmptyStruct(Infallible);
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(field_order = [b, a])]
+struct FieldOrderStruct {
+    a: bool,
+    b: Option<()>,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(field_order = [1, 0])]
+struct FieldOrderTupleStruct(bool, Option<()>);
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+enum FieldOrderEnum {
+    A,
+    #[ordinal(field_order = [b, a])]
+    B {
+        a: bool,
+        b: Option<()>,
+    },
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+#[ordinal(discriminant)]
+#[repr(u8)]
+enum DiscriminantEnum {
+    A = 1,
+    B,
+    C = 0,
+    D = 3,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+#[ordinal(discriminant(dense))]
+#[repr(i16)]
+enum DenseDiscriminantEnum {
+    A = 10,
+    B = -3,
+    C = 1 << 8,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(discriminant, reverse)]
+enum ReverseDiscriminantEnum {
+    A = 1,
+    B = 0,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+#[ordinal(discriminant)]
+enum EmptyDiscriminantEnum {}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+enum PayloadEnum {
+    A(bool),
+    B,
+    C(Infallible),
+    D(Option<bool>),
+    E {},
+    F(bool, bool),
+    G(),
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+enum UninhabitedLastEnum {
+    A(bool),
+    B(Infallible),
+}
+
+macro_rules! macro_struct {
+    ($name:ident, $a:ty, $b:ty) => {
+        #[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+        struct $name($a, $b);
+    };
+}
+
+macro_struct!(MacroStruct, bool, Option<()>);
+
+macro_rules! macro_enum {
+    ($name:ident, $($variant:ident($t:ty)),*) => {
+        #[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+        enum $name {
+            $($variant($t),)*
+        }
+    };
+}
+
+macro_enum!(MacroEnum, A(bool), B(Option<()>));
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(names)]
+enum NamesEnum {
+    Red,
+    #[ordinal(rename = "green")]
+    Green,
+    DarkBlue,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(names, rename_all = "kebab-case", case_insensitive, reverse)]
+enum RenameAllEnum {
+    #[ordinal(order = 1)]
+    LogLevel,
+    #[ordinal(order = 0)]
+    Http2,
+    #[ordinal(order = 2, rename = "X")]
+    Other,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(discriminant(dense), names, rename_all = "snake_case")]
+enum DenseNamesEnum {
+    TooHigh = 10,
+    TooLow = -3,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(names)]
+enum EmptyNamesEnum {}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(all_values, reverse)]
+enum AllValuesEnum {
+    #[ordinal(order = 1)]
+    A,
+    #[ordinal(order = 0)]
+    B,
+    #[ordinal(order = 2)]
+    C,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(discriminant(dense), all_values, names)]
+enum DenseAllValuesEnum {
+    A = 7,
+    B = -1,
+    C = 3,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(all_values)]
+enum EmptyAllValuesEnum {}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+struct GenericStruct<A, B>(A, Option<B>, bool);
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+enum GenericEnum<T>
+where
+    T: Copy,
+{
+    A(T),
+    B { b: [T; 2] },
+    C,
+}
+
+trait HasKey {
+    type Key;
+}
+
+impl HasKey for u8 {
+    type Key = bool;
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+struct AssocTypeStruct<T: HasKey>(T::Key);
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+#[ordinal(bound = "T: Ordinal + Copy")]
+struct BoundStruct<T>(T);
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+#[ordinal(transparent)]
+struct TransparentStruct(Option<bool>);
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+#[ordinal(transparent, reverse)]
+struct TransparentGeneric<T> {
+    inner: T,
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Clone)]
+#[ordinal(via = "TryFrom<u8>")]
+struct Percent(u8);
+
+impl TryFrom<u8> for Percent {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value <= 100 {
+            Ok(Percent(value))
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl From<Percent> for u8 {
+    fn from(value: Percent) -> u8 {
+        value.0
+    }
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug, Clone, Ord, PartialOrd)]
+#[ordinal(via = "core::convert::From<bool>")]
+enum Toggle {
+    Off,
+    On,
+}
+
+impl From<bool> for Toggle {
+    fn from(value: bool) -> Self {
+        if value {
+            Toggle::On
+        } else {
+            Toggle::Off
+        }
+    }
+}
+
+impl From<Toggle> for bool {
+    fn from(value: Toggle) -> bool {
+        value == Toggle::On
+    }
+}
+
+/// Derived code does not trigger `unsafe_code` lint.
+#[forbid(unsafe_code)]
+mod forbid_unsafe {
+    use crate as ordinal_map;
+    use crate::Ordinal;
+
+    #[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+    pub(super) enum SafeEnum {
+        A(bool),
+        B { b: Option<()> },
+    }
+
+    #[derive(Ordinal, Eq, PartialEq, Debug, Ord, PartialOrd)]
+    #[ordinal(transparent)]
+    pub(super) struct SafeStruct(pub(super) SafeEnum);
+
+    #[derive(Ordinal, Eq, PartialEq, Debug)]
+    #[ordinal(discriminant, reverse)]
+    pub(super) enum SafeDiscriminantEnum {
+        A = 1,
+        B = 0,
+    }
+}
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+struct WideTupleStruct(u64, bool);
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+struct WideRecordStruct {
+    a: bool,
+    b: i64,
+}
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+enum WideEnum {
+    A,
+    B(u64),
+    C { x: bool, y: u64 },
+}
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+struct WideEmptyStruct(u64, Infallible);
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+enum WideEmptyEnum {}
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+struct WideGeneric<T>(T, u32);
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+struct WideAddr(Ipv6Addr);
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+struct WideFullRecordStruct {
+    a: (),
+    b: u128,
+    c: (),
+}
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+enum WideFullEnum {
+    A(Infallible),
+    B(u64, u64),
+    C(Infallible, bool),
+}
+
+#[derive(WideOrdinal, Eq, PartialEq, Debug)]
+enum WideSplitEnum {
+    A(u64, [bool; 63]),
+    B(u64, [bool; 63]),
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(wide)]
+enum OrdinalWideEnum {
+    A,
+    B(bool),
+    C(u8),
+}
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(wide, reverse)]
+struct OrdinalWideGeneric<T>(T, bool);
+
+#[derive(Ordinal, Eq, PartialEq, Debug)]
+#[ordinal(wide)]
+enum OrdinalWideEmptyEnum {}
+
 #[cfg(test)]
 mod tests {
+    use std::net::Ipv6Addr;
+
+    use crate::map::OrdinalMap;
+    use crate::tests::derive::forbid_unsafe::SafeDiscriminantEnum;
+    use crate::tests::derive::forbid_unsafe::SafeEnum;
+    use crate::tests::derive::forbid_unsafe::SafeStruct;
+    use crate::tests::derive::AllValuesEnum;
+    use crate::tests::derive::AssocTypeStruct;
+    use crate::tests::derive::BoundStruct;
+    use crate::tests::derive::DenseAllValuesEnum;
+    use crate::tests::derive::DenseDiscriminantEnum;
+    use crate::tests::derive::DenseNamesEnum;
+    use crate::tests::derive::Digit;
+    use crate::tests::derive::DiscriminantEnum;
+    use crate::tests::derive::EmptyAllValuesEnum;
+    use crate::tests::derive::EmptyDiscriminantEnum;
     use crate::tests::derive::EmptyEnum;
+    use crate::tests::derive::EmptyNamesEnum;
     use crate::tests::derive::EmptyStruct;
+    use crate::tests::derive::FieldOrderEnum;
+    use crate::tests::derive::FieldOrderStruct;
+    use crate::tests::derive::FieldOrderTupleStruct;
+    use crate::tests::derive::GenericEnum;
+    use crate::tests::derive::GenericStruct;
+    use crate::tests::derive::MacroEnum;
+    use crate::tests::derive::MacroStruct;
     use crate::tests::derive::MixedEnum;
+    use crate::tests::derive::NamesEnum;
+    use crate::tests::derive::OrderedEnum;
+    use crate::tests::derive::OrdinalWideEmptyEnum;
+    use crate::tests::derive::OrdinalWideEnum;
+    use crate::tests::derive::OrdinalWideGeneric;
+    use crate::tests::derive::PayloadEnum;
+    use crate::tests::derive::Percent;
+    use crate::tests::derive::RangeEnum;
+    use crate::tests::derive::RangeRecordStruct;
     use crate::tests::derive::RecordEnum;
     use crate::tests::derive::RecordStruct1;
     use crate::tests::derive::RecordStruct2;
+    use crate::tests::derive::RenameAllEnum;
+    use crate::tests::derive::ReverseDiscriminantEnum;
+    use crate::tests::derive::ReverseEmptyStruct;
+    use crate::tests::derive::ReverseEnum;
+    use crate::tests::derive::ReverseStruct;
     use crate::tests::derive::SimpleEnum1;
     use crate::tests::derive::SimpleEnum3;
+    use crate::tests::derive::Toggle;
+    use crate::tests::derive::TransparentGeneric;
+    use crate::tests::derive::TransparentStruct;
     use crate::tests::derive::TupleEnum;
     use crate::tests::derive::TupleStruct1;
     use crate::tests::derive::TupleStruct2;
+    use crate::tests::derive::UninhabitedLastEnum;
     use crate::tests::derive::UnitStruct;
+    use crate::tests::derive::WideAddr;
+    use crate::tests::derive::WideEmptyEnum;
+    use crate::tests::derive::WideEmptyStruct;
+    use crate::tests::derive::WideEnum;
+    use crate::tests::derive::WideFullEnum;
+    use crate::tests::derive::WideFullRecordStruct;
+    use crate::tests::derive::WideGeneric;
+    use crate::tests::derive::WideRecordStruct;
+    use crate::tests::derive::WideSplitEnum;
+    use crate::tests::derive::WideTupleStruct;
     use crate::tests::util::test_ordinal;
+    use crate::tests::util::test_ordinal_order;
+    use crate::tests::util::test_wide_ordinal;
+    use crate::Ordinal;
+    use crate::ParseNameError;
+    use crate::WideOrdinal;
 
     #[test]
     fn test_unit_struct() {
@@ -87,7 +500,7 @@ mod tests {
 
     #[test]
     fn test_tuple_struct_1() {
-        test_ordinal((0..=255).map(|i| TupleStruct1(i)));
+        test_ordinal((0..=255).map(TupleStruct1));
     }
 
     #[test]
@@ -185,4 +598,487 @@ mod tests {
     fn test_empty_struct() {
         test_ordinal::<EmptyStruct>([]);
     }
+
+    #[test]
+    fn test_range_newtype() {
+        assert_eq!(10, Digit::ORDINAL_SIZE);
+        test_ordinal((0..10).map(Digit));
+    }
+
+    #[test]
+    fn test_range_record_struct() {
+        test_ordinal((-1..=1).flat_map(|a| [false, true].map(move |b| RangeRecordStruct { a, b })));
+    }
+
+    #[test]
+    fn test_range_enum() {
+        test_ordinal([
+            RangeEnum::A,
+            RangeEnum::B(1),
+            RangeEnum::B(2),
+            RangeEnum::B(3),
+        ]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic = "outside of `#[ordinal(range)]`"]
+    fn test_range_out_of_range() {
+        Digit(10).ordinal();
+    }
+
+    #[test]
+    fn test_ordered_enum() {
+        test_ordinal_order([
+            OrderedEnum::B(false),
+            OrderedEnum::B(true),
+            OrderedEnum::C,
+            OrderedEnum::A,
+        ]);
+    }
+
+    #[test]
+    fn test_reverse_enum() {
+        test_ordinal_order([ReverseEnum::B(true), ReverseEnum::B(false), ReverseEnum::A]);
+    }
+
+    #[test]
+    fn test_reverse_struct() {
+        test_ordinal_order([
+            ReverseStruct(true, Some(())),
+            ReverseStruct(true, None),
+            ReverseStruct(false, Some(())),
+            ReverseStruct(false, None),
+        ]);
+    }
+
+    #[test]
+    fn test_reverse_empty_struct() {
+        test_ordinal_order::<ReverseEmptyStruct>([]);
+    }
+
+    #[test]
+    fn test_field_order_struct() {
+        test_ordinal_order([
+            FieldOrderStruct { a: false, b: None },
+            FieldOrderStruct { a: true, b: None },
+            FieldOrderStruct {
+                a: false,
+                b: Some(()),
+            },
+            FieldOrderStruct {
+                a: true,
+                b: Some(()),
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_field_order_tuple_struct() {
+        test_ordinal_order([
+            FieldOrderTupleStruct(false, None),
+            FieldOrderTupleStruct(true, None),
+            FieldOrderTupleStruct(false, Some(())),
+            FieldOrderTupleStruct(true, Some(())),
+        ]);
+    }
+
+    #[test]
+    fn test_field_order_enum() {
+        test_ordinal_order([
+            FieldOrderEnum::A,
+            FieldOrderEnum::B { a: false, b: None },
+            FieldOrderEnum::B { a: true, b: None },
+            FieldOrderEnum::B {
+                a: false,
+                b: Some(()),
+            },
+            FieldOrderEnum::B {
+                a: true,
+                b: Some(()),
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_discriminant_enum() {
+        assert_eq!(4, DiscriminantEnum::ORDINAL_SIZE);
+        assert_eq!(1, DiscriminantEnum::A.ordinal());
+        assert_eq!(2, DiscriminantEnum::B.ordinal());
+        test_ordinal([
+            DiscriminantEnum::C,
+            DiscriminantEnum::A,
+            DiscriminantEnum::B,
+            DiscriminantEnum::D,
+        ]);
+    }
+
+    #[test]
+    fn test_dense_discriminant_enum() {
+        test_ordinal([
+            DenseDiscriminantEnum::B,
+            DenseDiscriminantEnum::A,
+            DenseDiscriminantEnum::C,
+        ]);
+    }
+
+    #[test]
+    fn test_reverse_discriminant_enum() {
+        assert_eq!(0, ReverseDiscriminantEnum::A.ordinal());
+        assert_eq!(1, ReverseDiscriminantEnum::B.ordinal());
+        assert_eq!(
+            Some(ReverseDiscriminantEnum::A),
+            ReverseDiscriminantEnum::from_ordinal(0)
+        );
+        assert_eq!(None, ReverseDiscriminantEnum::from_ordinal(2));
+    }
+
+    #[test]
+    fn test_empty_discriminant_enum() {
+        test_ordinal::<EmptyDiscriminantEnum>([]);
+    }
+
+    #[test]
+    fn test_payload_enum() {
+        test_ordinal([
+            PayloadEnum::A(false),
+            PayloadEnum::A(true),
+            PayloadEnum::B,
+            PayloadEnum::D(None),
+            PayloadEnum::D(Some(false)),
+            PayloadEnum::D(Some(true)),
+            PayloadEnum::E {},
+            PayloadEnum::F(false, false),
+            PayloadEnum::F(false, true),
+            PayloadEnum::F(true, false),
+            PayloadEnum::F(true, true),
+            PayloadEnum::G(),
+        ]);
+        assert_eq!(None, PayloadEnum::from_ordinal(usize::MAX));
+    }
+
+    #[test]
+    fn test_uninhabited_last_enum() {
+        test_ordinal([UninhabitedLastEnum::A(false), UninhabitedLastEnum::A(true)]);
+        assert_eq!(None, UninhabitedLastEnum::from_ordinal(2));
+        assert_eq!(None, UninhabitedLastEnum::from_ordinal(usize::MAX));
+    }
+
+    #[test]
+    fn test_forbid_unsafe() {
+        test_ordinal([
+            SafeStruct(SafeEnum::A(false)),
+            SafeStruct(SafeEnum::A(true)),
+            SafeStruct(SafeEnum::B { b: None }),
+            SafeStruct(SafeEnum::B { b: Some(()) }),
+        ]);
+        test_ordinal_order([SafeDiscriminantEnum::A, SafeDiscriminantEnum::B]);
+    }
+
+    #[test]
+    fn test_macro_struct() {
+        test_ordinal([
+            MacroStruct(false, None),
+            MacroStruct(false, Some(())),
+            MacroStruct(true, None),
+            MacroStruct(true, Some(())),
+        ]);
+    }
+
+    #[test]
+    fn test_macro_enum() {
+        test_ordinal([
+            MacroEnum::A(false),
+            MacroEnum::A(true),
+            MacroEnum::B(None),
+            MacroEnum::B(Some(())),
+        ]);
+    }
+
+    #[test]
+    fn test_names_enum() {
+        assert_eq!(["Red", "green", "DarkBlue"], NamesEnum::NAMES);
+        assert_eq!("green", NamesEnum::Green.name());
+        assert_eq!(Some(NamesEnum::DarkBlue), NamesEnum::from_name("DarkBlue"));
+        assert_eq!(None, NamesEnum::from_name("Green"));
+        assert_eq!(None, NamesEnum::from_name("red"));
+        assert_eq!(Ok(NamesEnum::Red), "Red".parse());
+        assert_eq!(Err(ParseNameError), "Blue".parse::<NamesEnum>());
+        assert_eq!("green", NamesEnum::Green.to_string());
+        assert_eq!("[Red  ]", format!("[{:5}]", NamesEnum::Red));
+    }
+
+    #[test]
+    fn test_rename_all_enum() {
+        test_ordinal_order([
+            RenameAllEnum::Other,
+            RenameAllEnum::LogLevel,
+            RenameAllEnum::Http2,
+        ]);
+        for v in RenameAllEnum::all_values() {
+            assert_eq!(RenameAllEnum::NAMES[v.ordinal()], v.name());
+        }
+        assert_eq!(["X", "log-level", "http2"], RenameAllEnum::NAMES);
+        assert_eq!(
+            Some(RenameAllEnum::LogLevel),
+            RenameAllEnum::from_name("Log-Level")
+        );
+        assert_eq!(Some(RenameAllEnum::Other), RenameAllEnum::from_name("x"));
+        assert_eq!(Ok(RenameAllEnum::Http2), "HTTP2".parse());
+    }
+
+    #[test]
+    fn test_dense_names_enum() {
+        assert_eq!(["too_low", "too_high"], DenseNamesEnum::NAMES);
+        assert_eq!(
+            Some(DenseNamesEnum::TooLow),
+            DenseNamesEnum::from_name("too_low")
+        );
+        assert_eq!("too_high", DenseNamesEnum::TooHigh.to_string());
+    }
+
+    #[test]
+    fn test_empty_names_enum() {
+        assert_eq!(0, EmptyNamesEnum::NAMES.len());
+        assert_eq!(None, EmptyNamesEnum::from_name(""));
+    }
+
+    #[test]
+    fn test_all_values_enum() {
+        const ALL: [AllValuesEnum; 3] = AllValuesEnum::ALL_VALUES;
+        let all: &'static [AllValuesEnum] = &AllValuesEnum::ALL_VALUES;
+        assert_eq!([AllValuesEnum::C, AllValuesEnum::A, AllValuesEnum::B], ALL);
+        assert_eq!(AllValuesEnum::all_values().collect::<Vec<_>>(), all);
+    }
+
+    #[test]
+    fn test_dense_all_values_enum() {
+        assert_eq!(
+            [
+                DenseAllValuesEnum::B,
+                DenseAllValuesEnum::C,
+                DenseAllValuesEnum::A
+            ],
+            DenseAllValuesEnum::ALL_VALUES
+        );
+        assert_eq!(["B", "C", "A"], DenseAllValuesEnum::NAMES);
+        test_ordinal_order(DenseAllValuesEnum::ALL_VALUES);
+    }
+
+    #[test]
+    fn test_empty_all_values_enum() {
+        assert_eq!(0, EmptyAllValuesEnum::ALL_VALUES.len());
+    }
+
+    #[test]
+    fn test_generic_struct() {
+        assert_eq!(12, GenericStruct::<bool, bool>::ORDINAL_SIZE);
+        test_ordinal([
+            GenericStruct((), None, false),
+            GenericStruct((), None, true),
+            GenericStruct((), Some(()), false),
+            GenericStruct((), Some(()), true),
+        ]);
+    }
+
+    #[test]
+    fn test_generic_enum() {
+        test_ordinal([
+            GenericEnum::A(false),
+            GenericEnum::A(true),
+            GenericEnum::B { b: [false, false] },
+            GenericEnum::B { b: [false, true] },
+            GenericEnum::B { b: [true, false] },
+            GenericEnum::B { b: [true, true] },
+            GenericEnum::C,
+        ]);
+    }
+
+    #[test]
+    fn test_assoc_type_struct() {
+        test_ordinal([AssocTypeStruct::<u8>(false), AssocTypeStruct(true)]);
+    }
+
+    #[test]
+    fn test_bound_struct() {
+        test_ordinal([BoundStruct(false), BoundStruct(true)]);
+    }
+
+    #[test]
+    fn test_transparent_struct() {
+        test_ordinal([
+            TransparentStruct(None),
+            TransparentStruct(Some(false)),
+            TransparentStruct(Some(true)),
+        ]);
+    }
+
+    #[test]
+    fn test_transparent_generic() {
+        test_ordinal_order([
+            TransparentGeneric { inner: true },
+            TransparentGeneric { inner: false },
+        ]);
+    }
+
+    #[test]
+    fn test_via_try_from() {
+        assert_eq!(256, Percent::ORDINAL_SIZE);
+        assert_eq!(42, Percent(42).ordinal());
+        assert_eq!(Some(Percent(100)), Percent::from_ordinal(100));
+        assert_eq!(None, Percent::from_ordinal(101));
+        assert_eq!(None, Percent::from_ordinal(256));
+    }
+
+    #[test]
+    #[should_panic(expected = "no value for ordinal below `ORDINAL_SIZE`")]
+    fn test_via_try_from_all_values() {
+        Percent::all_values().for_each(drop);
+    }
+
+    #[test]
+    fn test_via_try_from_map() {
+        // Keys are created only for inserted entries.
+        let mut map = OrdinalMap::new();
+        map.insert(Percent(100), "all");
+        map.insert(Percent(0), "none");
+        assert_eq!(
+            vec![(Percent(0), &"none"), (Percent(100), &"all")],
+            map.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(Some((Percent(100), "all")), map.into_iter().next_back());
+    }
+
+    #[test]
+    fn test_via_from() {
+        test_ordinal([Toggle::Off, Toggle::On]);
+    }
+
+    #[test]
+    fn test_wide_struct() {
+        assert_eq!(Some((1 << 65) - 1), WideTupleStruct::WIDE_ORDINAL_MAX);
+        assert_eq!(5, WideTupleStruct(2, true).wide_ordinal());
+        test_wide_ordinal([
+            WideTupleStruct(0, false),
+            WideTupleStruct(0, true),
+            WideTupleStruct(1, false),
+            WideTupleStruct(u64::MAX, true),
+        ]);
+        test_wide_ordinal([
+            WideRecordStruct {
+                a: false,
+                b: i64::MIN,
+            },
+            WideRecordStruct {
+                a: false,
+                b: i64::MAX,
+            },
+            WideRecordStruct {
+                a: true,
+                b: i64::MIN,
+            },
+            WideRecordStruct {
+                a: true,
+                b: i64::MAX,
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_wide_enum() {
+        assert_eq!(Some(3 << 64), WideEnum::WIDE_ORDINAL_MAX);
+        assert_eq!(0, WideEnum::A.wide_ordinal());
+        assert_eq!(1, WideEnum::B(0).wide_ordinal());
+        assert_eq!((1 << 64) + 1, WideEnum::C { x: false, y: 0 }.wide_ordinal());
+        test_wide_ordinal([
+            WideEnum::A,
+            WideEnum::B(0),
+            WideEnum::B(u64::MAX),
+            WideEnum::C { x: false, y: 0 },
+            WideEnum::C {
+                x: false,
+                y: u64::MAX,
+            },
+            WideEnum::C { x: true, y: 0 },
+            WideEnum::C {
+                x: true,
+                y: u64::MAX,
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_wide_empty() {
+        assert_eq!(None, WideEmptyStruct::WIDE_ORDINAL_MAX);
+        assert_eq!(None, WideEmptyStruct::from_wide_ordinal(0));
+        assert_eq!(0, WideEmptyStruct::all_wide_values().count());
+        assert_eq!(None, WideEmptyEnum::WIDE_ORDINAL_MAX);
+        assert_eq!(None, WideEmptyEnum::from_wide_ordinal(0));
+    }
+
+    #[test]
+    fn test_wide_generic() {
+        assert_eq!(Some((1 << 33) - 1), WideGeneric::<bool>::WIDE_ORDINAL_MAX);
+        assert_eq!(Some((1 << 96) - 1), WideGeneric::<u64>::WIDE_ORDINAL_MAX);
+        test_wide_ordinal([
+            WideGeneric(false, 0),
+            WideGeneric(false, u32::MAX),
+            WideGeneric(true, 0),
+        ]);
+        assert_eq!(
+            vec![WideGeneric((), 0), WideGeneric((), 1)],
+            WideGeneric::<()>::all_wide_values()
+                .take(2)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_wide_full() {
+        assert_eq!(Some(u128::MAX), WideAddr::WIDE_ORDINAL_MAX);
+        assert_eq!(1, WideAddr(Ipv6Addr::LOCALHOST).wide_ordinal());
+        test_wide_ordinal([
+            WideAddr(Ipv6Addr::UNSPECIFIED),
+            WideAddr(Ipv6Addr::LOCALHOST),
+            WideAddr(Ipv6Addr::from(u128::MAX)),
+        ]);
+        assert_eq!(Some(u128::MAX), WideFullRecordStruct::WIDE_ORDINAL_MAX);
+        test_wide_ordinal([0, 1, u128::MAX].map(|b| WideFullRecordStruct { a: (), b, c: () }));
+        assert_eq!(Some(u128::MAX), WideFullEnum::WIDE_ORDINAL_MAX);
+        test_wide_ordinal([
+            WideFullEnum::B(0, 0),
+            WideFullEnum::B(0, u64::MAX),
+            WideFullEnum::B(1, 0),
+            WideFullEnum::B(u64::MAX, u64::MAX),
+        ]);
+        assert_eq!(Some(u128::MAX), WideSplitEnum::WIDE_ORDINAL_MAX);
+        assert_eq!(1 << 127, WideSplitEnum::B(0, [false; 63]).wide_ordinal());
+        test_wide_ordinal([
+            WideSplitEnum::A(0, [false; 63]),
+            WideSplitEnum::A(u64::MAX, [true; 63]),
+            WideSplitEnum::B(0, [false; 63]),
+            WideSplitEnum::B(u64::MAX, [true; 63]),
+        ]);
+    }
+
+    #[test]
+    fn test_ordinal_wide() {
+        assert_eq!(Some(258), OrdinalWideEnum::WIDE_ORDINAL_MAX);
+        for value in OrdinalWideEnum::all_values() {
+            assert_eq!(value.ordinal() as u128, value.wide_ordinal());
+        }
+        test_wide_ordinal(OrdinalWideEnum::all_values());
+        assert_eq!(
+            OrdinalWideEnum::all_values().collect::<Vec<_>>(),
+            OrdinalWideEnum::all_wide_values().collect::<Vec<_>>()
+        );
+        assert_eq!(None, OrdinalWideEnum::from_wide_ordinal(u128::MAX));
+
+        assert_eq!(Some(511), OrdinalWideGeneric::<u8>::WIDE_ORDINAL_MAX);
+        assert_eq!(0, OrdinalWideGeneric(u8::MAX, true).wide_ordinal());
+        test_wide_ordinal(OrdinalWideGeneric::<u8>::all_values());
+
+        assert_eq!(None, OrdinalWideEmptyEnum::WIDE_ORDINAL_MAX);
+        assert_eq!(None, OrdinalWideEmptyEnum::from_wide_ordinal(0));
+    }
 }
@@ -7,6 +7,7 @@ use quickcheck::Gen;
 
 use crate as ordinal_map;
 use crate::Ordinal;
+use crate::WideOrdinal;
 
 pub(crate) fn test_ordinal_value<T: Ordinal + Eq + Debug>(value: T) {
     let ordinal = value.ordinal();
@@ -32,13 +33,21 @@ pub(crate) fn test_ordinal<T: Ordinal + Ord + Eq + Debug>(expected: impl IntoIte
         assert!(a < b);
     }
 
+    test_ordinal_order(expected);
+}
+
+/// Like [`test_ordinal`], but for types where ordinal order is not compatible with `Ord`.
+pub(crate) fn test_ordinal_order<T: Ordinal + Eq + Debug>(expected: impl IntoIterator<Item = T>) {
+    let expected = Vec::from_iter(expected);
+
     assert_eq!(expected.len(), T::ORDINAL_SIZE);
     assert_eq!(expected, T::all_values().collect::<Vec<_>>());
 
-    for i in 0..T::ORDINAL_SIZE {
+    for (i, expected) in expected.iter().enumerate() {
         let t = T::from_ordinal(i).unwrap();
-        assert_eq!(t, expected[i]);
+        assert_eq!(&t, expected);
         assert_eq!(i, t.ordinal());
+        assert_eq!(&unsafe { T::from_ordinal_unchecked(i) }, expected);
     }
 
     assert_eq!(None, T::from_ordinal(T::ORDINAL_SIZE));
@@ -46,6 +55,30 @@ pub(crate) fn test_ordinal<T: Ordinal + Ord + Eq + Debug>(expected: impl IntoIte
     test_ordinal_some::<T>();
 }
 
+/// Check wide ordinals of values given in increasing wide ordinal order
+/// (not necessarily all values).
+pub(crate) fn test_wide_ordinal<T: WideOrdinal + Eq + Debug>(
+    expected: impl IntoIterator<Item = T>,
+) {
+    let expected = Vec::from_iter(expected);
+    let max = T::WIDE_ORDINAL_MAX.unwrap();
+
+    let mut prev = None;
+    for expected in &expected {
+        let ordinal = expected.wide_ordinal();
+        assert!(ordinal <= max);
+        assert!(prev < Some(ordinal));
+        assert_eq!(Some(expected), T::from_wide_ordinal(ordinal).as_ref());
+        prev = Some(ordinal);
+    }
+
+    assert!(T::from_wide_ordinal(0).is_some());
+    assert!(T::from_wide_ordinal(max).is_some());
+    if let Some(size) = max.checked_add(1) {
+        assert_eq!(None, T::from_wide_ordinal(size));
+    }
+}
+
 #[derive(Ordinal, Eq, PartialEq, Hash, Copy, Clone, Debug, Ord, PartialOrd)]
 pub(crate) enum Example4 {
     A,
@@ -72,7 +105,7 @@ impl Arbitrary for Example4 {
 
 pub(crate) fn test_exact_size_iterator<I: ExactSizeIterator>(mut iter: I) {
     let mut rem = iter.len();
-    for _ in 0..rem {
+    while rem > 0 {
         assert_eq!(rem, iter.len());
         assert!(iter.next().is_some());
         rem -= 1;
@@ -39,7 +39,8 @@ impl<T: Ordinal> Iterator for OrdinalValues<T> {
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         let next = self.range.next()?;
-        Some(T::from_ordinal(next).unwrap())
+        // SAFETY: `range` is within `0..T::ORDINAL_SIZE`.
+        Some(unsafe { T::from_ordinal_unchecked(next) })
     }
 
     #[inline]
@@ -75,7 +76,8 @@ impl<T: Ordinal> DoubleEndedIterator for OrdinalValues<T> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         let next = self.range.next_back()?;
-        Some(T::from_ordinal(next).unwrap())
+        // SAFETY: `range` is within `0..T::ORDINAL_SIZE`.
+        Some(unsafe { T::from_ordinal_unchecked(next) })
     }
 }
 
@@ -128,4 +130,26 @@ mod tests {
         let iter = u8::all_values();
         assert_eq!(Some(255), iter.last());
     }
+
+    /// Broken implementation with no value for ordinal `1`.
+    #[derive(Debug)]
+    struct Gap(usize);
+
+    impl Ordinal for Gap {
+        const ORDINAL_SIZE: usize = 3;
+
+        fn ordinal(&self) -> usize {
+            self.0
+        }
+
+        fn from_ordinal(ordinal: usize) -> Option<Self> {
+            (ordinal < 3 && ordinal != 1).then_some(Gap(ordinal))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no value for ordinal below `ORDINAL_SIZE`")]
+    fn test_gap_panics() {
+        Gap::all_values().for_each(drop);
+    }
 }
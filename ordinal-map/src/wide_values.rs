@@ -0,0 +1,144 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+use crate::WideOrdinal;
+
+/// Iterator over [`WideOrdinal`] values.
+///
+/// This iterator is created by [`WideOrdinal::all_wide_values`](WideOrdinal::all_wide_values).
+///
+/// # Example
+///
+/// ```
+/// use ordinal_map::WideOrdinal;
+///
+/// let mut values = u64::all_wide_values();
+/// assert_eq!(Some(0), values.next());
+/// assert_eq!(Some(u64::MAX), values.next_back());
+/// ```
+pub struct WideOrdinalValues<T> {
+    range: RangeInclusive<u128>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: WideOrdinal> WideOrdinalValues<T> {
+    /// Create a new iterator.
+    #[inline]
+    pub(crate) fn new() -> Self {
+        #[allow(clippy::reversed_empty_ranges)]
+        let range = match T::WIDE_ORDINAL_MAX {
+            Some(max) => 0..=max,
+            None => 1..=0,
+        };
+        WideOrdinalValues {
+            range,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn value(ordinal: u128) -> T {
+        T::from_wide_ordinal(ordinal).expect("wide ordinal is not greater than `WIDE_ORDINAL_MAX`")
+    }
+}
+
+impl<T: WideOrdinal> Iterator for WideOrdinalValues<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(Self::value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(Self::value)
+    }
+}
+
+impl<T: WideOrdinal> DoubleEndedIterator for WideOrdinalValues<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(Self::value)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth_back(n).map(Self::value)
+    }
+}
+
+impl<T> Clone for WideOrdinalValues<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        WideOrdinalValues {
+            range: self.range.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: WideOrdinal + Debug> Debug for WideOrdinalValues<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut iter = self.clone();
+        let Some(first) = iter.next() else {
+            return write!(f, "[]");
+        };
+        if Some(*self.range.end()) == T::WIDE_ORDINAL_MAX {
+            write!(f, "[{:?}..]", first)
+        } else {
+            let Some(last) = iter.next_back() else {
+                return write!(f, "[{:?}]", first);
+            };
+            write!(f, "[{:?}..={:?}]", first, last)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::WideOrdinal;
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(
+            vec![false, true],
+            bool::all_wide_values().collect::<Vec<_>>()
+        );
+        assert_eq!(0, Infallible::all_wide_values().count());
+
+        let mut iter = u128::all_wide_values();
+        assert_eq!((usize::MAX, None), iter.size_hint());
+        assert_eq!(Some(0), iter.next());
+        assert_eq!(Some(u128::MAX), iter.next_back());
+        assert_eq!(Some(11), iter.nth(10));
+        assert_eq!(Some(u128::MAX - 11), iter.nth_back(10));
+    }
+
+    #[test]
+    fn test_iter_debug() {
+        let mut iter = u64::all_wide_values();
+        assert_eq!("[0..]", format!("{:?}", iter));
+        iter.next().unwrap();
+        iter.next_back().unwrap();
+        assert_eq!(format!("[1..={}]", u64::MAX - 1), format!("{:?}", iter));
+
+        let mut iter = bool::all_wide_values();
+        iter.nth(1).unwrap();
+        assert_eq!("[]", format!("{:?}", iter));
+    }
+}
@@ -0,0 +1,10 @@
+use ordinal_map::Ordinal;
+
+#[derive(Ordinal)]
+#[ordinal(discriminant)]
+enum Opcode {
+    Nop = 0,
+    Load = 2,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use ordinal_map::Ordinal;
+
+#[derive(Ordinal)]
+#[ordinal(max_size = 256)]
+struct Key(u8, bool);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use ordinal_map::Ordinal;
+
+#[derive(Ordinal)]
+enum Key {
+    A(u32, u16, u8, #[ordinal(range = 0..128)] u8),
+    B(u32, u16, u8, #[ordinal(range = 0..128)] u8),
+    C,
+}
+
+const SIZE: usize = Key::ORDINAL_SIZE;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ordinal_map::Ordinal;
+
+#[derive(Ordinal)]
+struct Pair<A, B>(A, B);
+
+const SIZE: usize = Pair::<u32, u32>::ORDINAL_SIZE;
+
+fn main() {}
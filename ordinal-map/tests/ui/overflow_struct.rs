@@ -0,0 +1,12 @@
+use ordinal_map::Ordinal;
+
+#[derive(Ordinal)]
+struct Key {
+    a: u32,
+    b: bool,
+    c: u32,
+}
+
+const SIZE: usize = Key::ORDINAL_SIZE;
+
+fn main() {}
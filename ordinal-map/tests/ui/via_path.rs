@@ -0,0 +1,25 @@
+use ordinal_map::Ordinal;
+
+mod my {
+    pub trait From<T> {
+        fn from(value: T) -> Self;
+    }
+}
+
+#[derive(Ordinal, Clone)]
+#[ordinal(via = "my::From<u8>")]
+struct Id(u8);
+
+impl my::From<u8> for Id {
+    fn from(value: u8) -> Id {
+        Id(value)
+    }
+}
+
+impl From<Id> for u8 {
+    fn from(id: Id) -> u8 {
+        id.0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ordinal_map::WideOrdinal;
+
+#[derive(WideOrdinal)]
+struct Triple(u64, u64, bool);
+
+const MAX: Option<u128> = Triple::WIDE_ORDINAL_MAX;
+
+fn main() {}